@@ -1,8 +1,29 @@
+import init, {quick_add_selection, quick_add_word} from './dictionary.js'
+
 const SITE_ORIGIN = 'https://www.oxfordlearnersdictionaries.com';
+const SITE_HOSTS = ['oxfordlearnersdictionaries.com', 'www.oxfordlearnersdictionaries.com'];
+const TAB_DATA_SCRIPT = 'tab-data.js';
 const SIDEPANEL = 'sidepanel.html';
+const QUICK_ADD = 'quick-add-word';
+const QUICK_ADD_SELECTION = 'quick-add-selection';
+const BADGE_TIMEOUT = 3000;
+const DEFAULT_TITLE = 'Click to open bookmarks panel';
+
+const wasm = init("./dictionary_bg.wasm");
 
 chrome.runtime.onInstalled.addListener(() => {
     chrome.sidePanel.setPanelBehavior({openPanelOnActionClick: true});
+    chrome.contextMenus.create({
+        id: QUICK_ADD,
+        title: "Add word to the default folder",
+        contexts: ["page"],
+        documentUrlPatterns: SITE_HOSTS.map(host => `*://${host}/*`)
+    });
+    chrome.contextMenus.create({
+        id: QUICK_ADD_SELECTION,
+        title: "Save \"%s\" to the default folder",
+        contexts: ["selection"]
+    });
 });
 
 chrome.action.onClicked.addListener((tab) => {
@@ -19,3 +40,96 @@ chrome.action.onClicked.addListener((tab) => {
 chrome.runtime.onMessage.addListener(function (request, sender, sendResponse) {
     // for sendMessage in content-script.js
 });
+
+chrome.contextMenus.onClicked.addListener((info, tab) => {
+    if (info.menuItemId === QUICK_ADD) {
+        quickAdd(tab);
+    } else if (info.menuItemId === QUICK_ADD_SELECTION) {
+        quickAddSelection(tab);
+    }
+});
+
+chrome.commands.onCommand.addListener((command, tab) => {
+    if (command === QUICK_ADD) {
+        quickAdd(tab);
+    }
+});
+
+function isSitePage(url) {
+    try {
+        return SITE_HOSTS.includes(new URL(url).hostname);
+    } catch (e) {
+        return false;
+    }
+}
+
+async function quickAdd(tab) {
+    if (!tab) return;
+    if (!tab.url || !isSitePage(tab.url)) {
+        await showBadge(tab.id, "!", "#ef4444", "Words can be added only from " + SITE_ORIGIN);
+        return;
+    }
+    try {
+        await chrome.scripting.executeScript({
+            target: {tabId: tab.id},
+            files: [TAB_DATA_SCRIPT]
+        });
+        const [{result}] = await chrome.scripting.executeScript({
+            target: {tabId: tab.id},
+            func: () => getTabData()
+        });
+        await wasm;
+        const folder = await quick_add_word(
+            tab.url,
+            result.word.trim(),
+            result.wordClass.trim(),
+            result.title.trim(),
+            result.phonetics.trim()
+        );
+        await showBadge(tab.id, "✓", "#22c55e", "Added to " + folder);
+    } catch (e) {
+        await showBadge(tab.id, "!", "#ef4444", String(e));
+    }
+}
+
+function getSelectionData() {
+    const selection = window.getSelection();
+    let text = "";
+    if (selection.rangeCount > 0) {
+        let element = selection.getRangeAt(0).commonAncestorContainer;
+        if (element.nodeType !== Node.ELEMENT_NODE) {
+            element = element.parentElement;
+        }
+        const block = element.closest("p, li, blockquote, dd, td, h1, h2, h3, h4, h5, h6");
+        text = (block || element).innerText;
+    }
+    return {selection: selection.toString(), text};
+}
+
+async function quickAddSelection(tab) {
+    if (!tab || !tab.url) return;
+    try {
+        const [{result}] = await chrome.scripting.executeScript({
+            target: {tabId: tab.id},
+            func: getSelectionData
+        });
+        await wasm;
+        const added = await quick_add_selection(tab.url, result.selection, result.text);
+        await showBadge(tab.id, "✓", "#22c55e", "Added to " + added.folder);
+        if (added.lookup_url) {
+            await chrome.tabs.create({url: added.lookup_url, index: tab.index + 1});
+        }
+    } catch (e) {
+        await showBadge(tab.id, "!", "#ef4444", String(e));
+    }
+}
+
+async function showBadge(tabId, text, color, title) {
+    await chrome.action.setBadgeBackgroundColor({tabId: tabId, color: color});
+    await chrome.action.setBadgeText({tabId: tabId, text: text});
+    await chrome.action.setTitle({tabId: tabId, title: title});
+    setTimeout(() => {
+        chrome.action.setBadgeText({tabId: tabId, text: ""});
+        chrome.action.setTitle({tabId: tabId, title: DEFAULT_TITLE});
+    }, BADGE_TIMEOUT);
+}
@@ -0,0 +1,28 @@
+// the themes of the side panel, shared by tailwind.config.js and tailwind.debug.js
+module.exports = {
+    themes: [
+        "light",
+        "dark",
+        {
+            highcontrast: {
+                "primary": "#ffff00",
+                "primary-content": "#000000",
+                "secondary": "#00ffff",
+                "accent": "#ff00ff",
+                "neutral": "#ffffff",
+                "neutral-content": "#000000",
+                "base-100": "#000000",
+                "base-200": "#000000",
+                "base-300": "#ffffff",
+                "base-content": "#ffffff",
+                "info": "#00ffff",
+                "success": "#00ff00",
+                "warning": "#ffff00",
+                "error": "#ff4040",
+                "--rounded-btn": "0",
+                "--border-btn": "2px",
+            },
+        },
+    ],
+    darkTheme: "dark",
+};
@@ -1,5 +1,7 @@
 // noinspection DuplicatedCode
 
+const TAB_DATA_SCRIPT = "tab-data.js";
+
 async function getCurrentTab() {
     const queryOptions = {active: true, currentWindow: true};
     const [tab] = await chrome.tabs.query(queryOptions);
@@ -34,42 +36,8 @@ export async function openUrl(url) {
     await executeInCurrentTab(doOpenUrl, [url]);
 }
 
-// TODO avoid code duplication with content-script.js
 async function doUpdateCurrentTabData() {
-    let word = "";
-    let wordClass = "";
-    let title = "";
-    let phonetics = "";
-    try {
-        if (window.location.href.split("/")[4] === "american_english") {
-            for (let element of window.document.getElementsByClassName("webtop-g")) {
-                if (element.nodeName === "DIV") {
-                    word = element.getElementsByClassName("h")[0].firstChild.textContent
-                    wordClass = element.getElementsByClassName("pos")[0].textContent;
-                    break;
-                }
-            }
-        } else {
-            for (let element of window.document.getElementsByClassName("headword")) {
-                if (element.nodeName === "H1") {
-                    word = element.firstChild.textContent;
-                    wordClass = element.parentElement.getElementsByClassName("pos")[0].textContent;
-                    break;
-                }
-            }
-            phonetics = window.document.getElementsByClassName("phonetics")[0]
-                .getElementsByClassName("phons_br")[0]
-                .getElementsByClassName("phon")[0]
-                .textContent;
-        }
-    } catch (e) {
-        // console.log(e);
-    }
-    try {
-        title = window.document.head.getElementsByTagName("title")[0].textContent;
-    } catch (e) {
-        // console.log(e);
-    }
+    const {word, wordClass, title, phonetics} = getTabData();
     await chrome.runtime.sendMessage({
         from_content_script: "loaded",
         from_content_script_word: word,
@@ -80,6 +48,11 @@ async function doUpdateCurrentTabData() {
 }
 
 export async function updateCurrentTabData() {
+    const tab = await getCurrentTab();
+    await chrome.scripting.executeScript({
+        target: {tabId: tab.id},
+        files: [TAB_DATA_SCRIPT]
+    });
     await executeInCurrentTab(doUpdateCurrentTabData, []);
 }
 
@@ -91,8 +64,8 @@ export function startDownload(url, filename) {
     });
 }
 
-export function scrollTo(id) {
-    const container = document.getElementById("words-list");
+export function scrollTo(listId, id) {
+    const container = document.getElementById(listId);
     const target = document.getElementById(id);
     if (container && target) {
         container.scrollTo({
@@ -121,3 +94,27 @@ export function doPlayPhonetics() {
 export async function playPhonetics() {
     await executeInCurrentTab(doPlayPhonetics, []);
 }
+
+export function focusElement(id) {
+    const element = document.getElementById(id);
+    if (element) {
+        element.focus();
+    }
+}
+
+export function focusedElementTag() {
+    const element = document.activeElement;
+    return element ? element.tagName : "";
+}
+
+export function setTheme(theme) {
+    if (theme) {
+        document.documentElement.setAttribute("data-theme", theme);
+    } else {
+        document.documentElement.removeAttribute("data-theme");
+    }
+}
+
+export function openTab(url) {
+    chrome.tabs.create({url: url});
+}
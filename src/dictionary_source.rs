@@ -0,0 +1,207 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+const OXFORD_LEARNERS_HOST: &str = "oxfordlearnersdictionaries.com";
+const OXFORD_LEARNERS_ORIGIN: &str = "https://www.oxfordlearnersdictionaries.com";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DictionarySource {
+    OxfordLearners,
+    Other,
+}
+
+struct UrlParts<'a> {
+    scheme: &'a str,
+    host: &'a str,
+    path: &'a str,
+    query: Option<&'a str>,
+}
+
+impl DictionarySource {
+    pub(crate) fn from_url(url: &str) -> DictionarySource {
+        match split_url(url) {
+            Some(parts) if strip_www(&parts.host.to_lowercase()) == OXFORD_LEARNERS_HOST => {
+                DictionarySource::OxfordLearners
+            }
+            _ => DictionarySource::Other,
+        }
+    }
+
+    /// Dictionary entries are identified by their path only, so the query string,
+    /// the fragment, the `www.` prefix and a trailing slash are dropped.
+    /// Search pages keep only the `q` parameter, the searched text.
+    /// For other sites the query string is kept since it may select the page.
+    pub(crate) fn canonical_url(&self, url: &str) -> String {
+        let url = url.trim();
+        let parts = match split_url(url) {
+            None => return url.to_string(),
+            Some(parts) => parts,
+        };
+        let path = parts.path.trim_end_matches('/');
+
+        match self {
+            DictionarySource::OxfordLearners => {
+                let search = parts
+                    .query
+                    .filter(|_| path.starts_with("/search"))
+                    .and_then(|query| query.split('&').find(|param| param.starts_with("q=")));
+                match search {
+                    Some(param) => format!("{}{}/?{}", OXFORD_LEARNERS_ORIGIN, path, param),
+                    None => format!("{}{}", OXFORD_LEARNERS_ORIGIN, path),
+                }
+            }
+            DictionarySource::Other => {
+                let mut result = format!(
+                    "{}://{}{}",
+                    parts.scheme.to_lowercase(),
+                    strip_www(&parts.host.to_lowercase()),
+                    path
+                );
+                if let Some(query) = parts.query.filter(|query| !query.is_empty()) {
+                    result.push('?');
+                    result.push_str(query);
+                }
+                result
+            }
+        }
+    }
+}
+
+pub(crate) fn canonical_url(url: &str) -> String {
+    DictionarySource::from_url(url).canonical_url(url)
+}
+
+pub(crate) fn lookup_url(search: &str) -> String {
+    let mut query = String::new();
+    for byte in search.trim().bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                query.push(byte as char)
+            }
+            b' ' => query.push('+'),
+            _ => query.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    format!("{}/search/english/?q={}", OXFORD_LEARNERS_ORIGIN, query)
+}
+
+/// Falls back to the page title when the headword isn't found on the page.
+pub(crate) fn headword(word: String, title: &str) -> String {
+    if !word.is_empty() {
+        return word;
+    }
+    match title.split_once(" - ").or_else(|| title.split_once(" | ")) {
+        Some((str, _)) => str.to_string(),
+        None => word,
+    }
+}
+
+fn split_url(url: &str) -> Option<UrlParts<'_>> {
+    let (scheme, rest) = url.split_once("://")?;
+    let rest = match rest.split_once('#') {
+        None => rest,
+        Some((rest, _fragment)) => rest,
+    };
+    let (rest, query) = match rest.split_once('?') {
+        None => (rest, None),
+        Some((rest, query)) => (rest, Some(query)),
+    };
+    let (host, path) = match rest.find('/') {
+        None => (rest, ""),
+        Some(index) => rest.split_at(index),
+    };
+    if host.is_empty() {
+        return None;
+    }
+    Some(UrlParts { scheme, host, path, query })
+}
+
+fn strip_www(host: &str) -> String {
+    host.strip_prefix("www.").unwrap_or(host).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::tests::test_init;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    fn canonical_url_test() {
+        test_init("canonical_url_test");
+
+        let expected = "https://www.oxfordlearnersdictionaries.com/definition/english/run_1";
+        assert_eq!(expected, canonical_url(expected));
+        assert_eq!(expected, canonical_url(
+            "https://www.oxfordlearnersdictionaries.com/definition/english/run_1?q=run"));
+        assert_eq!(expected, canonical_url(
+            "https://oxfordlearnersdictionaries.com/definition/english/run_1#run_sng_1"));
+        assert_eq!(expected, canonical_url(
+            "http://WWW.OxfordLearnersDictionaries.com/definition/english/run_1/"));
+
+        let expected = "https://www.oxfordlearnersdictionaries.com/search/english/?q=look+up";
+        assert_eq!(expected, canonical_url(expected));
+        assert_eq!(expected, canonical_url(
+            "https://oxfordlearnersdictionaries.com/search/english?q=look+up&page=2#top"));
+
+        assert_eq!(
+            "https://example.com/word?id=1",
+            canonical_url("https://www.Example.com/word/?id=1#top")
+        );
+        assert_eq!("url-1", canonical_url(" url-1 "));
+    }
+
+    #[wasm_bindgen_test]
+    fn dictionary_source_test() {
+        test_init("dictionary_source_test");
+
+        assert_eq!(
+            DictionarySource::OxfordLearners,
+            DictionarySource::from_url("https://www.oxfordlearnersdictionaries.com/")
+        );
+        assert_eq!(
+            DictionarySource::Other,
+            DictionarySource::from_url("https://www.example.com/")
+        );
+        assert_eq!(DictionarySource::Other, DictionarySource::from_url("url-1"));
+    }
+
+    #[wasm_bindgen_test]
+    fn lookup_url_test() {
+        test_init("lookup_url_test");
+
+        assert_eq!(
+            "https://www.oxfordlearnersdictionaries.com/search/english/?q=look+up",
+            lookup_url(" look up ")
+        );
+        assert_eq!(
+            "https://www.oxfordlearnersdictionaries.com/search/english/?q=caf%C3%A9%26co",
+            lookup_url("café&co")
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn headword_test() {
+        test_init("headword_test");
+
+        assert_eq!("run", headword("run".to_string(), "title"));
+        assert_eq!("run", headword(String::new(), "run - Oxford Learner's Dictionaries"));
+        assert_eq!("run", headword(String::new(), "run | Dictionary"));
+        assert_eq!("", headword(String::new(), "title"));
+    }
+}
@@ -0,0 +1,254 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+
+use super::Msg;
+
+pub(super) const MESSAGES: &[(Msg, &str)] = &[
+    (Msg::Folders, "Ordner"),
+    (Msg::Words, "Wörter"),
+    (Msg::Settings, "Einstellungen"),
+    (Msg::ShowAddFolderForm, "Formular für neue Ordner anzeigen"),
+    (Msg::ShowAddWordForm, "Formular für neue Wörter anzeigen"),
+    (Msg::FolderNameIsEmpty, "Der Ordnername ist leer"),
+    (Msg::FolderNameHasSeparator, "Der Ordnername darf \" / \" nicht enthalten, es trennt Unterordner"),
+    (Msg::NoConditions, "Bitte mindestens eine Bedingung auswählen"),
+    (Msg::WordIsEmpty, "Das Wort ist leer"),
+    (Msg::SelectFolderFirst, "Bitte zuerst einen Ordner auswählen"),
+    (Msg::DataSuccessfullyImported, "Daten erfolgreich importiert"),
+    (Msg::DataProtectionIsSet, "Der Datenschutz ist aktiv. Er kann in den Einstellungen deaktiviert werden"),
+    (Msg::WordWasDeleted, "Das Wort wurde gelöscht"),
+    (Msg::FolderWasDeleted, "Der Ordner wurde gelöscht"),
+    (Msg::SmartFolderWasDeleted, "Der intelligente Ordner wurde gelöscht"),
+    (Msg::SmartFolders, "Intelligente Ordner"),
+    (Msg::SmartFolderPath, "Intelligenter Ordner: "),
+    (Msg::NewSmartFolderName, "Name des neuen intelligenten Ordners"),
+    (Msg::AddedInDays, "hinzugefügt in Tagen"),
+    (Msg::TagInNotes, "#Tag in Notizen"),
+    (Msg::DueForReview, "zur Wiederholung fällig"),
+    (Msg::NoNoteYet, "noch keine Notiz"),
+    (Msg::AddSmartFolder, "Intelligenten Ordner hinzufügen"),
+    (Msg::DeleteSmartFolderKeepWords, "Intelligenten Ordner löschen, die Wörter bleiben erhalten"),
+    (Msg::ConditionAddedWithinDays, "in den letzten {0} Tagen hinzugefügt"),
+    (Msg::ConditionWordClass, "Wortart: {0}"),
+    (Msg::UseArrowKeysToNavigate, "Mit den Pfeiltasten nach oben und unten durch die Liste blättern"),
+    (Msg::AlreadySavedInFolders, "Bereits gespeichert im Ordner: {0}"),
+    (Msg::WordAlreadyInFolder, "'{0}' ist bereits in diesem Ordner"),
+    (Msg::WordWasUpdated, "Das Wort wurde aktualisiert"),
+    (Msg::BackToWords, "Zurück zu den Wörtern"),
+    (Msg::Edit, "Bearbeiten"),
+    (Msg::MoveToFolder, "In einen anderen Ordner verschieben"),
+    (Msg::OpenInDictionary, "Im Wörterbuch öffnen"),
+    (Msg::PlayAudio, "Audio abspielen"),
+    (Msg::DetailWord, "Wort"),
+    (Msg::DetailWordClass, "Wortart"),
+    (Msg::DetailFolder, "Ordner"),
+    (Msg::DetailUrl, "URL"),
+    (Msg::DetailAdded, "Hinzugefügt"),
+    (Msg::DetailNote, "Notiz"),
+    (Msg::DetailExamples, "Beispiele"),
+    (Msg::DetailContext, "Kontext"),
+    (Msg::DetailReviews, "Wiederholungen"),
+    (Msg::DetailAlsoIn, "Auch in"),
+    (Msg::ReviewSummary, "{0}, {1} richtig ({2} %), zuletzt: {3}"),
+    (Msg::NeverReviewed, "nie"),
+    (Msg::Remembered, "Gewusst"),
+    (Msg::Forgot, "Vergessen"),
+    (Msg::Save, "Speichern"),
+    (Msg::Move, "Verschieben"),
+    (Msg::ErrorConstraintViolation, "Ein Ordner oder Wort mit diesem Namen existiert bereits"),
+    (Msg::ErrorQuotaExceeded, "Der Browserspeicher ist voll. Exportiere deine Daten und lösche, was du nicht brauchst"),
+    (Msg::ErrorVersionMismatch, "Die Daten wurden von einer anderen Version der Erweiterung gespeichert"),
+    (Msg::ErrorCorruptData, "Einige Daten sind beschädigt. Repariere sie mit \"Datenbank prüfen\" in den Einstellungen"),
+    (Msg::ErrorNotFound, "Es wurde gelöscht oder kann nicht gefunden werden"),
+    (Msg::ErrorUnknown, "Auf den Speicher kann nicht zugegriffen werden, bitte erneut versuchen"),
+    (Msg::ErrorSettingsNotLoaded, "Die Einstellungen können nicht geladen werden, Änderungen werden erst nach dem erneuten Öffnen des Wörterbuchs gespeichert."),
+    (Msg::FirstPage, "Erste Seite"),
+    (Msg::PageLeft, "Seite zurück"),
+    (Msg::PageNumber, "Seitennummer"),
+    (Msg::TotalPages, "Seiten insgesamt"),
+    (Msg::PageRight, "Seite vor"),
+    (Msg::LastPage, "Letzte Seite"),
+    (Msg::Count, "Anzahl"),
+    (Msg::SortAscending, "Sortierung: aufsteigend"),
+    (Msg::SortDescending, "Sortierung: absteigend"),
+    (Msg::FilterWords, "Wörter, Notizen und Beispiele filtern"),
+    (Msg::FilterFolders, "Ordner und Notizen filtern"),
+    (Msg::ShowArchived, "archivierte anzeigen"),
+    (Msg::LookUpInDictionary, "Im Wörterbuch nachschlagen"),
+    (Msg::WordDetails, "Details zum Wort"),
+    (Msg::DeleteWord, "Das Wort löschen"),
+    (Msg::Expand, "Aufklappen"),
+    (Msg::Collapse, "Zuklappen"),
+    (Msg::PinToTop, "Oben anheften"),
+    (Msg::Unpin, "Loslösen"),
+    (Msg::ColourAndIcon, "Farbe und Symbol"),
+    (Msg::ArchiveWithSubfolders, "Mit Unterordnern archivieren"),
+    (Msg::RestoreFromArchive, "Aus dem Archiv wiederherstellen"),
+    (Msg::ExportWithSubfolders, "Den Ordner mit Unterordnern exportieren"),
+    (Msg::DeleteWithSubfolders, "Den Ordner mit Unterordnern und Wörtern löschen"),
+    (Msg::WordCountTitle, "Wörter im Ordner / mit Unterordnern"),
+    (Msg::FolderColour, "Ordnerfarbe"),
+    (Msg::FolderIcon, "Ordnersymbol"),
+    (Msg::NoColour, "keine Farbe"),
+    (Msg::NoIcon, "kein Symbol"),
+    (Msg::ColourRed, "rot"),
+    (Msg::ColourOrange, "orange"),
+    (Msg::ColourYellow, "gelb"),
+    (Msg::ColourGreen, "grün"),
+    (Msg::ColourBlue, "blau"),
+    (Msg::ColourPurple, "lila"),
+    (Msg::IconBook, "Buch"),
+    (Msg::IconBookmark, "Lesezeichen"),
+    (Msg::IconStar, "Stern"),
+    (Msg::IconFlag, "Flagge"),
+    (Msg::IconHeart, "Herz"),
+    (Msg::IconAward, "Auszeichnung"),
+    (Msg::SortFolders, "Ordner sortieren"),
+    (Msg::ParentFolder, "Übergeordneter Ordner"),
+    (Msg::SortByName, "nach Name"),
+    (Msg::SortByCreationDate, "nach Erstellungsdatum"),
+    (Msg::SortByLastAddedWord, "nach zuletzt hinzugefügtem Wort"),
+    (Msg::SortByWordCount, "nach Anzahl der Wörter"),
+    (Msg::FolderPath, "Ordner: "),
+    (Msg::SearchPlaceholder, "suchen"),
+    (Msg::Search, "Suchen"),
+    (Msg::NewFolderName, "Name des neuen Ordners"),
+    (Msg::FolderNote, "Notiz"),
+    (Msg::NoParentFolder, "kein übergeordneter Ordner"),
+    (Msg::AddFolder, "Ordner hinzufügen"),
+    (Msg::UrlToAdd, "Hinzuzufügende URL:"),
+    (Msg::WordOrLinkName, "Wort oder Name des Links"),
+    (Msg::WordClass, "Wortart"),
+    (Msg::WordNote, "Notiz oder Aussprache, **fett**, *kursiv*, - Liste"),
+    (Msg::WordExamples, "Beispielsätze, einer pro Zeile"),
+    (Msg::AddWord, "Wort hinzufügen"),
+    (Msg::Clear, "Leeren"),
+    (Msg::Show, "Anzeigen"),
+    (Msg::UpdateNoteAndUrl, "Notiz und URL aktualisieren"),
+    (Msg::Cancel, "Abbrechen"),
+    (Msg::Change, "Ändern"),
+    (Msg::Export, "Exportieren"),
+    (Msg::Import, "Importieren"),
+    (Msg::PageLength, "Seitenlänge"),
+    (Msg::PageLengthForFolders, "Seitenlänge für Ordner"),
+    (Msg::PageLengthForWords, "Seitenlänge für Wörter"),
+    (Msg::UnlimitedPageLength, "unbegrenzt"),
+    (Msg::PageLengthUpdatedToUnlimited, "Seitenlänge auf unbegrenzt geändert"),
+    (Msg::PageLengthUpdatedToNumber, "Seitenlänge auf {0} geändert"),
+    (Msg::DataProtection, "Datenschutz"),
+    (Msg::Delete, "Löschen"),
+    (Msg::DeleteWordQuestion, "Das Wort „{0}“ löschen?"),
+    (Msg::DeleteFolderQuestion, "Den Ordner „{0}“ mit {1} Unterordnern und {2} Wörtern löschen?"),
+    (Msg::DeleteSmartFolderQuestion, "Den intelligenten Ordner „{0}“ löschen? Seine Wörter bleiben erhalten"),
+    (Msg::TypeNameToConfirm, "Zur Bestätigung „{0}“ eingeben"),
+    (Msg::ProtectDeleteWord, "Löschen eines Wortes"),
+    (Msg::ProtectDeleteFolder, "Löschen eines Ordners"),
+    (Msg::ProtectDeleteSmartFolder, "Löschen eines intelligenten Ordners"),
+    (Msg::ProtectionConfirm, "Bestätigung verlangen"),
+    (Msg::ProtectionTypeName, "Namen eingeben lassen"),
+    (Msg::ProtectionProtected, "Nicht erlauben"),
+    (Msg::ProtectionUnprotected, "Ohne Nachfrage löschen"),
+    (Msg::Statistics, "Statistik"),
+    (Msg::StatsWordCount, "Wörter: {0}"),
+    (Msg::StatsReviews, "Wiederholungen: {0}, richtig: {1} %"),
+    (Msg::StatsNoReviews, "Noch keine Wiederholungen"),
+    (Msg::StatsCurrentStreak, "Aktuelle Serie: {0} Tage"),
+    (Msg::StatsLongestStreak, "Längste Serie: {0} Tage"),
+    (Msg::StatsAddedPerDay, "Hinzugefügte Wörter pro Tag"),
+    (Msg::StatsAddedPerWeek, "Hinzugefügte Wörter pro Woche"),
+    (Msg::StatsByDay, "pro Tag"),
+    (Msg::StatsByWeek, "pro Woche"),
+    (Msg::StatsPerFolder, "Wörter pro Ordner"),
+    (Msg::StatsPerWordClass, "Wörter pro Wortart"),
+    (Msg::StatsMostReviewed, "Am häufigsten wiederholte Wörter"),
+    (Msg::StatsNoWordClass, "ohne Wortart"),
+    (Msg::StatsReviewCount, "{0} Wiederholungen, {1} richtig"),
+    (Msg::DailyGoal, "Tagesziel"),
+    (Msg::DailyGoalWords, "Neue Wörter pro Tag"),
+    (Msg::DailyGoalReviews, "Wörter zum Wiederholen pro Tag"),
+    (Msg::DailyGoalHint, "0 bedeutet kein Ziel"),
+    (Msg::GoalWordsProgress, "Wörter heute: {0} / {1}"),
+    (Msg::GoalReviewsProgress, "Heute wiederholte Wörter: {0} / {1}"),
+    (Msg::GoalStreak, "Serie: {0} Tage"),
+    (Msg::PrintLayout, "Einen Ordner drucken"),
+    (Msg::PrintMode, "Layout"),
+    (Msg::PrintWordList, "Wortliste"),
+    (Msg::PrintFlashcards, "Karteikarten zum Ausschneiden"),
+    (Msg::PaperSize, "Papierformat"),
+    (Msg::FontFamily, "Schrift"),
+    (Msg::FontSerif, "Serifen"),
+    (Msg::FontSansSerif, "Serifenlos"),
+    (Msg::FontSize, "Schriftgröße"),
+    (Msg::OpenPrintLayout, "Zum Drucken öffnen"),
+    (Msg::PrintHeaderWord, "Wort"),
+    (Msg::PrintHeaderWordClass, "Wortart"),
+    (Msg::PrintHeaderPronunciation, "Aussprache"),
+    (Msg::PrintHeaderNote, "Notiz"),
+    (Msg::Autoplay, "Automatische Wiedergabe"),
+    (Msg::AutoplayIsEnabled, "Die automatische Wiedergabe ist aktiviert"),
+    (Msg::AutoplayIsDisabled, "Die automatische Wiedergabe ist deaktiviert"),
+    (Msg::QuickAdd, "Schnelles Hinzufügen"),
+    (Msg::QuickAddFolderHint, "Standardordner für das Kontextmenü und Strg+Umschalt+B"),
+    (Msg::QuickAddFolderIsNotSet, "nicht festgelegt"),
+    (Msg::QuickAddFolderNotFound, "Ordner '{0}' nicht gefunden"),
+    (Msg::QuickAddFolderUpdated, "Standardordner auf '{0}' geändert"),
+    (Msg::LookUpSavedSelections, "Gespeicherte Auswahl im Wörterbuch nachschlagen"),
+    (Msg::SavedSelectionsAreNotLookedUp, "Gespeicherte Auswahl wird nicht nachgeschlagen"),
+    (Msg::Language, "Sprache"),
+    (Msg::BrowserLanguage, "Sprache des Browsers"),
+    (Msg::Theme, "Design"),
+    (Msg::ThemeSystem, "Wie im System"),
+    (Msg::ThemeLight, "Hell"),
+    (Msg::ThemeDark, "Dunkel"),
+    (Msg::ThemeHighContrast, "Hoher Kontrast"),
+    (Msg::Database, "Datenbank"),
+    (Msg::KeyboardShortcuts, "Tastenkürzel"),
+    (Msg::PressAKey, "Taste drücken, Escape zum Abbrechen"),
+    (Msg::KeyIsAlreadyBound, "'{0}' ist bereits '{1}' zugewiesen"),
+    (Msg::ResetKeyBindings, "Auf Standard zurücksetzen"),
+    (Msg::Close, "Schließen"),
+    (Msg::KeyShowFolders, "Ordner anzeigen"),
+    (Msg::KeyShowWords, "Wörter anzeigen"),
+    (Msg::KeyShowSettings, "Einstellungen anzeigen"),
+    (Msg::KeyPreviousItem, "Vorherigen Eintrag auswählen"),
+    (Msg::KeyNextItem, "Nächsten Eintrag auswählen"),
+    (Msg::KeyPageLeft, "Vorherige Seite"),
+    (Msg::KeyPageRight, "Nächste Seite"),
+    (Msg::KeyOpen, "Ausgewählten Eintrag öffnen"),
+    (Msg::KeySearch, "Zum Filter springen"),
+    (Msg::KeyDelete, "Ausgewählten Eintrag löschen"),
+    (Msg::KeyEdit, "Ausgewählten Eintrag bearbeiten"),
+    (Msg::KeyHelp, "Tastenkürzel anzeigen"),
+    (Msg::CheckDatabase, "Datenbank prüfen"),
+    (Msg::Repair, "Reparieren"),
+    (Msg::Quarantine, "Quarantäne"),
+    (Msg::NoCorruptedRecords, "Keine beschädigten Einträge gefunden"),
+    (Msg::CorruptedRecords, "Beschädigte Einträge: {0}"),
+    (Msg::QuarantinedRecords, "Einträge in Quarantäne: {0}"),
+    (Msg::QuarantineHint, "Einträge, die nicht repariert werden können, kommen in Quarantäne und werden nicht mehr angezeigt"),
+    (Msg::CheckIntegrity, "Integrität prüfen"),
+    (Msg::NoIntegrityProblems, "Keine Integritätsprobleme gefunden"),
+    (Msg::WrongIds, "Das id-Feld von {0} Eintrag/Einträgen korrigieren"),
+    (Msg::MismatchedFolders, "{0} Wort/Wörter in den Ordner mit demselben Namen in anderer Schreibweise verschieben"),
+    (Msg::Conflicts, "{0} davon sind bereits in diesem Ordner und werden übersprungen"),
+    (Msg::OrphanWords, "Wörter ohne Ordner: {0}"),
+    (Msg::MissingFolders, "Fehlende Ordner: {0}"),
+    (Msg::RecordsChanged, "Geänderte Einträge: {0}"),
+    (Msg::OrphansKeep, "Die Wörter behalten"),
+    (Msg::OrphansCreateFolders, "Die fehlenden Ordner erstellen"),
+    (Msg::OrphansDeleteWords, "Die Wörter löschen"),
+];
@@ -0,0 +1,254 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+
+use super::Msg;
+
+pub(super) const MESSAGES: &[(Msg, &str)] = &[
+    (Msg::Folders, "Folders"),
+    (Msg::Words, "Words"),
+    (Msg::Settings, "Settings"),
+    (Msg::ShowAddFolderForm, "Show add folder form"),
+    (Msg::ShowAddWordForm, "Show add word form"),
+    (Msg::FolderNameIsEmpty, "Folder name is empty"),
+    (Msg::FolderNameHasSeparator, "Folder name can't contain \" / \", it separates subfolders"),
+    (Msg::NoConditions, "Please choose at least one condition"),
+    (Msg::WordIsEmpty, "Word is empty"),
+    (Msg::SelectFolderFirst, "Please select a folder first"),
+    (Msg::DataSuccessfullyImported, "Data successfully imported"),
+    (Msg::DataProtectionIsSet, "Data protection is set. Check the settings to disable it"),
+    (Msg::WordWasDeleted, "Word was deleted"),
+    (Msg::FolderWasDeleted, "Folder was deleted"),
+    (Msg::SmartFolderWasDeleted, "Smart folder was deleted"),
+    (Msg::SmartFolders, "Smart folders"),
+    (Msg::SmartFolderPath, "Smart folder: "),
+    (Msg::NewSmartFolderName, "new smart folder name"),
+    (Msg::AddedInDays, "added in days"),
+    (Msg::TagInNotes, "#tag in notes"),
+    (Msg::DueForReview, "due for review"),
+    (Msg::NoNoteYet, "no note yet"),
+    (Msg::AddSmartFolder, "Add smart folder"),
+    (Msg::DeleteSmartFolderKeepWords, "Delete the smart folder, the words are kept"),
+    (Msg::ConditionAddedWithinDays, "added in the last {0} days"),
+    (Msg::ConditionWordClass, "word class: {0}"),
+    (Msg::UseArrowKeysToNavigate, "Use the up and down arrow keys to navigate the list"),
+    (Msg::AlreadySavedInFolders, "Already saved in folder: {0}"),
+    (Msg::WordAlreadyInFolder, "'{0}' is already in this folder"),
+    (Msg::WordWasUpdated, "Word was updated"),
+    (Msg::BackToWords, "Back to words"),
+    (Msg::Edit, "Edit"),
+    (Msg::MoveToFolder, "Move to another folder"),
+    (Msg::OpenInDictionary, "Open in dictionary"),
+    (Msg::PlayAudio, "Play audio"),
+    (Msg::DetailWord, "Word"),
+    (Msg::DetailWordClass, "Word class"),
+    (Msg::DetailFolder, "Folder"),
+    (Msg::DetailUrl, "URL"),
+    (Msg::DetailAdded, "Added"),
+    (Msg::DetailNote, "Note"),
+    (Msg::DetailExamples, "Examples"),
+    (Msg::DetailContext, "Context"),
+    (Msg::DetailReviews, "Reviews"),
+    (Msg::DetailAlsoIn, "Also in"),
+    (Msg::ReviewSummary, "{0}, {1} correct ({2}%), last: {3}"),
+    (Msg::NeverReviewed, "never"),
+    (Msg::Remembered, "Remembered"),
+    (Msg::Forgot, "Forgot"),
+    (Msg::Save, "Save"),
+    (Msg::Move, "Move"),
+    (Msg::ErrorConstraintViolation, "A folder or word with this name already exists"),
+    (Msg::ErrorQuotaExceeded, "The browser storage is full. Export your data and delete what you don't need"),
+    (Msg::ErrorVersionMismatch, "The data was saved by a different version of the extension"),
+    (Msg::ErrorCorruptData, "Some data is damaged. Use \"Check database\" in the settings to repair it"),
+    (Msg::ErrorNotFound, "It was deleted or can't be found"),
+    (Msg::ErrorUnknown, "The storage can't be accessed, please try again"),
+    (Msg::ErrorSettingsNotLoaded, "The settings can't be loaded, changes to them won't be saved until the dictionary is reopened."),
+    (Msg::FirstPage, "First page"),
+    (Msg::PageLeft, "Page left"),
+    (Msg::PageNumber, "Page number"),
+    (Msg::TotalPages, "Total pages"),
+    (Msg::PageRight, "Page right"),
+    (Msg::LastPage, "Last page"),
+    (Msg::Count, "Count"),
+    (Msg::SortAscending, "Sort order: ascending"),
+    (Msg::SortDescending, "Sort order: descending"),
+    (Msg::FilterWords, "filter words, notes and examples"),
+    (Msg::FilterFolders, "filter folders and notes"),
+    (Msg::ShowArchived, "show archived"),
+    (Msg::LookUpInDictionary, "Look up in the dictionary"),
+    (Msg::WordDetails, "Word details"),
+    (Msg::DeleteWord, "Delete the word"),
+    (Msg::Expand, "Expand"),
+    (Msg::Collapse, "Collapse"),
+    (Msg::PinToTop, "Pin to top"),
+    (Msg::Unpin, "Unpin"),
+    (Msg::ColourAndIcon, "Colour and icon"),
+    (Msg::ArchiveWithSubfolders, "Archive with subfolders"),
+    (Msg::RestoreFromArchive, "Restore from the archive"),
+    (Msg::ExportWithSubfolders, "Export the folder with subfolders"),
+    (Msg::DeleteWithSubfolders, "Delete the folder with subfolders and words"),
+    (Msg::WordCountTitle, "words in the folder / with subfolders"),
+    (Msg::FolderColour, "Folder colour"),
+    (Msg::FolderIcon, "Folder icon"),
+    (Msg::NoColour, "no colour"),
+    (Msg::NoIcon, "no icon"),
+    (Msg::ColourRed, "red"),
+    (Msg::ColourOrange, "orange"),
+    (Msg::ColourYellow, "yellow"),
+    (Msg::ColourGreen, "green"),
+    (Msg::ColourBlue, "blue"),
+    (Msg::ColourPurple, "purple"),
+    (Msg::IconBook, "book"),
+    (Msg::IconBookmark, "bookmark"),
+    (Msg::IconStar, "star"),
+    (Msg::IconFlag, "flag"),
+    (Msg::IconHeart, "heart"),
+    (Msg::IconAward, "award"),
+    (Msg::SortFolders, "Sort folders"),
+    (Msg::ParentFolder, "Parent folder"),
+    (Msg::SortByName, "by name"),
+    (Msg::SortByCreationDate, "by creation date"),
+    (Msg::SortByLastAddedWord, "by last added word"),
+    (Msg::SortByWordCount, "by word count"),
+    (Msg::FolderPath, "Folder: "),
+    (Msg::SearchPlaceholder, "search"),
+    (Msg::Search, "Search"),
+    (Msg::NewFolderName, "new folder name"),
+    (Msg::FolderNote, "note"),
+    (Msg::NoParentFolder, "no parent folder"),
+    (Msg::AddFolder, "Add folder"),
+    (Msg::UrlToAdd, "URL to add:"),
+    (Msg::WordOrLinkName, "word or link name"),
+    (Msg::WordClass, "word class"),
+    (Msg::WordNote, "note or pronunciation, **bold**, *italic*, - list"),
+    (Msg::WordExamples, "example sentences, one per line"),
+    (Msg::AddWord, "Add word"),
+    (Msg::Clear, "Clear"),
+    (Msg::Show, "Show"),
+    (Msg::UpdateNoteAndUrl, "Update note and URL"),
+    (Msg::Cancel, "Cancel"),
+    (Msg::Change, "Change"),
+    (Msg::Export, "Export"),
+    (Msg::Import, "Import"),
+    (Msg::PageLength, "Page length"),
+    (Msg::PageLengthForFolders, "page length for folders"),
+    (Msg::PageLengthForWords, "page length for words"),
+    (Msg::UnlimitedPageLength, "unlimited"),
+    (Msg::PageLengthUpdatedToUnlimited, "page length updated to unlimited"),
+    (Msg::PageLengthUpdatedToNumber, "page length updated to {0}"),
+    (Msg::DataProtection, "Data protection"),
+    (Msg::Delete, "Delete"),
+    (Msg::DeleteWordQuestion, "Delete the word \"{0}\"?"),
+    (Msg::DeleteFolderQuestion, "Delete the folder \"{0}\" with {1} subfolders and {2} words?"),
+    (Msg::DeleteSmartFolderQuestion, "Delete the smart folder \"{0}\"? Its words are kept"),
+    (Msg::TypeNameToConfirm, "Type \"{0}\" to confirm"),
+    (Msg::ProtectDeleteWord, "Deleting a word"),
+    (Msg::ProtectDeleteFolder, "Deleting a folder"),
+    (Msg::ProtectDeleteSmartFolder, "Deleting a smart folder"),
+    (Msg::ProtectionConfirm, "Ask for a confirmation"),
+    (Msg::ProtectionTypeName, "Ask to type the name"),
+    (Msg::ProtectionProtected, "Don't allow"),
+    (Msg::ProtectionUnprotected, "Delete without asking"),
+    (Msg::Statistics, "Statistics"),
+    (Msg::StatsWordCount, "Words: {0}"),
+    (Msg::StatsReviews, "Reviews: {0}, correct: {1}%"),
+    (Msg::StatsNoReviews, "No reviews yet"),
+    (Msg::StatsCurrentStreak, "Current streak: {0} days"),
+    (Msg::StatsLongestStreak, "Longest streak: {0} days"),
+    (Msg::StatsAddedPerDay, "Words added per day"),
+    (Msg::StatsAddedPerWeek, "Words added per week"),
+    (Msg::StatsByDay, "by day"),
+    (Msg::StatsByWeek, "by week"),
+    (Msg::StatsPerFolder, "Words per folder"),
+    (Msg::StatsPerWordClass, "Words per word class"),
+    (Msg::StatsMostReviewed, "Most reviewed words"),
+    (Msg::StatsNoWordClass, "no word class"),
+    (Msg::StatsReviewCount, "{0} reviews, {1} correct"),
+    (Msg::DailyGoal, "Daily goal"),
+    (Msg::DailyGoalWords, "New words per day"),
+    (Msg::DailyGoalReviews, "Words to review per day"),
+    (Msg::DailyGoalHint, "0 is no goal"),
+    (Msg::GoalWordsProgress, "Words today: {0} / {1}"),
+    (Msg::GoalReviewsProgress, "Words reviewed today: {0} / {1}"),
+    (Msg::GoalStreak, "Streak: {0} days"),
+    (Msg::PrintLayout, "Print a folder"),
+    (Msg::PrintMode, "Layout"),
+    (Msg::PrintWordList, "Word list"),
+    (Msg::PrintFlashcards, "Flashcards to cut out"),
+    (Msg::PaperSize, "Paper size"),
+    (Msg::FontFamily, "Font"),
+    (Msg::FontSerif, "Serif"),
+    (Msg::FontSansSerif, "Sans serif"),
+    (Msg::FontSize, "Font size"),
+    (Msg::OpenPrintLayout, "Open for printing"),
+    (Msg::PrintHeaderWord, "Word"),
+    (Msg::PrintHeaderWordClass, "Word class"),
+    (Msg::PrintHeaderPronunciation, "Pronunciation"),
+    (Msg::PrintHeaderNote, "Note"),
+    (Msg::Autoplay, "Autoplay"),
+    (Msg::AutoplayIsEnabled, "Autoplay is enabled"),
+    (Msg::AutoplayIsDisabled, "Autoplay is disabled"),
+    (Msg::QuickAdd, "Quick add"),
+    (Msg::QuickAddFolderHint, "Default folder for the context menu and Ctrl+Shift+B"),
+    (Msg::QuickAddFolderIsNotSet, "not set"),
+    (Msg::QuickAddFolderNotFound, "folder '{0}' not found"),
+    (Msg::QuickAddFolderUpdated, "default folder updated to '{0}'"),
+    (Msg::LookUpSavedSelections, "Look up saved selections in the dictionary"),
+    (Msg::SavedSelectionsAreNotLookedUp, "Saved selections are not looked up"),
+    (Msg::Language, "Language"),
+    (Msg::BrowserLanguage, "Browser language"),
+    (Msg::Theme, "Theme"),
+    (Msg::ThemeSystem, "As in the system"),
+    (Msg::ThemeLight, "Light"),
+    (Msg::ThemeDark, "Dark"),
+    (Msg::ThemeHighContrast, "High contrast"),
+    (Msg::Database, "Database"),
+    (Msg::KeyboardShortcuts, "Keyboard shortcuts"),
+    (Msg::PressAKey, "press a key, Escape to cancel"),
+    (Msg::KeyIsAlreadyBound, "'{0}' is already bound to '{1}'"),
+    (Msg::ResetKeyBindings, "Reset to defaults"),
+    (Msg::Close, "Close"),
+    (Msg::KeyShowFolders, "Show folders"),
+    (Msg::KeyShowWords, "Show words"),
+    (Msg::KeyShowSettings, "Show settings"),
+    (Msg::KeyPreviousItem, "Select the previous item"),
+    (Msg::KeyNextItem, "Select the next item"),
+    (Msg::KeyPageLeft, "Previous page"),
+    (Msg::KeyPageRight, "Next page"),
+    (Msg::KeyOpen, "Open the selected item"),
+    (Msg::KeySearch, "Focus the filter"),
+    (Msg::KeyDelete, "Delete the selected item"),
+    (Msg::KeyEdit, "Edit the selected item"),
+    (Msg::KeyHelp, "Show keyboard shortcuts"),
+    (Msg::CheckDatabase, "Check database"),
+    (Msg::Repair, "Repair"),
+    (Msg::Quarantine, "Quarantine"),
+    (Msg::NoCorruptedRecords, "No corrupted records found"),
+    (Msg::CorruptedRecords, "Corrupted records: {0}"),
+    (Msg::QuarantinedRecords, "Records in quarantine: {0}"),
+    (Msg::QuarantineHint, "Records which can't be repaired are moved to quarantine and are no longer shown"),
+    (Msg::CheckIntegrity, "Check integrity"),
+    (Msg::NoIntegrityProblems, "No integrity problems found"),
+    (Msg::WrongIds, "Fix the id field of {0} record(s)"),
+    (Msg::MismatchedFolders, "Move {0} word(s) to the folder with the same name in a different case or spacing"),
+    (Msg::Conflicts, "{0} of them are already in that folder and are skipped"),
+    (Msg::OrphanWords, "Words without a folder: {0}"),
+    (Msg::MissingFolders, "Missing folders: {0}"),
+    (Msg::RecordsChanged, "Records changed: {0}"),
+    (Msg::OrphansKeep, "Keep the words"),
+    (Msg::OrphansCreateFolders, "Create the missing folders"),
+    (Msg::OrphansDeleteWords, "Delete the words"),
+];
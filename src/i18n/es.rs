@@ -0,0 +1,254 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+
+use super::Msg;
+
+pub(super) const MESSAGES: &[(Msg, &str)] = &[
+    (Msg::Folders, "Carpetas"),
+    (Msg::Words, "Palabras"),
+    (Msg::Settings, "Ajustes"),
+    (Msg::ShowAddFolderForm, "Mostrar el formulario de nueva carpeta"),
+    (Msg::ShowAddWordForm, "Mostrar el formulario de nueva palabra"),
+    (Msg::FolderNameIsEmpty, "El nombre de la carpeta está vacío"),
+    (Msg::FolderNameHasSeparator, "El nombre de la carpeta no puede contener \" / \", separa las subcarpetas"),
+    (Msg::NoConditions, "Elige al menos una condición"),
+    (Msg::WordIsEmpty, "La palabra está vacía"),
+    (Msg::SelectFolderFirst, "Primero selecciona una carpeta"),
+    (Msg::DataSuccessfullyImported, "Datos importados correctamente"),
+    (Msg::DataProtectionIsSet, "La protección de datos está activada. Desactívala en los ajustes"),
+    (Msg::WordWasDeleted, "La palabra se ha eliminado"),
+    (Msg::FolderWasDeleted, "La carpeta se ha eliminado"),
+    (Msg::SmartFolderWasDeleted, "La carpeta inteligente se ha eliminado"),
+    (Msg::SmartFolders, "Carpetas inteligentes"),
+    (Msg::SmartFolderPath, "Carpeta inteligente: "),
+    (Msg::NewSmartFolderName, "nombre de la nueva carpeta inteligente"),
+    (Msg::AddedInDays, "añadidas en días"),
+    (Msg::TagInNotes, "#etiqueta en notas"),
+    (Msg::DueForReview, "pendientes de repaso"),
+    (Msg::NoNoteYet, "sin nota todavía"),
+    (Msg::AddSmartFolder, "Añadir carpeta inteligente"),
+    (Msg::DeleteSmartFolderKeepWords, "Eliminar la carpeta inteligente, las palabras se conservan"),
+    (Msg::ConditionAddedWithinDays, "añadidas en los últimos {0} días"),
+    (Msg::ConditionWordClass, "categoría gramatical: {0}"),
+    (Msg::UseArrowKeysToNavigate, "Usa las flechas arriba y abajo para recorrer la lista"),
+    (Msg::AlreadySavedInFolders, "Ya guardada en la carpeta: {0}"),
+    (Msg::WordAlreadyInFolder, "'{0}' ya está en esta carpeta"),
+    (Msg::WordWasUpdated, "La palabra se ha actualizado"),
+    (Msg::BackToWords, "Volver a las palabras"),
+    (Msg::Edit, "Editar"),
+    (Msg::MoveToFolder, "Mover a otra carpeta"),
+    (Msg::OpenInDictionary, "Abrir en el diccionario"),
+    (Msg::PlayAudio, "Reproducir audio"),
+    (Msg::DetailWord, "Palabra"),
+    (Msg::DetailWordClass, "Categoría gramatical"),
+    (Msg::DetailFolder, "Carpeta"),
+    (Msg::DetailUrl, "URL"),
+    (Msg::DetailAdded, "Añadida"),
+    (Msg::DetailNote, "Nota"),
+    (Msg::DetailExamples, "Ejemplos"),
+    (Msg::DetailContext, "Contexto"),
+    (Msg::DetailReviews, "Repasos"),
+    (Msg::DetailAlsoIn, "También en"),
+    (Msg::ReviewSummary, "{0}, {1} correctas ({2} %), último: {3}"),
+    (Msg::NeverReviewed, "nunca"),
+    (Msg::Remembered, "Recordada"),
+    (Msg::Forgot, "Olvidada"),
+    (Msg::Save, "Guardar"),
+    (Msg::Move, "Mover"),
+    (Msg::ErrorConstraintViolation, "Ya existe una carpeta o palabra con este nombre"),
+    (Msg::ErrorQuotaExceeded, "El almacenamiento del navegador está lleno. Exporta tus datos y elimina lo que no necesites"),
+    (Msg::ErrorVersionMismatch, "Los datos se guardaron con otra versión de la extensión"),
+    (Msg::ErrorCorruptData, "Algunos datos están dañados. Usa \"Comprobar la base de datos\" en los ajustes para repararlos"),
+    (Msg::ErrorNotFound, "Se ha eliminado o no se encuentra"),
+    (Msg::ErrorUnknown, "No se puede acceder al almacenamiento, inténtalo de nuevo"),
+    (Msg::ErrorSettingsNotLoaded, "No se pueden cargar los ajustes, los cambios no se guardarán hasta que se vuelva a abrir el diccionario."),
+    (Msg::FirstPage, "Primera página"),
+    (Msg::PageLeft, "Página anterior"),
+    (Msg::PageNumber, "Número de página"),
+    (Msg::TotalPages, "Total de páginas"),
+    (Msg::PageRight, "Página siguiente"),
+    (Msg::LastPage, "Última página"),
+    (Msg::Count, "Cantidad"),
+    (Msg::SortAscending, "Orden: ascendente"),
+    (Msg::SortDescending, "Orden: descendente"),
+    (Msg::FilterWords, "filtrar palabras, notas y ejemplos"),
+    (Msg::FilterFolders, "filtrar carpetas y notas"),
+    (Msg::ShowArchived, "mostrar archivadas"),
+    (Msg::LookUpInDictionary, "Buscar en el diccionario"),
+    (Msg::WordDetails, "Detalles de la palabra"),
+    (Msg::DeleteWord, "Eliminar la palabra"),
+    (Msg::Expand, "Expandir"),
+    (Msg::Collapse, "Contraer"),
+    (Msg::PinToTop, "Fijar arriba"),
+    (Msg::Unpin, "Desfijar"),
+    (Msg::ColourAndIcon, "Color e icono"),
+    (Msg::ArchiveWithSubfolders, "Archivar con subcarpetas"),
+    (Msg::RestoreFromArchive, "Restaurar del archivo"),
+    (Msg::ExportWithSubfolders, "Exportar la carpeta con subcarpetas"),
+    (Msg::DeleteWithSubfolders, "Eliminar la carpeta con subcarpetas y palabras"),
+    (Msg::WordCountTitle, "palabras en la carpeta / con subcarpetas"),
+    (Msg::FolderColour, "Color de la carpeta"),
+    (Msg::FolderIcon, "Icono de la carpeta"),
+    (Msg::NoColour, "sin color"),
+    (Msg::NoIcon, "sin icono"),
+    (Msg::ColourRed, "rojo"),
+    (Msg::ColourOrange, "naranja"),
+    (Msg::ColourYellow, "amarillo"),
+    (Msg::ColourGreen, "verde"),
+    (Msg::ColourBlue, "azul"),
+    (Msg::ColourPurple, "morado"),
+    (Msg::IconBook, "libro"),
+    (Msg::IconBookmark, "marcador"),
+    (Msg::IconStar, "estrella"),
+    (Msg::IconFlag, "bandera"),
+    (Msg::IconHeart, "corazón"),
+    (Msg::IconAward, "premio"),
+    (Msg::SortFolders, "Ordenar carpetas"),
+    (Msg::ParentFolder, "Carpeta superior"),
+    (Msg::SortByName, "por nombre"),
+    (Msg::SortByCreationDate, "por fecha de creación"),
+    (Msg::SortByLastAddedWord, "por última palabra añadida"),
+    (Msg::SortByWordCount, "por número de palabras"),
+    (Msg::FolderPath, "Carpeta: "),
+    (Msg::SearchPlaceholder, "buscar"),
+    (Msg::Search, "Buscar"),
+    (Msg::NewFolderName, "nombre de la nueva carpeta"),
+    (Msg::FolderNote, "nota"),
+    (Msg::NoParentFolder, "sin carpeta superior"),
+    (Msg::AddFolder, "Añadir carpeta"),
+    (Msg::UrlToAdd, "URL que se añadirá:"),
+    (Msg::WordOrLinkName, "palabra o nombre del enlace"),
+    (Msg::WordClass, "categoría gramatical"),
+    (Msg::WordNote, "nota o pronunciación, **negrita**, *cursiva*, - lista"),
+    (Msg::WordExamples, "frases de ejemplo, una por línea"),
+    (Msg::AddWord, "Añadir palabra"),
+    (Msg::Clear, "Borrar"),
+    (Msg::Show, "Mostrar"),
+    (Msg::UpdateNoteAndUrl, "Actualizar la nota y la URL"),
+    (Msg::Cancel, "Cancelar"),
+    (Msg::Change, "Cambiar"),
+    (Msg::Export, "Exportar"),
+    (Msg::Import, "Importar"),
+    (Msg::PageLength, "Tamaño de página"),
+    (Msg::PageLengthForFolders, "tamaño de página de carpetas"),
+    (Msg::PageLengthForWords, "tamaño de página de palabras"),
+    (Msg::UnlimitedPageLength, "ilimitado"),
+    (Msg::PageLengthUpdatedToUnlimited, "tamaño de página cambiado a ilimitado"),
+    (Msg::PageLengthUpdatedToNumber, "tamaño de página cambiado a {0}"),
+    (Msg::DataProtection, "Protección de datos"),
+    (Msg::Delete, "Eliminar"),
+    (Msg::DeleteWordQuestion, "¿Eliminar la palabra «{0}»?"),
+    (Msg::DeleteFolderQuestion, "¿Eliminar la carpeta «{0}» con {1} subcarpetas y {2} palabras?"),
+    (Msg::DeleteSmartFolderQuestion, "¿Eliminar la carpeta inteligente «{0}»? Sus palabras se conservan"),
+    (Msg::TypeNameToConfirm, "Escribe «{0}» para confirmar"),
+    (Msg::ProtectDeleteWord, "Eliminar una palabra"),
+    (Msg::ProtectDeleteFolder, "Eliminar una carpeta"),
+    (Msg::ProtectDeleteSmartFolder, "Eliminar una carpeta inteligente"),
+    (Msg::ProtectionConfirm, "Pedir confirmación"),
+    (Msg::ProtectionTypeName, "Pedir que se escriba el nombre"),
+    (Msg::ProtectionProtected, "No permitir"),
+    (Msg::ProtectionUnprotected, "Eliminar sin preguntar"),
+    (Msg::Statistics, "Estadísticas"),
+    (Msg::StatsWordCount, "Palabras: {0}"),
+    (Msg::StatsReviews, "Repasos: {0}, correctos: {1} %"),
+    (Msg::StatsNoReviews, "Aún no hay repasos"),
+    (Msg::StatsCurrentStreak, "Racha actual: {0} días"),
+    (Msg::StatsLongestStreak, "Racha más larga: {0} días"),
+    (Msg::StatsAddedPerDay, "Palabras añadidas por día"),
+    (Msg::StatsAddedPerWeek, "Palabras añadidas por semana"),
+    (Msg::StatsByDay, "por día"),
+    (Msg::StatsByWeek, "por semana"),
+    (Msg::StatsPerFolder, "Palabras por carpeta"),
+    (Msg::StatsPerWordClass, "Palabras por categoría gramatical"),
+    (Msg::StatsMostReviewed, "Palabras más repasadas"),
+    (Msg::StatsNoWordClass, "sin categoría"),
+    (Msg::StatsReviewCount, "{0} repasos, {1} correctos"),
+    (Msg::DailyGoal, "Objetivo diario"),
+    (Msg::DailyGoalWords, "Palabras nuevas por día"),
+    (Msg::DailyGoalReviews, "Palabras para repasar por día"),
+    (Msg::DailyGoalHint, "0 significa sin objetivo"),
+    (Msg::GoalWordsProgress, "Palabras hoy: {0} / {1}"),
+    (Msg::GoalReviewsProgress, "Palabras repasadas hoy: {0} / {1}"),
+    (Msg::GoalStreak, "Racha: {0} días"),
+    (Msg::PrintLayout, "Imprimir una carpeta"),
+    (Msg::PrintMode, "Diseño"),
+    (Msg::PrintWordList, "Lista de palabras"),
+    (Msg::PrintFlashcards, "Tarjetas para recortar"),
+    (Msg::PaperSize, "Tamaño del papel"),
+    (Msg::FontFamily, "Fuente"),
+    (Msg::FontSerif, "Con serifa"),
+    (Msg::FontSansSerif, "Sin serifa"),
+    (Msg::FontSize, "Tamaño de fuente"),
+    (Msg::OpenPrintLayout, "Abrir para imprimir"),
+    (Msg::PrintHeaderWord, "Palabra"),
+    (Msg::PrintHeaderWordClass, "Categoría"),
+    (Msg::PrintHeaderPronunciation, "Pronunciación"),
+    (Msg::PrintHeaderNote, "Nota"),
+    (Msg::Autoplay, "Reproducción automática"),
+    (Msg::AutoplayIsEnabled, "La reproducción automática está activada"),
+    (Msg::AutoplayIsDisabled, "La reproducción automática está desactivada"),
+    (Msg::QuickAdd, "Añadir rápido"),
+    (Msg::QuickAddFolderHint, "Carpeta predeterminada para el menú contextual y Ctrl+Mayús+B"),
+    (Msg::QuickAddFolderIsNotSet, "sin definir"),
+    (Msg::QuickAddFolderNotFound, "no se encontró la carpeta '{0}'"),
+    (Msg::QuickAddFolderUpdated, "carpeta predeterminada cambiada a '{0}'"),
+    (Msg::LookUpSavedSelections, "Buscar en el diccionario las selecciones guardadas"),
+    (Msg::SavedSelectionsAreNotLookedUp, "Las selecciones guardadas no se buscan"),
+    (Msg::Language, "Idioma"),
+    (Msg::BrowserLanguage, "Idioma del navegador"),
+    (Msg::Theme, "Tema"),
+    (Msg::ThemeSystem, "Como en el sistema"),
+    (Msg::ThemeLight, "Claro"),
+    (Msg::ThemeDark, "Oscuro"),
+    (Msg::ThemeHighContrast, "Alto contraste"),
+    (Msg::Database, "Base de datos"),
+    (Msg::KeyboardShortcuts, "Atajos de teclado"),
+    (Msg::PressAKey, "pulsa una tecla, Escape para cancelar"),
+    (Msg::KeyIsAlreadyBound, "'{0}' ya está asignada a '{1}'"),
+    (Msg::ResetKeyBindings, "Restablecer valores predeterminados"),
+    (Msg::Close, "Cerrar"),
+    (Msg::KeyShowFolders, "Mostrar carpetas"),
+    (Msg::KeyShowWords, "Mostrar palabras"),
+    (Msg::KeyShowSettings, "Mostrar ajustes"),
+    (Msg::KeyPreviousItem, "Seleccionar el elemento anterior"),
+    (Msg::KeyNextItem, "Seleccionar el elemento siguiente"),
+    (Msg::KeyPageLeft, "Página anterior"),
+    (Msg::KeyPageRight, "Página siguiente"),
+    (Msg::KeyOpen, "Abrir el elemento seleccionado"),
+    (Msg::KeySearch, "Ir al filtro"),
+    (Msg::KeyDelete, "Eliminar el elemento seleccionado"),
+    (Msg::KeyEdit, "Editar el elemento seleccionado"),
+    (Msg::KeyHelp, "Mostrar los atajos de teclado"),
+    (Msg::CheckDatabase, "Comprobar la base de datos"),
+    (Msg::Repair, "Reparar"),
+    (Msg::Quarantine, "Cuarentena"),
+    (Msg::NoCorruptedRecords, "No se encontraron registros dañados"),
+    (Msg::CorruptedRecords, "Registros dañados: {0}"),
+    (Msg::QuarantinedRecords, "Registros en cuarentena: {0}"),
+    (Msg::QuarantineHint, "Los registros que no se pueden reparar pasan a cuarentena y ya no se muestran"),
+    (Msg::CheckIntegrity, "Comprobar la integridad"),
+    (Msg::NoIntegrityProblems, "No se encontraron problemas de integridad"),
+    (Msg::WrongIds, "Corregir el campo id de {0} registro(s)"),
+    (Msg::MismatchedFolders, "Mover {0} palabra(s) a la carpeta con el mismo nombre escrito con otras mayúsculas o espacios"),
+    (Msg::Conflicts, "{0} de ellas ya están en esa carpeta y se omiten"),
+    (Msg::OrphanWords, "Palabras sin carpeta: {0}"),
+    (Msg::MissingFolders, "Carpetas que faltan: {0}"),
+    (Msg::RecordsChanged, "Registros modificados: {0}"),
+    (Msg::OrphansKeep, "Conservar las palabras"),
+    (Msg::OrphansCreateFolders, "Crear las carpetas que faltan"),
+    (Msg::OrphansDeleteWords, "Eliminar las palabras"),
+];
@@ -0,0 +1,254 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+
+use super::Msg;
+
+pub(super) const MESSAGES: &[(Msg, &str)] = &[
+    (Msg::Folders, "フォルダー"),
+    (Msg::Words, "単語"),
+    (Msg::Settings, "設定"),
+    (Msg::ShowAddFolderForm, "フォルダー追加フォームを表示"),
+    (Msg::ShowAddWordForm, "単語追加フォームを表示"),
+    (Msg::FolderNameIsEmpty, "フォルダー名が空です"),
+    (Msg::FolderNameHasSeparator, "フォルダー名に \" / \" は使えません。サブフォルダーの区切りです"),
+    (Msg::NoConditions, "条件を 1 つ以上選択してください"),
+    (Msg::WordIsEmpty, "単語が空です"),
+    (Msg::SelectFolderFirst, "先にフォルダーを選択してください"),
+    (Msg::DataSuccessfullyImported, "データをインポートしました"),
+    (Msg::DataProtectionIsSet, "データ保護が有効です。設定で無効にできます"),
+    (Msg::WordWasDeleted, "単語を削除しました"),
+    (Msg::FolderWasDeleted, "フォルダーを削除しました"),
+    (Msg::SmartFolderWasDeleted, "スマートフォルダーを削除しました"),
+    (Msg::SmartFolders, "スマートフォルダー"),
+    (Msg::SmartFolderPath, "スマートフォルダー: "),
+    (Msg::NewSmartFolderName, "新しいスマートフォルダーの名前"),
+    (Msg::AddedInDays, "追加からの日数"),
+    (Msg::TagInNotes, "メモの#タグ"),
+    (Msg::DueForReview, "復習の時期"),
+    (Msg::NoNoteYet, "メモなし"),
+    (Msg::AddSmartFolder, "スマートフォルダーを追加"),
+    (Msg::DeleteSmartFolderKeepWords, "スマートフォルダーを削除します（単語は残ります）"),
+    (Msg::ConditionAddedWithinDays, "過去{0}日間に追加"),
+    (Msg::ConditionWordClass, "品詞: {0}"),
+    (Msg::UseArrowKeysToNavigate, "上下の矢印キーでリストを移動できます"),
+    (Msg::AlreadySavedInFolders, "保存済みのフォルダー: {0}"),
+    (Msg::WordAlreadyInFolder, "'{0}' はすでにこのフォルダーにあります"),
+    (Msg::WordWasUpdated, "単語を更新しました"),
+    (Msg::BackToWords, "単語一覧に戻る"),
+    (Msg::Edit, "編集"),
+    (Msg::MoveToFolder, "別のフォルダーに移動"),
+    (Msg::OpenInDictionary, "辞書で開く"),
+    (Msg::PlayAudio, "音声を再生"),
+    (Msg::DetailWord, "単語"),
+    (Msg::DetailWordClass, "品詞"),
+    (Msg::DetailFolder, "フォルダー"),
+    (Msg::DetailUrl, "URL"),
+    (Msg::DetailAdded, "追加日"),
+    (Msg::DetailNote, "メモ"),
+    (Msg::DetailExamples, "例文"),
+    (Msg::DetailContext, "文脈"),
+    (Msg::DetailReviews, "復習"),
+    (Msg::DetailAlsoIn, "他のフォルダー"),
+    (Msg::ReviewSummary, "{0}回、正解{1}回（{2}%）、最終: {3}"),
+    (Msg::NeverReviewed, "なし"),
+    (Msg::Remembered, "覚えていた"),
+    (Msg::Forgot, "忘れた"),
+    (Msg::Save, "保存"),
+    (Msg::Move, "移動"),
+    (Msg::ErrorConstraintViolation, "同じ名前のフォルダーまたは単語がすでに存在します"),
+    (Msg::ErrorQuotaExceeded, "ブラウザーのストレージがいっぱいです。データをエクスポートして不要なものを削除してください"),
+    (Msg::ErrorVersionMismatch, "データは別のバージョンの拡張機能で保存されました"),
+    (Msg::ErrorCorruptData, "一部のデータが破損しています。設定の「データベースを確認」で修復してください"),
+    (Msg::ErrorNotFound, "削除されたか、見つかりません"),
+    (Msg::ErrorUnknown, "ストレージにアクセスできません。もう一度お試しください"),
+    (Msg::ErrorSettingsNotLoaded, "設定を読み込めません。辞書を開き直すまで変更は保存されません。"),
+    (Msg::FirstPage, "最初のページ"),
+    (Msg::PageLeft, "前のページ"),
+    (Msg::PageNumber, "ページ番号"),
+    (Msg::TotalPages, "総ページ数"),
+    (Msg::PageRight, "次のページ"),
+    (Msg::LastPage, "最後のページ"),
+    (Msg::Count, "件数"),
+    (Msg::SortAscending, "並び順: 昇順"),
+    (Msg::SortDescending, "並び順: 降順"),
+    (Msg::FilterWords, "単語・メモ・例文で絞り込み"),
+    (Msg::FilterFolders, "フォルダー・メモで絞り込み"),
+    (Msg::ShowArchived, "アーカイブを表示"),
+    (Msg::LookUpInDictionary, "辞書で調べる"),
+    (Msg::WordDetails, "単語の詳細"),
+    (Msg::DeleteWord, "単語を削除"),
+    (Msg::Expand, "展開"),
+    (Msg::Collapse, "折りたたむ"),
+    (Msg::PinToTop, "上部に固定"),
+    (Msg::Unpin, "固定を解除"),
+    (Msg::ColourAndIcon, "色とアイコン"),
+    (Msg::ArchiveWithSubfolders, "サブフォルダーごとアーカイブ"),
+    (Msg::RestoreFromArchive, "アーカイブから戻す"),
+    (Msg::ExportWithSubfolders, "フォルダーをサブフォルダーごとエクスポート"),
+    (Msg::DeleteWithSubfolders, "フォルダーをサブフォルダーと単語ごと削除"),
+    (Msg::WordCountTitle, "フォルダー内の単語数 / サブフォルダーを含む"),
+    (Msg::FolderColour, "フォルダーの色"),
+    (Msg::FolderIcon, "フォルダーのアイコン"),
+    (Msg::NoColour, "色なし"),
+    (Msg::NoIcon, "アイコンなし"),
+    (Msg::ColourRed, "赤"),
+    (Msg::ColourOrange, "オレンジ"),
+    (Msg::ColourYellow, "黄"),
+    (Msg::ColourGreen, "緑"),
+    (Msg::ColourBlue, "青"),
+    (Msg::ColourPurple, "紫"),
+    (Msg::IconBook, "本"),
+    (Msg::IconBookmark, "しおり"),
+    (Msg::IconStar, "星"),
+    (Msg::IconFlag, "旗"),
+    (Msg::IconHeart, "ハート"),
+    (Msg::IconAward, "賞"),
+    (Msg::SortFolders, "フォルダーの並べ替え"),
+    (Msg::ParentFolder, "親フォルダー"),
+    (Msg::SortByName, "名前順"),
+    (Msg::SortByCreationDate, "作成日順"),
+    (Msg::SortByLastAddedWord, "最後に追加した単語順"),
+    (Msg::SortByWordCount, "単語数順"),
+    (Msg::FolderPath, "フォルダー: "),
+    (Msg::SearchPlaceholder, "検索"),
+    (Msg::Search, "検索"),
+    (Msg::NewFolderName, "新しいフォルダー名"),
+    (Msg::FolderNote, "メモ"),
+    (Msg::NoParentFolder, "親フォルダーなし"),
+    (Msg::AddFolder, "フォルダーを追加"),
+    (Msg::UrlToAdd, "追加する URL:"),
+    (Msg::WordOrLinkName, "単語またはリンク名"),
+    (Msg::WordClass, "品詞"),
+    (Msg::WordNote, "メモまたは発音、**太字**、*斜体*、- リスト"),
+    (Msg::WordExamples, "例文 (1 行に 1 つ)"),
+    (Msg::AddWord, "単語を追加"),
+    (Msg::Clear, "クリア"),
+    (Msg::Show, "表示"),
+    (Msg::UpdateNoteAndUrl, "メモと URL を更新"),
+    (Msg::Cancel, "キャンセル"),
+    (Msg::Change, "変更"),
+    (Msg::Export, "エクスポート"),
+    (Msg::Import, "インポート"),
+    (Msg::PageLength, "1 ページの件数"),
+    (Msg::PageLengthForFolders, "フォルダーの 1 ページの件数"),
+    (Msg::PageLengthForWords, "単語の 1 ページの件数"),
+    (Msg::UnlimitedPageLength, "無制限"),
+    (Msg::PageLengthUpdatedToUnlimited, "1 ページの件数を無制限に変更しました"),
+    (Msg::PageLengthUpdatedToNumber, "1 ページの件数を {0} に変更しました"),
+    (Msg::DataProtection, "データ保護"),
+    (Msg::Delete, "削除"),
+    (Msg::DeleteWordQuestion, "単語「{0}」を削除しますか?"),
+    (Msg::DeleteFolderQuestion, "フォルダー「{0}」とサブフォルダー {1} 個、単語 {2} 個を削除しますか?"),
+    (Msg::DeleteSmartFolderQuestion, "スマートフォルダー「{0}」を削除しますか?単語は残ります"),
+    (Msg::TypeNameToConfirm, "確認のため「{0}」と入力してください"),
+    (Msg::ProtectDeleteWord, "単語の削除"),
+    (Msg::ProtectDeleteFolder, "フォルダーの削除"),
+    (Msg::ProtectDeleteSmartFolder, "スマートフォルダーの削除"),
+    (Msg::ProtectionConfirm, "確認する"),
+    (Msg::ProtectionTypeName, "名前の入力を求める"),
+    (Msg::ProtectionProtected, "許可しない"),
+    (Msg::ProtectionUnprotected, "確認せずに削除"),
+    (Msg::Statistics, "統計"),
+    (Msg::StatsWordCount, "単語: {0}"),
+    (Msg::StatsReviews, "復習: {0} 回、正解率: {1}%"),
+    (Msg::StatsNoReviews, "まだ復習はありません"),
+    (Msg::StatsCurrentStreak, "現在の連続日数: {0} 日"),
+    (Msg::StatsLongestStreak, "最長の連続日数: {0} 日"),
+    (Msg::StatsAddedPerDay, "日ごとの追加単語数"),
+    (Msg::StatsAddedPerWeek, "週ごとの追加単語数"),
+    (Msg::StatsByDay, "日ごと"),
+    (Msg::StatsByWeek, "週ごと"),
+    (Msg::StatsPerFolder, "フォルダーごとの単語数"),
+    (Msg::StatsPerWordClass, "品詞ごとの単語数"),
+    (Msg::StatsMostReviewed, "よく復習した単語"),
+    (Msg::StatsNoWordClass, "品詞なし"),
+    (Msg::StatsReviewCount, "復習 {0} 回、正解 {1} 回"),
+    (Msg::DailyGoal, "1日の目標"),
+    (Msg::DailyGoalWords, "1日の新しい単語数"),
+    (Msg::DailyGoalReviews, "1日に復習する単語数"),
+    (Msg::DailyGoalHint, "0 は目標なし"),
+    (Msg::GoalWordsProgress, "今日の単語: {0} / {1}"),
+    (Msg::GoalReviewsProgress, "今日復習した単語: {0} / {1}"),
+    (Msg::GoalStreak, "連続: {0} 日"),
+    (Msg::PrintLayout, "フォルダーを印刷"),
+    (Msg::PrintMode, "レイアウト"),
+    (Msg::PrintWordList, "単語リスト"),
+    (Msg::PrintFlashcards, "切り取り用の単語カード"),
+    (Msg::PaperSize, "用紙サイズ"),
+    (Msg::FontFamily, "フォント"),
+    (Msg::FontSerif, "明朝体"),
+    (Msg::FontSansSerif, "ゴシック体"),
+    (Msg::FontSize, "文字サイズ"),
+    (Msg::OpenPrintLayout, "印刷用に開く"),
+    (Msg::PrintHeaderWord, "単語"),
+    (Msg::PrintHeaderWordClass, "品詞"),
+    (Msg::PrintHeaderPronunciation, "発音"),
+    (Msg::PrintHeaderNote, "メモ"),
+    (Msg::Autoplay, "自動再生"),
+    (Msg::AutoplayIsEnabled, "自動再生は有効です"),
+    (Msg::AutoplayIsDisabled, "自動再生は無効です"),
+    (Msg::QuickAdd, "クイック追加"),
+    (Msg::QuickAddFolderHint, "コンテキストメニューと Ctrl+Shift+B の既定フォルダー"),
+    (Msg::QuickAddFolderIsNotSet, "未設定"),
+    (Msg::QuickAddFolderNotFound, "フォルダー '{0}' が見つかりません"),
+    (Msg::QuickAddFolderUpdated, "既定フォルダーを '{0}' に変更しました"),
+    (Msg::LookUpSavedSelections, "保存した選択範囲を辞書で調べる"),
+    (Msg::SavedSelectionsAreNotLookedUp, "保存した選択範囲は辞書で調べません"),
+    (Msg::Language, "言語"),
+    (Msg::BrowserLanguage, "ブラウザーの言語"),
+    (Msg::Theme, "テーマ"),
+    (Msg::ThemeSystem, "システムに合わせる"),
+    (Msg::ThemeLight, "ライト"),
+    (Msg::ThemeDark, "ダーク"),
+    (Msg::ThemeHighContrast, "ハイコントラスト"),
+    (Msg::Database, "データベース"),
+    (Msg::KeyboardShortcuts, "キーボードショートカット"),
+    (Msg::PressAKey, "キーを押してください (Escape でキャンセル)"),
+    (Msg::KeyIsAlreadyBound, "'{0}' はすでに「{1}」に割り当てられています"),
+    (Msg::ResetKeyBindings, "既定に戻す"),
+    (Msg::Close, "閉じる"),
+    (Msg::KeyShowFolders, "フォルダーを表示"),
+    (Msg::KeyShowWords, "単語を表示"),
+    (Msg::KeyShowSettings, "設定を表示"),
+    (Msg::KeyPreviousItem, "前の項目を選択"),
+    (Msg::KeyNextItem, "次の項目を選択"),
+    (Msg::KeyPageLeft, "前のページ"),
+    (Msg::KeyPageRight, "次のページ"),
+    (Msg::KeyOpen, "選択した項目を開く"),
+    (Msg::KeySearch, "絞り込み欄に移動"),
+    (Msg::KeyDelete, "選択した項目を削除"),
+    (Msg::KeyEdit, "選択した項目を編集"),
+    (Msg::KeyHelp, "キーボードショートカットを表示"),
+    (Msg::CheckDatabase, "データベースを確認"),
+    (Msg::Repair, "修復"),
+    (Msg::Quarantine, "隔離"),
+    (Msg::NoCorruptedRecords, "破損したレコードはありません"),
+    (Msg::CorruptedRecords, "破損したレコード: {0}"),
+    (Msg::QuarantinedRecords, "隔離中のレコード: {0}"),
+    (Msg::QuarantineHint, "修復できないレコードは隔離され、表示されなくなります"),
+    (Msg::CheckIntegrity, "整合性を確認"),
+    (Msg::NoIntegrityProblems, "整合性の問題はありません"),
+    (Msg::WrongIds, "{0} 件のレコードの id フィールドを修正"),
+    (Msg::MismatchedFolders, "{0} 個の単語を、大文字小文字や空白だけが異なる同名のフォルダーに移動"),
+    (Msg::Conflicts, "そのうち {0} 個はすでにそのフォルダーにあるため、スキップします"),
+    (Msg::OrphanWords, "フォルダーのない単語: {0}"),
+    (Msg::MissingFolders, "見つからないフォルダー: {0}"),
+    (Msg::RecordsChanged, "変更したレコード: {0}"),
+    (Msg::OrphansKeep, "単語を残す"),
+    (Msg::OrphansCreateFolders, "見つからないフォルダーを作成"),
+    (Msg::OrphansDeleteWords, "単語を削除"),
+];
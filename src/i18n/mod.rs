@@ -0,0 +1,420 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+
+//! The messages of the user interface in the supported languages.
+//! Every bundle has a text for every `Msg`, the parameters are written as {0}, {1}, ...
+
+use serde::{Deserialize, Serialize};
+
+mod de;
+mod en;
+mod es;
+mod ja;
+mod ru;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Hash, Eq)]
+pub enum Locale {
+    En,
+    De,
+    Es,
+    Ru,
+    Ja,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 5] = [Locale::En, Locale::De, Locale::Es, Locale::Ru, Locale::Ja];
+
+    /// The name of the language in that language
+    pub fn name(&self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::De => "Deutsch",
+            Locale::Es => "Español",
+            Locale::Ru => "Русский",
+            Locale::Ja => "日本語",
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::De => "de",
+            Locale::Es => "es",
+            Locale::Ru => "ru",
+            Locale::Ja => "ja",
+        }
+    }
+
+    /// The locale of a language tag like "de-AT", English for the unsupported languages
+    pub fn from_language_tag(tag: &str) -> Locale {
+        let language = tag.split(['-', '_']).next().unwrap_or_default().to_lowercase();
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.code() == language)
+            .unwrap_or(Locale::En)
+    }
+
+    /// The locale of the browser's user interface
+    pub fn browser() -> Locale {
+        web_sys::window()
+            .and_then(|window| window.navigator().language())
+            .map_or(Locale::En, |tag| Locale::from_language_tag(&tag))
+    }
+
+    fn messages(&self) -> &'static [(Msg, &'static str)] {
+        match self {
+            Locale::En => en::MESSAGES,
+            Locale::De => de::MESSAGES,
+            Locale::Es => es::MESSAGES,
+            Locale::Ru => ru::MESSAGES,
+            Locale::Ja => ja::MESSAGES,
+        }
+    }
+}
+
+/// Defines `Msg` and the list of all its keys, so the list can't miss a key
+macro_rules! messages {
+    ($($msg:ident,)*) => {
+        #[derive(Debug, PartialEq, Clone, Copy, Hash, Eq)]
+        pub enum Msg {
+            $($msg,)*
+        }
+
+        impl Msg {
+            /// Every key, the bundles are checked against it
+            #[cfg(all(test, not(target_arch = "wasm32")))]
+            pub const ALL: &'static [Msg] = &[$(Msg::$msg,)*];
+        }
+    };
+}
+
+messages! {
+    // navigation
+    Folders,
+    Words,
+    Settings,
+    ShowAddFolderForm,
+    ShowAddWordForm,
+    FolderNameIsEmpty,
+    FolderNameHasSeparator,
+    NoConditions,
+    WordIsEmpty,
+    SelectFolderFirst,
+    DataSuccessfullyImported,
+    DataProtectionIsSet,
+    WordWasDeleted,
+    FolderWasDeleted,
+    SmartFolderWasDeleted,
+    SmartFolders,
+    SmartFolderPath,
+    NewSmartFolderName,
+    AddedInDays,
+    TagInNotes,
+    DueForReview,
+    NoNoteYet,
+    AddSmartFolder,
+    DeleteSmartFolderKeepWords,
+    ConditionAddedWithinDays,
+    ConditionWordClass,
+    UseArrowKeysToNavigate,
+    AlreadySavedInFolders,
+    WordAlreadyInFolder,
+    WordWasUpdated,
+    BackToWords,
+    Edit,
+    MoveToFolder,
+    OpenInDictionary,
+    PlayAudio,
+    DetailWord,
+    DetailWordClass,
+    DetailFolder,
+    DetailUrl,
+    DetailAdded,
+    DetailNote,
+    DetailExamples,
+    DetailContext,
+    DetailReviews,
+    DetailAlsoIn,
+    ReviewSummary,
+    NeverReviewed,
+    Remembered,
+    Forgot,
+    Save,
+    Move,
+    // storage errors
+    ErrorConstraintViolation,
+    ErrorQuotaExceeded,
+    ErrorVersionMismatch,
+    ErrorCorruptData,
+    ErrorNotFound,
+    ErrorUnknown,
+    ErrorSettingsNotLoaded,
+    // lists
+    FirstPage,
+    PageLeft,
+    PageNumber,
+    TotalPages,
+    PageRight,
+    LastPage,
+    Count,
+    SortAscending,
+    SortDescending,
+    FilterWords,
+    FilterFolders,
+    ShowArchived,
+    LookUpInDictionary,
+    WordDetails,
+    DeleteWord,
+    Expand,
+    Collapse,
+    PinToTop,
+    Unpin,
+    ColourAndIcon,
+    ArchiveWithSubfolders,
+    RestoreFromArchive,
+    ExportWithSubfolders,
+    DeleteWithSubfolders,
+    WordCountTitle,
+    FolderColour,
+    FolderIcon,
+    NoColour,
+    NoIcon,
+    ColourRed,
+    ColourOrange,
+    ColourYellow,
+    ColourGreen,
+    ColourBlue,
+    ColourPurple,
+    IconBook,
+    IconBookmark,
+    IconStar,
+    IconFlag,
+    IconHeart,
+    IconAward,
+    SortFolders,
+    ParentFolder,
+    SortByName,
+    SortByCreationDate,
+    SortByLastAddedWord,
+    SortByWordCount,
+    FolderPath,
+    // forms
+    SearchPlaceholder,
+    Search,
+    NewFolderName,
+    FolderNote,
+    NoParentFolder,
+    AddFolder,
+    UrlToAdd,
+    WordOrLinkName,
+    WordClass,
+    WordNote,
+    WordExamples,
+    AddWord,
+    Clear,
+    Show,
+    UpdateNoteAndUrl,
+    Cancel,
+    Change,
+    // settings
+    Export,
+    Import,
+    PageLength,
+    PageLengthForFolders,
+    PageLengthForWords,
+    UnlimitedPageLength,
+    PageLengthUpdatedToUnlimited,
+    PageLengthUpdatedToNumber,
+    DataProtection,
+    Delete,
+    DeleteWordQuestion,
+    DeleteFolderQuestion,
+    DeleteSmartFolderQuestion,
+    TypeNameToConfirm,
+    ProtectDeleteWord,
+    ProtectDeleteFolder,
+    ProtectDeleteSmartFolder,
+    ProtectionConfirm,
+    ProtectionTypeName,
+    ProtectionProtected,
+    ProtectionUnprotected,
+    Statistics,
+    StatsWordCount,
+    StatsReviews,
+    StatsNoReviews,
+    StatsCurrentStreak,
+    StatsLongestStreak,
+    StatsAddedPerDay,
+    StatsAddedPerWeek,
+    StatsByDay,
+    StatsByWeek,
+    StatsPerFolder,
+    StatsPerWordClass,
+    StatsMostReviewed,
+    StatsNoWordClass,
+    StatsReviewCount,
+    DailyGoal,
+    DailyGoalWords,
+    DailyGoalReviews,
+    DailyGoalHint,
+    GoalWordsProgress,
+    GoalReviewsProgress,
+    GoalStreak,
+    PrintLayout,
+    PrintMode,
+    PrintWordList,
+    PrintFlashcards,
+    PaperSize,
+    FontFamily,
+    FontSerif,
+    FontSansSerif,
+    FontSize,
+    OpenPrintLayout,
+    PrintHeaderWord,
+    PrintHeaderWordClass,
+    PrintHeaderPronunciation,
+    PrintHeaderNote,
+    Autoplay,
+    AutoplayIsEnabled,
+    AutoplayIsDisabled,
+    QuickAdd,
+    QuickAddFolderHint,
+    QuickAddFolderIsNotSet,
+    QuickAddFolderNotFound,
+    QuickAddFolderUpdated,
+    LookUpSavedSelections,
+    SavedSelectionsAreNotLookedUp,
+    Language,
+    BrowserLanguage,
+    Theme,
+    ThemeSystem,
+    ThemeLight,
+    ThemeDark,
+    ThemeHighContrast,
+    Database,
+    // keyboard shortcuts
+    KeyboardShortcuts,
+    PressAKey,
+    KeyIsAlreadyBound,
+    ResetKeyBindings,
+    Close,
+    KeyShowFolders,
+    KeyShowWords,
+    KeyShowSettings,
+    KeyPreviousItem,
+    KeyNextItem,
+    KeyPageLeft,
+    KeyPageRight,
+    KeyOpen,
+    KeySearch,
+    KeyDelete,
+    KeyEdit,
+    KeyHelp,
+    // database checks
+    CheckDatabase,
+    Repair,
+    Quarantine,
+    NoCorruptedRecords,
+    CorruptedRecords,
+    QuarantinedRecords,
+    QuarantineHint,
+    CheckIntegrity,
+    NoIntegrityProblems,
+    WrongIds,
+    MismatchedFolders,
+    Conflicts,
+    OrphanWords,
+    MissingFolders,
+    RecordsChanged,
+    OrphansKeep,
+    OrphansCreateFolders,
+    OrphansDeleteWords,
+}
+
+/// The text of the message, the English one if the bundle misses it
+pub fn tr(locale: Locale, msg: Msg) -> &'static str {
+    find(locale, msg)
+        .or_else(|| find(Locale::En, msg))
+        .unwrap_or_default()
+}
+
+/// The text of the message with the parameters {0}, {1}, ... replaced by the arguments
+pub fn tr_args(locale: Locale, msg: Msg, args: &[&str]) -> String {
+    args.iter()
+        .enumerate()
+        .fold(tr(locale, msg).to_string(), |text, (index, arg)| {
+            text.replace(&format!("{{{}}}", index), arg)
+        })
+}
+
+fn find(locale: Locale, msg: Msg) -> Option<&'static str> {
+    locale
+        .messages()
+        .iter()
+        .find(|(key, _)| *key == msg)
+        .map(|(_, text)| *text)
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn parameters(text: &str) -> usize {
+        (0..10).take_while(|index| text.contains(&format!("{{{}}}", index))).count()
+    }
+
+    #[test]
+    fn every_key_in_every_bundle_test() {
+        assert_eq!(Msg::ALL.len(), Msg::ALL.iter().collect::<HashSet<_>>().len());
+
+        for locale in Locale::ALL {
+            let messages = locale.messages();
+            let keys = messages.iter().map(|(key, _)| *key).collect::<HashSet<Msg>>();
+            assert_eq!(messages.len(), keys.len(), "duplicate keys in {:?}", locale);
+
+            for &msg in Msg::ALL {
+                let text = find(locale, msg);
+                assert!(text.is_some_and(|text| !text.is_empty()), "{:?} misses {:?}", locale, msg);
+                assert_eq!(
+                    parameters(tr(Locale::En, msg)),
+                    parameters(text.unwrap()),
+                    "parameters of {:?} in {:?}", msg, locale
+                );
+            }
+            assert_eq!(Msg::ALL.len(), keys.len(), "unknown keys in {:?}", locale);
+        }
+    }
+
+    #[test]
+    fn tr_args_test() {
+        assert_eq!("'e' is already bound to 'Edit'",
+                   tr_args(Locale::En, Msg::KeyIsAlreadyBound, &["e", "Edit"]));
+        assert_eq!("Ordner: ", tr(Locale::De, Msg::FolderPath));
+        assert_eq!("папка 'inbox' не найдена", tr_args(Locale::Ru, Msg::QuickAddFolderNotFound, &["inbox"]));
+    }
+
+    #[test]
+    fn from_language_tag_test() {
+        assert_eq!(Locale::De, Locale::from_language_tag("de-AT"));
+        assert_eq!(Locale::Ja, Locale::from_language_tag("ja"));
+        assert_eq!(Locale::Es, Locale::from_language_tag("ES_mx"));
+        assert_eq!(Locale::En, Locale::from_language_tag("fr-FR"));
+        assert_eq!(Locale::En, Locale::from_language_tag(""));
+    }
+}
@@ -0,0 +1,254 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+
+use super::Msg;
+
+pub(super) const MESSAGES: &[(Msg, &str)] = &[
+    (Msg::Folders, "Папки"),
+    (Msg::Words, "Слова"),
+    (Msg::Settings, "Настройки"),
+    (Msg::ShowAddFolderForm, "Показать форму новой папки"),
+    (Msg::ShowAddWordForm, "Показать форму нового слова"),
+    (Msg::FolderNameIsEmpty, "Не указано имя папки"),
+    (Msg::FolderNameHasSeparator, "Имя папки не может содержать \" / \", так разделяются вложенные папки"),
+    (Msg::NoConditions, "Выберите хотя бы одно условие"),
+    (Msg::WordIsEmpty, "Не указано слово"),
+    (Msg::SelectFolderFirst, "Сначала выберите папку"),
+    (Msg::DataSuccessfullyImported, "Данные успешно импортированы"),
+    (Msg::DataProtectionIsSet, "Включена защита данных. Её можно отключить в настройках"),
+    (Msg::WordWasDeleted, "Слово удалено"),
+    (Msg::FolderWasDeleted, "Папка удалена"),
+    (Msg::SmartFolderWasDeleted, "Умная папка удалена"),
+    (Msg::SmartFolders, "Умные папки"),
+    (Msg::SmartFolderPath, "Умная папка: "),
+    (Msg::NewSmartFolderName, "название новой умной папки"),
+    (Msg::AddedInDays, "добавлены за дней"),
+    (Msg::TagInNotes, "#тег в заметках"),
+    (Msg::DueForReview, "пора повторить"),
+    (Msg::NoNoteYet, "ещё без заметки"),
+    (Msg::AddSmartFolder, "Добавить умную папку"),
+    (Msg::DeleteSmartFolderKeepWords, "Удалить умную папку, слова сохранятся"),
+    (Msg::ConditionAddedWithinDays, "добавлены за последние {0} дн."),
+    (Msg::ConditionWordClass, "часть речи: {0}"),
+    (Msg::UseArrowKeysToNavigate, "Используйте стрелки вверх и вниз для перемещения по списку"),
+    (Msg::AlreadySavedInFolders, "Уже сохранено в папке: {0}"),
+    (Msg::WordAlreadyInFolder, "'{0}' уже есть в этой папке"),
+    (Msg::WordWasUpdated, "Слово обновлено"),
+    (Msg::BackToWords, "Назад к словам"),
+    (Msg::Edit, "Изменить"),
+    (Msg::MoveToFolder, "Переместить в другую папку"),
+    (Msg::OpenInDictionary, "Открыть в словаре"),
+    (Msg::PlayAudio, "Воспроизвести аудио"),
+    (Msg::DetailWord, "Слово"),
+    (Msg::DetailWordClass, "Часть речи"),
+    (Msg::DetailFolder, "Папка"),
+    (Msg::DetailUrl, "URL"),
+    (Msg::DetailAdded, "Добавлено"),
+    (Msg::DetailNote, "Заметка"),
+    (Msg::DetailExamples, "Примеры"),
+    (Msg::DetailContext, "Контекст"),
+    (Msg::DetailReviews, "Повторения"),
+    (Msg::DetailAlsoIn, "Также в"),
+    (Msg::ReviewSummary, "{0}, правильно {1} ({2}%), последнее: {3}"),
+    (Msg::NeverReviewed, "никогда"),
+    (Msg::Remembered, "Помню"),
+    (Msg::Forgot, "Забыл"),
+    (Msg::Save, "Сохранить"),
+    (Msg::Move, "Переместить"),
+    (Msg::ErrorConstraintViolation, "Папка или слово с таким именем уже существует"),
+    (Msg::ErrorQuotaExceeded, "Хранилище браузера заполнено. Экспортируйте данные и удалите ненужное"),
+    (Msg::ErrorVersionMismatch, "Данные сохранены другой версией расширения"),
+    (Msg::ErrorCorruptData, "Часть данных повреждена. Восстановите их кнопкой \"Проверить базу данных\" в настройках"),
+    (Msg::ErrorNotFound, "Запись удалена или не найдена"),
+    (Msg::ErrorUnknown, "Нет доступа к хранилищу, попробуйте ещё раз"),
+    (Msg::ErrorSettingsNotLoaded, "Не удалось загрузить настройки, изменения не будут сохранены, пока словарь не будет открыт заново."),
+    (Msg::FirstPage, "Первая страница"),
+    (Msg::PageLeft, "Предыдущая страница"),
+    (Msg::PageNumber, "Номер страницы"),
+    (Msg::TotalPages, "Всего страниц"),
+    (Msg::PageRight, "Следующая страница"),
+    (Msg::LastPage, "Последняя страница"),
+    (Msg::Count, "Количество"),
+    (Msg::SortAscending, "Сортировка: по возрастанию"),
+    (Msg::SortDescending, "Сортировка: по убыванию"),
+    (Msg::FilterWords, "фильтр по словам, заметкам и примерам"),
+    (Msg::FilterFolders, "фильтр по папкам и заметкам"),
+    (Msg::ShowArchived, "показывать архивные"),
+    (Msg::LookUpInDictionary, "Найти в словаре"),
+    (Msg::WordDetails, "Подробнее о слове"),
+    (Msg::DeleteWord, "Удалить слово"),
+    (Msg::Expand, "Развернуть"),
+    (Msg::Collapse, "Свернуть"),
+    (Msg::PinToTop, "Закрепить сверху"),
+    (Msg::Unpin, "Открепить"),
+    (Msg::ColourAndIcon, "Цвет и значок"),
+    (Msg::ArchiveWithSubfolders, "Архивировать с подпапками"),
+    (Msg::RestoreFromArchive, "Вернуть из архива"),
+    (Msg::ExportWithSubfolders, "Экспортировать папку с подпапками"),
+    (Msg::DeleteWithSubfolders, "Удалить папку с подпапками и словами"),
+    (Msg::WordCountTitle, "слов в папке / с подпапками"),
+    (Msg::FolderColour, "Цвет папки"),
+    (Msg::FolderIcon, "Значок папки"),
+    (Msg::NoColour, "без цвета"),
+    (Msg::NoIcon, "без значка"),
+    (Msg::ColourRed, "красный"),
+    (Msg::ColourOrange, "оранжевый"),
+    (Msg::ColourYellow, "жёлтый"),
+    (Msg::ColourGreen, "зелёный"),
+    (Msg::ColourBlue, "синий"),
+    (Msg::ColourPurple, "фиолетовый"),
+    (Msg::IconBook, "книга"),
+    (Msg::IconBookmark, "закладка"),
+    (Msg::IconStar, "звезда"),
+    (Msg::IconFlag, "флажок"),
+    (Msg::IconHeart, "сердце"),
+    (Msg::IconAward, "награда"),
+    (Msg::SortFolders, "Сортировка папок"),
+    (Msg::ParentFolder, "Родительская папка"),
+    (Msg::SortByName, "по имени"),
+    (Msg::SortByCreationDate, "по дате создания"),
+    (Msg::SortByLastAddedWord, "по последнему добавленному слову"),
+    (Msg::SortByWordCount, "по количеству слов"),
+    (Msg::FolderPath, "Папка: "),
+    (Msg::SearchPlaceholder, "поиск"),
+    (Msg::Search, "Найти"),
+    (Msg::NewFolderName, "имя новой папки"),
+    (Msg::FolderNote, "заметка"),
+    (Msg::NoParentFolder, "без родительской папки"),
+    (Msg::AddFolder, "Добавить папку"),
+    (Msg::UrlToAdd, "Добавляемый URL:"),
+    (Msg::WordOrLinkName, "слово или название ссылки"),
+    (Msg::WordClass, "часть речи"),
+    (Msg::WordNote, "заметка или произношение, **жирный**, *курсив*, - список"),
+    (Msg::WordExamples, "примеры предложений, по одному в строке"),
+    (Msg::AddWord, "Добавить слово"),
+    (Msg::Clear, "Очистить"),
+    (Msg::Show, "Показать"),
+    (Msg::UpdateNoteAndUrl, "Обновить заметку и URL"),
+    (Msg::Cancel, "Отмена"),
+    (Msg::Change, "Изменить"),
+    (Msg::Export, "Экспорт"),
+    (Msg::Import, "Импорт"),
+    (Msg::PageLength, "Размер страницы"),
+    (Msg::PageLengthForFolders, "размер страницы для папок"),
+    (Msg::PageLengthForWords, "размер страницы для слов"),
+    (Msg::UnlimitedPageLength, "без ограничений"),
+    (Msg::PageLengthUpdatedToUnlimited, "размер страницы изменён на неограниченный"),
+    (Msg::PageLengthUpdatedToNumber, "размер страницы изменён на {0}"),
+    (Msg::DataProtection, "Защита данных"),
+    (Msg::Delete, "Удалить"),
+    (Msg::DeleteWordQuestion, "Удалить слово «{0}»?"),
+    (Msg::DeleteFolderQuestion, "Удалить папку «{0}» вместе с подпапками ({1}) и словами ({2})?"),
+    (Msg::DeleteSmartFolderQuestion, "Удалить умную папку «{0}»? Её слова сохранятся"),
+    (Msg::TypeNameToConfirm, "Введите «{0}» для подтверждения"),
+    (Msg::ProtectDeleteWord, "Удаление слова"),
+    (Msg::ProtectDeleteFolder, "Удаление папки"),
+    (Msg::ProtectDeleteSmartFolder, "Удаление умной папки"),
+    (Msg::ProtectionConfirm, "Спрашивать подтверждение"),
+    (Msg::ProtectionTypeName, "Просить ввести название"),
+    (Msg::ProtectionProtected, "Запретить"),
+    (Msg::ProtectionUnprotected, "Удалять без вопросов"),
+    (Msg::Statistics, "Статистика"),
+    (Msg::StatsWordCount, "Слов: {0}"),
+    (Msg::StatsReviews, "Повторений: {0}, верно: {1}%"),
+    (Msg::StatsNoReviews, "Повторений пока нет"),
+    (Msg::StatsCurrentStreak, "Текущая серия: {0} дн."),
+    (Msg::StatsLongestStreak, "Самая длинная серия: {0} дн."),
+    (Msg::StatsAddedPerDay, "Добавлено слов по дням"),
+    (Msg::StatsAddedPerWeek, "Добавлено слов по неделям"),
+    (Msg::StatsByDay, "по дням"),
+    (Msg::StatsByWeek, "по неделям"),
+    (Msg::StatsPerFolder, "Слов в папках"),
+    (Msg::StatsPerWordClass, "Слов по частям речи"),
+    (Msg::StatsMostReviewed, "Чаще всего повторяемые слова"),
+    (Msg::StatsNoWordClass, "без части речи"),
+    (Msg::StatsReviewCount, "повторений: {0}, верно: {1}"),
+    (Msg::DailyGoal, "Цель на день"),
+    (Msg::DailyGoalWords, "Новых слов в день"),
+    (Msg::DailyGoalReviews, "Слов на повторение в день"),
+    (Msg::DailyGoalHint, "0 — без цели"),
+    (Msg::GoalWordsProgress, "Слов сегодня: {0} / {1}"),
+    (Msg::GoalReviewsProgress, "Слов повторено сегодня: {0} / {1}"),
+    (Msg::GoalStreak, "Серия: {0} дн."),
+    (Msg::PrintLayout, "Печать папки"),
+    (Msg::PrintMode, "Вид"),
+    (Msg::PrintWordList, "Список слов"),
+    (Msg::PrintFlashcards, "Карточки для вырезания"),
+    (Msg::PaperSize, "Размер бумаги"),
+    (Msg::FontFamily, "Шрифт"),
+    (Msg::FontSerif, "С засечками"),
+    (Msg::FontSansSerif, "Без засечек"),
+    (Msg::FontSize, "Размер шрифта"),
+    (Msg::OpenPrintLayout, "Открыть для печати"),
+    (Msg::PrintHeaderWord, "Слово"),
+    (Msg::PrintHeaderWordClass, "Часть речи"),
+    (Msg::PrintHeaderPronunciation, "Произношение"),
+    (Msg::PrintHeaderNote, "Заметка"),
+    (Msg::Autoplay, "Автовоспроизведение"),
+    (Msg::AutoplayIsEnabled, "Автовоспроизведение включено"),
+    (Msg::AutoplayIsDisabled, "Автовоспроизведение выключено"),
+    (Msg::QuickAdd, "Быстрое добавление"),
+    (Msg::QuickAddFolderHint, "Папка по умолчанию для контекстного меню и Ctrl+Shift+B"),
+    (Msg::QuickAddFolderIsNotSet, "не задана"),
+    (Msg::QuickAddFolderNotFound, "папка '{0}' не найдена"),
+    (Msg::QuickAddFolderUpdated, "папка по умолчанию изменена на '{0}'"),
+    (Msg::LookUpSavedSelections, "Искать сохранённые выделения в словаре"),
+    (Msg::SavedSelectionsAreNotLookedUp, "Сохранённые выделения не ищутся в словаре"),
+    (Msg::Language, "Язык"),
+    (Msg::BrowserLanguage, "Язык браузера"),
+    (Msg::Theme, "Тема"),
+    (Msg::ThemeSystem, "Как в системе"),
+    (Msg::ThemeLight, "Светлая"),
+    (Msg::ThemeDark, "Тёмная"),
+    (Msg::ThemeHighContrast, "Высокая контрастность"),
+    (Msg::Database, "База данных"),
+    (Msg::KeyboardShortcuts, "Сочетания клавиш"),
+    (Msg::PressAKey, "нажмите клавишу, Escape для отмены"),
+    (Msg::KeyIsAlreadyBound, "'{0}' уже назначена для '{1}'"),
+    (Msg::ResetKeyBindings, "Восстановить по умолчанию"),
+    (Msg::Close, "Закрыть"),
+    (Msg::KeyShowFolders, "Показать папки"),
+    (Msg::KeyShowWords, "Показать слова"),
+    (Msg::KeyShowSettings, "Показать настройки"),
+    (Msg::KeyPreviousItem, "Выбрать предыдущий элемент"),
+    (Msg::KeyNextItem, "Выбрать следующий элемент"),
+    (Msg::KeyPageLeft, "Предыдущая страница"),
+    (Msg::KeyPageRight, "Следующая страница"),
+    (Msg::KeyOpen, "Открыть выбранный элемент"),
+    (Msg::KeySearch, "Перейти к фильтру"),
+    (Msg::KeyDelete, "Удалить выбранный элемент"),
+    (Msg::KeyEdit, "Редактировать выбранный элемент"),
+    (Msg::KeyHelp, "Показать сочетания клавиш"),
+    (Msg::CheckDatabase, "Проверить базу данных"),
+    (Msg::Repair, "Исправить"),
+    (Msg::Quarantine, "Карантин"),
+    (Msg::NoCorruptedRecords, "Повреждённых записей не найдено"),
+    (Msg::CorruptedRecords, "Повреждённые записи: {0}"),
+    (Msg::QuarantinedRecords, "Записи в карантине: {0}"),
+    (Msg::QuarantineHint, "Записи, которые нельзя исправить, переносятся в карантин и больше не показываются"),
+    (Msg::CheckIntegrity, "Проверить целостность"),
+    (Msg::NoIntegrityProblems, "Проблем с целостностью не найдено"),
+    (Msg::WrongIds, "Исправить поле id у записей: {0}"),
+    (Msg::MismatchedFolders, "Перенести слова ({0}) в папку с тем же именем, записанным в другом регистре или с другими пробелами"),
+    (Msg::Conflicts, "{0} из них уже есть в этой папке и будут пропущены"),
+    (Msg::OrphanWords, "Слова без папки: {0}"),
+    (Msg::MissingFolders, "Отсутствующие папки: {0}"),
+    (Msg::RecordsChanged, "Изменено записей: {0}"),
+    (Msg::OrphansKeep, "Оставить слова"),
+    (Msg::OrphansCreateFolders, "Создать отсутствующие папки"),
+    (Msg::OrphansDeleteWords, "Удалить слова"),
+];
@@ -23,7 +23,14 @@ use wasm_bindgen::prelude::*;
 use crate::storage_global::init_storage;
 use crate::ui::App;
 
+mod dictionary_source;
+mod i18n;
+mod markdown;
 mod model;
+mod print;
+mod quick_add;
+mod source_context;
+mod stats;
 mod storage;
 mod storage_global;
 mod storage_props;
@@ -48,6 +55,11 @@ pub async fn start() -> Result<(), JsValue> {
     init_log();
     debug!("start(): Inited.");
 
+    // the service worker only uses quick_add_word()
+    if web_sys::window().is_none() {
+        return Ok(());
+    }
+
     init_storage().await;
 
     // log_storage_properties().await?;
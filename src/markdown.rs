@@ -0,0 +1,191 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! A small subset of Markdown for notes: **bold**, *italic* or _italic_,
+//! `- ` / `* ` bullet lists and `1. ` numbered lists.
+//! The result is rendered as elements, so notes are never inserted as raw html.
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Inline {
+    Text(String),
+    Bold(String),
+    Italic(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Block {
+    Paragraph(Vec<Vec<Inline>>),
+    List { ordered: bool, items: Vec<Vec<Inline>> },
+}
+
+pub(crate) fn parse(text: &str) -> Vec<Block> {
+    let mut blocks: Vec<Block> = vec![];
+
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            blocks.push(Block::Paragraph(vec![]));
+            continue;
+        }
+
+        let (ordered, item) = match list_item(line) {
+            Some((ordered, item)) => (Some(ordered), item),
+            None => (None, line),
+        };
+        let inlines = parse_inline(item);
+
+        match (blocks.last_mut(), ordered) {
+            (Some(Block::List { ordered: last, items }), Some(ordered)) if *last == ordered => {
+                items.push(inlines)
+            }
+            (_, Some(ordered)) => blocks.push(Block::List { ordered, items: vec![inlines] }),
+            (Some(Block::Paragraph(lines)), None) if !lines.is_empty() => lines.push(inlines),
+            (_, None) => blocks.push(Block::Paragraph(vec![inlines])),
+        }
+    }
+
+    blocks.retain(|block| !matches!(block, Block::Paragraph(lines) if lines.is_empty()));
+    blocks
+}
+
+fn list_item(line: &str) -> Option<(bool, &str)> {
+    if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some((false, item.trim_start()));
+    }
+    let (number, item) = line.split_once(". ")?;
+    if !number.is_empty() && number.chars().all(|ch| ch.is_ascii_digit()) {
+        return Some((true, item.trim_start()));
+    }
+    None
+}
+
+/// A marker opens before a non-whitespace character and closes after one,
+/// `_` inside a word like snake_case isn't a marker
+pub(crate) fn parse_inline(line: &str) -> Vec<Inline> {
+    let mut result: Vec<Inline> = vec![];
+    let mut text = String::new();
+    let mut rest = line;
+
+    while !rest.is_empty() {
+        let styled = ["**", "*", "_"].iter().find_map(|marker| {
+            let inner = rest.strip_prefix(marker)?;
+            let next = inner.chars().next()?;
+            if next.is_whitespace() || next == '*' {
+                return None;
+            }
+            if *marker == "_" && text.chars().last().is_some_and(char::is_alphanumeric) {
+                return None;
+            }
+            let end = closing_marker(inner, marker)?;
+            let content = inner[..end].to_string();
+            let styled = match *marker {
+                "**" => Inline::Bold(content),
+                _ => Inline::Italic(content),
+            };
+            Some((styled, &inner[end + marker.len()..]))
+        });
+
+        match styled {
+            Some((styled, remainder)) => {
+                if !text.is_empty() {
+                    result.push(Inline::Text(std::mem::take(&mut text)));
+                }
+                result.push(styled);
+                rest = remainder;
+            }
+            None => {
+                let ch = rest.chars().next().unwrap();
+                text.push(ch);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+    }
+
+    if !text.is_empty() {
+        result.push(Inline::Text(text));
+    }
+    result
+}
+
+fn closing_marker(inner: &str, marker: &str) -> Option<usize> {
+    inner.match_indices(marker).map(|(index, _)| index).find(|&index| {
+        let before = inner[..index].chars().last();
+        let after = inner[index + marker.len()..].chars().next();
+        before.is_some_and(|ch| !ch.is_whitespace())
+            && (marker != "_" || !after.is_some_and(char::is_alphanumeric))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::tests::test_init;
+
+    use super::*;
+    use super::Inline::{Bold, Italic, Text};
+
+    #[wasm_bindgen_test]
+    fn parse_inline_test() {
+        test_init("parse_inline_test");
+
+        assert_eq!(
+            vec![
+                Text("a ".to_string()),
+                Bold("bold".to_string()),
+                Text(" and ".to_string()),
+                Italic("italic".to_string()),
+                Text(", ".to_string()),
+                Italic("too".to_string()),
+            ],
+            parse_inline("a **bold** and *italic*, _too_")
+        );
+        assert_eq!(vec![Text("2 * 3 ** 4 <b>".to_string())], parse_inline("2 * 3 ** 4 <b>"));
+        assert_eq!(vec![Text("**".to_string())], parse_inline("**"));
+        assert_eq!(vec![Text("2 * 3".to_string())], parse_inline("2 * 3"));
+        assert_eq!(vec![Text("a_b_c".to_string())], parse_inline("a_b_c"));
+        assert_eq!(
+            vec![Text("use ".to_string()), Italic("snake_case".to_string()), Text(" names".to_string())],
+            parse_inline("use _snake_case_ names")
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn parse_test() {
+        test_init("parse_test");
+
+        let blocks = parse("first line\nsecond line\n\n- one\n* two\n1. three\n2. four\nlast");
+        assert_eq!(
+            vec![
+                Block::Paragraph(vec![
+                    vec![Text("first line".to_string())],
+                    vec![Text("second line".to_string())],
+                ]),
+                Block::List {
+                    ordered: false,
+                    items: vec![vec![Text("one".to_string())], vec![Text("two".to_string())]],
+                },
+                Block::List {
+                    ordered: true,
+                    items: vec![vec![Text("three".to_string())], vec![Text("four".to_string())]],
+                },
+                Block::Paragraph(vec![vec![Text("last".to_string())]]),
+            ],
+            blocks
+        );
+        assert!(parse("\n\n").is_empty());
+    }
+}
@@ -16,7 +16,7 @@
 
 #![allow(non_upper_case_globals)]
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use chrono::Utc;
 use lazy_static::lazy_static;
@@ -24,15 +24,48 @@ use rexie::Direction;
 use rexie::Direction::{Next, Prev};
 use serde::{Deserialize, Serialize};
 
+use crate::dictionary_source::canonical_url;
+use crate::i18n::Locale;
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
 pub struct Folder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<u32>,
     pub folder: String,
     pub folder_note: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<u32>,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<FolderColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<FolderIcon>,
+    #[serde(default)]
+    pub archived: bool,
     pub datetime: i64,
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Hash, Eq)]
+pub enum FolderColor {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Hash, Eq)]
+pub enum FolderIcon {
+    Book,
+    Bookmark,
+    Star,
+    Flag,
+    Heart,
+    Award,
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
 pub struct Word {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -41,15 +74,166 @@ pub struct Word {
     pub word: String,
     pub word_class: String,
     pub url: String,
+    #[serde(default)]
+    pub canonical_url: String,
     pub note: String,
+    #[serde(default)]
+    pub source_context: String,
+    #[serde(default)]
+    pub examples: Vec<String>,
+    #[serde(default)]
+    pub review: Review,
+    pub datetime: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq, Default)]
+pub struct Review {
+    pub count: u32,
+    pub correct: u32,
+    pub datetime: i64,
+}
+
+/// A virtual folder with the words matching all its conditions
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
+pub struct SmartFolder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    pub name: String,
+    pub conditions: Vec<QueryCondition>,
     pub datetime: i64,
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
+pub enum QueryCondition {
+    AddedWithinDays(u32),
+    WordClass(String),
+    /// A hashtag in the note or in the examples, e.g. #exam
+    Tag(String),
+    DueForReview,
+    NoNote,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
+pub struct SmartFolderStats {
+    pub smart_folder: SmartFolder,
+    pub word_count: u32,
+}
+
+pub const SETTINGS_VERSION: u32 = 2;
+
+/// Separates the names in the key of a subfolder
+pub const FOLDER_PATH_SEPARATOR: &str = " / ";
+
+/// How a destructive action is protected
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Hash, Eq, Default)]
+pub enum DataProtection {
+    /// The action is refused
+    Protected,
+    /// The action is done after a confirmation
+    #[default]
+    Confirm,
+    /// The name of the deleted word or folder has to be typed to confirm the action
+    TypeName,
+    Unprotected,
+}
+
+/// Destructive actions with their own protection
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Hash, Eq, PartialOrd, Ord)]
+pub enum ProtectedAction {
+    DeleteWord,
+    DeleteFolder,
+    DeleteSmartFolder,
+}
+
+/// All user settings, stored as one record.
+/// Missing fields get their default values, so new fields don't need a migration
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
+#[serde(default)]
+pub struct Settings {
+    /// 0 means the settings were collected from the old separate keys and aren't saved yet
+    pub version: u32,
+    pub autoplay: bool,
+    /// The protection of all actions in version 1, replaced by `protection`
+    #[serde(skip_serializing)]
+    pub data_protection: Option<DataProtection>,
+    pub folders_page_length: Option<u32>,
+    pub words_page_length: Option<u32>,
+    pub show_add_folder_form: bool,
+    pub show_add_word_form: bool,
+    pub selected_folder: String,
+    pub show_use_keyboard_message: bool,
+    pub folders_sort: FolderSort,
+    pub show_archived_folders: bool,
+    pub quick_add_folder: String,
+    pub lookup_after_save: bool,
+    /// Only the bindings changed by the user, the other actions keep their default keys
+    pub key_bindings: BTreeMap<KeyAction, String>,
+    /// None is the language of the browser
+    pub locale: Option<Locale>,
+    pub theme: Theme,
+    /// Only the actions with a changed protection, the other ones ask for a confirmation
+    pub protection: BTreeMap<ProtectedAction, DataProtection>,
+    pub daily_goal: DailyGoal,
+}
+
+/// New words and words to review every day, 0 is no goal
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Hash, Eq, Default)]
+#[serde(default)]
+pub struct DailyGoal {
+    pub words: u32,
+    pub reviews: u32,
+}
+
+/// New words and reviews of one local day, they are kept when the words are deleted
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Hash, Eq, Default)]
+#[serde(default)]
+pub struct DailyTotal {
+    pub words: u32,
+    /// Words reviewed on the day, a word reviewed twice counts once.
+    /// The totals filled from the words count the same, since only the last review of a word is known
+    pub reviews: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Hash, Eq, Default)]
+pub enum Theme {
+    /// Light or dark, as the colour scheme of the system
+    #[default]
+    System,
+    Light,
+    Dark,
+    HighContrast,
+}
+
+/// Actions of the side panel that can be bound to a key
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Hash, Eq, PartialOrd, Ord)]
+pub enum KeyAction {
+    ShowFolders,
+    ShowWords,
+    ShowSettings,
+    PreviousItem,
+    NextItem,
+    PageLeft,
+    PageRight,
+    Open,
+    Search,
+    Delete,
+    Edit,
+    Help,
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
 pub struct Data {
     pub version: u32,
     pub folders: Vec<Folder>,
     pub words: Vec<Word>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub smart_folders: Vec<SmartFolder>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<Settings>,
+    /// Folder name -> parent folder name, since the ids aren't exported
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub folder_parents: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
@@ -58,6 +242,32 @@ pub struct FoldersAndCount {
     pub count: u32,
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Hash, Eq, Default)]
+pub enum FolderSort {
+    Name,
+    #[default]
+    Created,
+    /// The last time a word was added to the folder
+    Modified,
+    WordCount,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
+pub struct FolderStats {
+    pub folder: Folder,
+    pub word_count: u32,
+    /// Words of the folder and all its subfolders
+    pub subtree_word_count: u32,
+    pub subfolder_count: u32,
+    pub modified: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
+pub struct FolderStatsAndCount {
+    pub folders: Vec<FolderStats>,
+    pub count: u32,
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
 pub struct WordsAndCount {
     pub words: Vec<Word>,
@@ -74,42 +284,432 @@ pub struct FolderKey {
     pub id: u32,
 }
 
-pub const sort_directions: [(&'static str, Direction); 2] =
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
+pub struct SmartFolderKey {
+    pub id: u32,
+}
+
+pub const sort_directions: [(&str, Direction); 2] =
     [("\u{2191}", Next), ("\u{2193}", Prev)];
 
 lazy_static! {
-    pub static ref default_sort_direction: &'static str = sort_directions.get(0).unwrap().0;
+    pub static ref default_sort_direction: &'static str = sort_directions.first().unwrap().0;
     pub static ref sort_direction_map: HashMap<&'static str, Direction> =
         HashMap::from(sort_directions);
 }
 
 impl Folder {
-    pub fn new(folder: &String, folder_note: &String) -> Folder {
+    pub fn new(folder: &str, folder_note: &str) -> Folder {
         Folder {
             id: None,
-            folder: folder.clone(),
-            folder_note: folder_note.clone(),
+            folder: folder.to_string(),
+            folder_note: folder_note.to_string(),
+            parent_id: None,
+            pinned: false,
+            color: None,
+            icon: None,
+            archived: false,
             datetime: Utc::now().timestamp_millis(),
         }
     }
+
+    pub fn with_parent(&self, parent_id: Option<u32>) -> Folder {
+        Folder {
+            parent_id,
+            ..self.clone()
+        }
+    }
+
+    /// A subfolder is keyed by the key of its parent and its name,
+    /// so the same name can be used under different parents
+    pub fn new_subfolder(parent: &Folder, name: &str, folder_note: &str) -> Folder {
+        let folder = format!("{}{}{}", parent.folder, FOLDER_PATH_SEPARATOR, name);
+        Folder::new(&folder, folder_note).with_parent(parent.id)
+    }
+
+    /// The name without the names of the parents
+    pub fn name(&self) -> &str {
+        match self.parent_id {
+            Some(_) => self.folder.rsplit(FOLDER_PATH_SEPARATOR).next().unwrap_or(&self.folder),
+            None => &self.folder,
+        }
+    }
+
+    /// Case-insensitive search in the name and the note of the folder
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.folder.to_lowercase().contains(&query)
+            || self.folder_note.to_lowercase().contains(&query)
+    }
 }
 
 impl Word {
     pub fn new(
-        folder: &String,
-        word: &String,
-        word_class: &String,
-        url: &String,
-        note: &String,
+        folder: &str,
+        word: &str,
+        word_class: &str,
+        url: &str,
+        note: &str,
     ) -> Word {
         Word {
             id: None,
-            folder: folder.clone(),
-            word: word.clone(),
-            word_class: word_class.clone(),
-            url: url.clone(),
-            note: note.clone(),
+            folder: folder.to_string(),
+            word: word.to_string(),
+            word_class: word_class.to_string(),
+            url: url.to_string(),
+            canonical_url: canonical_url(url),
+            note: note.to_string(),
+            source_context: String::new(),
+            examples: vec![],
+            review: Review::default(),
             datetime: Utc::now().timestamp_millis(),
         }
     }
+
+    pub fn with_canonical_url(&self) -> Word {
+        let mut word = self.clone();
+        word.canonical_url = canonical_url(&word.url);
+        word
+    }
+
+    pub fn with_source_context(&self, source_context: &str) -> Word {
+        let mut word = self.clone();
+        word.source_context = source_context.to_string();
+        word
+    }
+
+    pub fn with_examples(&self, examples: &str) -> Word {
+        let mut word = self.clone();
+        word.examples = examples
+            .lines()
+            .map(str::trim)
+            .filter(|example| !example.is_empty())
+            .map(str::to_string)
+            .collect();
+        word
+    }
+
+    pub fn with_review(&self, correct: bool) -> Word {
+        let mut word = self.clone();
+        word.review.count += 1;
+        if correct {
+            word.review.correct += 1;
+        }
+        word.review.datetime = Utc::now().timestamp_millis();
+        word
+    }
+
+    /// Case-insensitive search in all text fields of the word
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || [&self.word, &self.word_class, &self.note, &self.source_context]
+                .into_iter()
+                .chain(self.examples.iter())
+                .any(|field| field.to_lowercase().contains(&query))
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            version: SETTINGS_VERSION,
+            autoplay: true,
+            data_protection: None,
+            folders_page_length: None,
+            words_page_length: None,
+            show_add_folder_form: true,
+            show_add_word_form: true,
+            selected_folder: String::new(),
+            show_use_keyboard_message: true,
+            folders_sort: FolderSort::default(),
+            show_archived_folders: false,
+            quick_add_folder: String::new(),
+            lookup_after_save: false,
+            key_bindings: BTreeMap::new(),
+            locale: None,
+            theme: Theme::default(),
+            protection: BTreeMap::new(),
+            daily_goal: DailyGoal::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Upgrades settings saved by older versions of the extension
+    pub fn migrate(self) -> Settings {
+        let mut settings = self;
+        // the global lock of version 1 is replaced with a confirmation unless it was disabled
+        if let Some(DataProtection::Unprotected) = settings.data_protection.take() {
+            for action in ProtectedAction::ALL {
+                settings.protection.insert(action, DataProtection::Unprotected);
+            }
+        }
+        Settings {
+            version: SETTINGS_VERSION,
+            ..settings
+        }
+        .validate()
+    }
+
+    pub fn validate(self) -> Settings {
+        Settings {
+            folders_page_length: self.folders_page_length.filter(|length| *length > 0),
+            words_page_length: self.words_page_length.filter(|length| *length > 0),
+            selected_folder: self.selected_folder.trim().to_string(),
+            quick_add_folder: self.quick_add_folder.trim().to_string(),
+            key_bindings: self.key_bindings
+                .into_iter()
+                .filter(|(action, key)| !key.is_empty() && *key != action.default_key())
+                .collect(),
+            protection: self.protection
+                .into_iter()
+                .filter(|(_, protection)| *protection != DataProtection::default())
+                .collect(),
+            ..self
+        }
+    }
+
+    pub fn key_binding(&self, action: KeyAction) -> String {
+        self.key_bindings
+            .get(&action)
+            .cloned()
+            .unwrap_or_else(|| action.default_key().to_string())
+    }
+
+    /// The action bound to the key, see `KeyAction::key_name` for the names of the keys
+    pub fn key_action(&self, key: &str) -> Option<KeyAction> {
+        KeyAction::ALL
+            .into_iter()
+            .find(|action| self.key_binding(*action) == key)
+    }
+
+    /// Binds the key to the action unless another action already uses it
+    pub fn bind_key(&mut self, action: KeyAction, key: &str) -> Result<(), KeyAction> {
+        match self.key_action(key) {
+            Some(other) if other != action => Err(other),
+            _ => {
+                if key == action.default_key() {
+                    self.key_bindings.remove(&action);
+                } else {
+                    self.key_bindings.insert(action, key.to_string());
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub fn protection(&self, action: ProtectedAction) -> DataProtection {
+        self.protection.get(&action).copied().unwrap_or_default()
+    }
+
+    pub fn set_protection(&mut self, action: ProtectedAction, protection: DataProtection) {
+        if protection == DataProtection::default() {
+            self.protection.remove(&action);
+        } else {
+            self.protection.insert(action, protection);
+        }
+    }
+}
+
+impl DailyGoal {
+    pub fn is_set(&self) -> bool {
+        self.words > 0 || self.reviews > 0
+    }
+
+    /// Without a goal any new word or review is enough
+    pub fn is_met(&self, total: &DailyTotal) -> bool {
+        match self.is_set() {
+            true => total.words >= self.words && total.reviews >= self.reviews,
+            false => total.words > 0 || total.reviews > 0,
+        }
+    }
+}
+
+impl DataProtection {
+    pub const ALL: [DataProtection; 4] = [
+        DataProtection::Confirm,
+        DataProtection::TypeName,
+        DataProtection::Protected,
+        DataProtection::Unprotected,
+    ];
+}
+
+impl ProtectedAction {
+    pub const ALL: [ProtectedAction; 3] = [
+        ProtectedAction::DeleteWord,
+        ProtectedAction::DeleteFolder,
+        ProtectedAction::DeleteSmartFolder,
+    ];
+}
+
+impl Theme {
+    pub const ALL: [Theme; 4] = [Theme::System, Theme::Light, Theme::Dark, Theme::HighContrast];
+
+    /// The name of the DaisyUI theme, the system theme has none
+    pub fn data_theme(&self) -> Option<&'static str> {
+        match self {
+            Theme::System => None,
+            Theme::Light => Some("light"),
+            Theme::Dark => Some("dark"),
+            Theme::HighContrast => Some("highcontrast"),
+        }
+    }
+}
+
+impl KeyAction {
+    pub const ALL: [KeyAction; 12] = [
+        KeyAction::ShowFolders,
+        KeyAction::ShowWords,
+        KeyAction::ShowSettings,
+        KeyAction::PreviousItem,
+        KeyAction::NextItem,
+        KeyAction::PageLeft,
+        KeyAction::PageRight,
+        KeyAction::Open,
+        KeyAction::Search,
+        KeyAction::Delete,
+        KeyAction::Edit,
+        KeyAction::Help,
+    ];
+
+    pub fn default_key(&self) -> &'static str {
+        match self {
+            KeyAction::ShowFolders => "f",
+            KeyAction::ShowWords => "w",
+            KeyAction::ShowSettings => "s",
+            KeyAction::PreviousItem => "ArrowUp",
+            KeyAction::NextItem => "ArrowDown",
+            KeyAction::PageLeft => "PageUp",
+            KeyAction::PageRight => "PageDown",
+            KeyAction::Open => "Enter",
+            KeyAction::Search => "/",
+            KeyAction::Delete => "Delete",
+            KeyAction::Edit => "e",
+            KeyAction::Help => "?",
+        }
+    }
+
+    /// The name of a key with its modifiers, e.g. "Ctrl+e".
+    /// Shift is left out of printable keys, since it is already in the character
+    pub fn key_name(key: &str, ctrl: bool, alt: bool, shift: bool) -> String {
+        let mut name = String::new();
+        if ctrl {
+            name.push_str("Ctrl+");
+        }
+        if alt {
+            name.push_str("Alt+");
+        }
+        if shift && key.chars().count() > 1 {
+            name.push_str("Shift+");
+        }
+        name.push_str(key);
+        name
+    }
+}
+
+const DAY_MILLIS: i64 = 24 * 60 * 60 * 1000;
+
+impl Review {
+    /// Never reviewed words are due, the interval doubles with every correct answer up to 64 days
+    pub fn is_due(&self, now: i64) -> bool {
+        let interval_days = 1i64 << self.correct.min(6);
+        self.count == 0 || now - self.datetime >= interval_days * DAY_MILLIS
+    }
+}
+
+impl SmartFolder {
+    pub fn new(name: &str, conditions: Vec<QueryCondition>) -> SmartFolder {
+        SmartFolder {
+            id: None,
+            name: name.to_string(),
+            conditions,
+            datetime: Utc::now().timestamp_millis(),
+        }
+    }
+
+    pub fn matches(&self, word: &Word, now: i64) -> bool {
+        self.conditions.iter().all(|condition| condition.matches(word, now))
+    }
+}
+
+impl QueryCondition {
+    pub fn matches(&self, word: &Word, now: i64) -> bool {
+        match self {
+            QueryCondition::AddedWithinDays(days) => now - word.datetime <= *days as i64 * DAY_MILLIS,
+            QueryCondition::WordClass(word_class) => {
+                word.word_class.trim().eq_ignore_ascii_case(word_class.trim())
+            }
+            QueryCondition::Tag(tag) => {
+                let tag = format!("#{}", tag.trim().trim_start_matches('#').to_lowercase());
+                std::iter::once(&word.note)
+                    .chain(word.examples.iter())
+                    .flat_map(|text| text.split(|c: char| !(c.is_alphanumeric() || "#-_".contains(c))))
+                    .any(|token| token.to_lowercase() == tag)
+            }
+            QueryCondition::DueForReview => word.review.is_due(now),
+            QueryCondition::NoNote => word.note.trim().is_empty(),
+        }
+    }
+}
+
+/// The parent of the folder, if it still exists
+pub fn folder_parent(folders: &[Folder], folder: &Folder) -> Option<u32> {
+    folder
+        .parent_id
+        .filter(|parent_id| folders.iter().any(|x| x.id == Some(*parent_id)))
+}
+
+/// The folder with the given id and all its descendants, parents go before their children
+pub fn folder_subtree(folders: &[Folder], id: u32) -> Vec<Folder> {
+    let mut visited = HashSet::new();
+    let mut subtree: Vec<Folder> = folders.iter().filter(|x| x.id == Some(id)).cloned().collect();
+
+    let mut index = 0;
+    while index < subtree.len() {
+        let parent_id = subtree[index].id;
+        visited.insert(parent_id);
+        // the visited set protects against cycles in broken data
+        let children: Vec<Folder> = folders
+            .iter()
+            .filter(|x| x.parent_id.is_some() && x.parent_id == parent_id && !visited.contains(&x.id))
+            .cloned()
+            .collect();
+        for child in children {
+            visited.insert(child.id);
+            subtree.push(child);
+        }
+        index += 1;
+    }
+
+    subtree
+}
+
+/// Archived folders hide their subfolders too
+pub fn folder_is_archived(folders: &[Folder], folder: &Folder) -> bool {
+    folder.archived || folder_path(folders, &folder.folder).iter().any(|x| x.archived)
+}
+
+/// Pinned folders go first, otherwise the order is kept
+pub fn pinned_first(folders: &mut [Folder]) {
+    folders.sort_by_key(|folder| !folder.pinned);
+}
+
+/// The ancestors of the folder with the given name and the folder itself, starting from the root
+pub fn folder_path(folders: &[Folder], name: &str) -> Vec<Folder> {
+    let mut path: Vec<Folder> = vec![];
+    let mut current = folders.iter().find(|x| x.folder == name);
+
+    while let Some(folder) = current {
+        if path.iter().any(|x| x.id == folder.id) {
+            break;
+        }
+        path.push(folder.clone());
+        current = folder_parent(folders, folder)
+            .and_then(|parent_id| folders.iter().find(|x| x.id == Some(parent_id)));
+    }
+
+    path.reverse();
+    path
 }
@@ -0,0 +1,270 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Print layouts of exported words: a word list table and flashcards to cut out.
+//! The page is a standalone HTML document, the browser prints it or saves it as PDF.
+
+use crate::i18n::{tr, Locale, Msg};
+use crate::markdown::{parse, Block, Inline};
+use crate::model::{Data, Word};
+
+const CARD_COLUMNS: usize = 2;
+const CARD_ROWS: usize = 5;
+const PAGE_MARGIN_MM: f32 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum PrintMode {
+    #[default]
+    WordList,
+    Flashcards,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum PaperSize {
+    #[default]
+    A4,
+    Letter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum FontFamily {
+    #[default]
+    Serif,
+    SansSerif,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PrintOptions {
+    pub(crate) mode: PrintMode,
+    pub(crate) paper_size: PaperSize,
+    pub(crate) font_family: FontFamily,
+    /// In points
+    pub(crate) font_size: u32,
+    pub(crate) locale: Locale,
+}
+
+impl PaperSize {
+    pub(crate) const ALL: [PaperSize; 2] = [PaperSize::A4, PaperSize::Letter];
+
+    /// The name for the CSS `@page` rule
+    pub(crate) fn css_name(&self) -> &'static str {
+        match self {
+            PaperSize::A4 => "A4",
+            PaperSize::Letter => "letter",
+        }
+    }
+
+    fn height_mm(&self) -> f32 {
+        match self {
+            PaperSize::A4 => 297.0,
+            PaperSize::Letter => 279.4,
+        }
+    }
+}
+
+impl FontFamily {
+    fn css_name(&self) -> &'static str {
+        match self {
+            FontFamily::Serif => "serif",
+            FontFamily::SansSerif => "sans-serif",
+        }
+    }
+}
+
+pub(crate) fn print_html(data: &Data, options: &PrintOptions) -> String {
+    let title = data.folders.first().map(|folder| folder.folder.as_str()).unwrap_or_default();
+    let body = match options.mode {
+        PrintMode::WordList => word_list(data, options.locale),
+        PrintMode::Flashcards => flashcards(&data.words),
+    };
+    let card_height = (options.paper_size.height_mm() - 2.0 * PAGE_MARGIN_MM) / CARD_ROWS as f32;
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="{lang}">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+@page {{ size: {paper}; margin: {margin}mm; }}
+body {{ font-family: {font}; font-size: {font_size}pt; margin: 0; }}
+table {{ width: 100%; border-collapse: collapse; margin-bottom: 1em; }}
+th, td {{ border: 1px solid #888; padding: 0.2em 0.4em; text-align: left; vertical-align: top; }}
+thead {{ display: table-header-group; }}
+tr {{ break-inside: avoid; }}
+p, ul, ol {{ margin: 0; }}
+.examples {{ font-style: italic; }}
+.sheet {{ display: grid; grid-template-columns: repeat({columns}, 1fr); grid-auto-rows: {card_height:.1}mm; break-after: page; }}
+.card {{ border: 1px dashed #888; padding: 0.5em; overflow: hidden; display: flex; flex-direction: column; justify-content: center; align-items: center; text-align: center; }}
+.front .word {{ font-size: 1.6em; font-weight: bold; }}
+.word-class {{ font-style: italic; }}
+</style>
+</head>
+<body>
+{body}</body>
+</html>
+"#,
+        lang = options.locale.code(),
+        title = escape(title),
+        paper = options.paper_size.css_name(),
+        margin = PAGE_MARGIN_MM,
+        font = options.font_family.css_name(),
+        font_size = options.font_size,
+        columns = CARD_COLUMNS,
+    )
+}
+
+/// A table for every folder, the subfolders follow their parents
+fn word_list(data: &Data, locale: Locale) -> String {
+    let mut html = String::new();
+    for folder in &data.folders {
+        let words: Vec<&Word> = data.words.iter().filter(|word| word.folder == folder.folder).collect();
+        if words.is_empty() {
+            continue;
+        }
+        html += &format!("<h2>{}</h2>\n<table>\n<thead><tr>", escape(&folder.folder));
+        for header in [
+            Msg::PrintHeaderWord,
+            Msg::PrintHeaderWordClass,
+            Msg::PrintHeaderPronunciation,
+            Msg::PrintHeaderNote,
+        ] {
+            html += &format!("<th>{}</th>", escape(tr(locale, header)));
+        }
+        html += "</tr></thead>\n<tbody>\n";
+        for word in words {
+            let (pronunciation, note) = split_pronunciation(&word.note);
+            html += &format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}{}</td></tr>\n",
+                escape(&word.word),
+                escape(&word.word_class),
+                escape(&pronunciation),
+                markdown_html(&note),
+                examples_html(&word.examples),
+            );
+        }
+        html += "</tbody>\n</table>\n";
+    }
+    html
+}
+
+/// Pages of fronts with the words, each followed by the page of their backs.
+/// The backs are mirrored in every row, so they match the fronts when the sheet is printed on both sides
+fn flashcards(words: &[Word]) -> String {
+    let mut html = String::new();
+    for page in words.chunks(CARD_COLUMNS * CARD_ROWS) {
+        html += "<div class=\"sheet front\">\n";
+        for word in page {
+            html += &format!(
+                "<div class=\"card\"><div class=\"word\">{}</div></div>\n",
+                escape(&word.word),
+            );
+        }
+        html += "</div>\n<div class=\"sheet back\">\n";
+        for row in page.chunks(CARD_COLUMNS) {
+            for column in (0..CARD_COLUMNS).rev() {
+                html += &match row.get(column) {
+                    Some(word) => {
+                        let (pronunciation, note) = split_pronunciation(&word.note);
+                        format!(
+                            "<div class=\"card\">{}{}{}{}</div>\n",
+                            optional_div("word-class", &word.word_class),
+                            optional_div("pronunciation", &pronunciation),
+                            markdown_html(&note),
+                            examples_html(&word.examples),
+                        )
+                    }
+                    // keeps the place of the missing front
+                    None => "<div class=\"blank\"></div>\n".to_string(),
+                };
+            }
+        }
+        html += "</div>\n";
+    }
+    html
+}
+
+/// Notes often start with the pronunciation, as /haʊs/ or [haʊs]
+pub(crate) fn split_pronunciation(note: &str) -> (String, String) {
+    let note = note.trim();
+    let closing = match note.chars().next() {
+        Some('/') => '/',
+        Some('[') => ']',
+        _ => return (String::new(), note.to_string()),
+    };
+    match note[1..].find(closing) {
+        Some(end) => {
+            let end = end + 2;
+            (note[..end].to_string(), note[end..].trim().to_string())
+        }
+        None => (String::new(), note.to_string()),
+    }
+}
+
+fn markdown_html(text: &str) -> String {
+    parse(text)
+        .iter()
+        .map(|block| match block {
+            Block::Paragraph(lines) => format!(
+                "<p>{}</p>",
+                lines.iter().map(|line| inline_html(line)).collect::<Vec<_>>().join("<br>"),
+            ),
+            Block::List { ordered, items } => {
+                let tag = if *ordered { "ol" } else { "ul" };
+                let items: String = items
+                    .iter()
+                    .map(|item| format!("<li>{}</li>", inline_html(item)))
+                    .collect();
+                format!("<{tag}>{items}</{tag}>")
+            }
+        })
+        .collect()
+}
+
+fn inline_html(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => escape(text),
+            Inline::Bold(text) => format!("<b>{}</b>", escape(text)),
+            Inline::Italic(text) => format!("<i>{}</i>", escape(text)),
+        })
+        .collect()
+}
+
+fn optional_div(class: &str, text: &str) -> String {
+    match text.trim().is_empty() {
+        true => String::new(),
+        false => format!("<div class=\"{}\">{}</div>", class, escape(text.trim())),
+    }
+}
+
+fn examples_html(examples: &[String]) -> String {
+    match examples.is_empty() {
+        true => String::new(),
+        false => format!(
+            "<ul class=\"examples\">{}</ul>",
+            examples.iter().map(|example| format!("<li>{}</li>", escape(example))).collect::<String>(),
+        ),
+    }
+}
+
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,131 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use log::{debug, warn};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::dictionary_source::{headword, lookup_url};
+use crate::i18n::Locale;
+use crate::model::{DailyTotal, Word};
+use crate::source_context::sentence_around;
+use crate::stats::local_offset_seconds;
+use crate::storage::{storage_error_message, Storage, StorageBackend, StorageConfig, StorageError};
+
+const QUICK_ADD_FOLDER_IS_NOT_SET_ERROR: &str = "Default folder for quick add is not set";
+const WORD_IS_EMPTY_ERROR: &str = "Word is empty";
+
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct QuickAddResult {
+    pub(crate) folder: String,
+    pub(crate) lookup_url: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum QuickAddError {
+    FolderIsNotSet,
+    Storage(StorageError),
+}
+
+impl From<StorageError> for QuickAddError {
+    fn from(err: StorageError) -> Self {
+        QuickAddError::Storage(err)
+    }
+}
+
+/// Called from the service worker, where the side panel and its storage aren't available,
+/// so the database is opened and closed for every word.
+#[wasm_bindgen]
+pub async fn quick_add_word(
+    url: String,
+    word: String,
+    word_class: String,
+    title: String,
+    phonetics: String,
+) -> Result<String, JsValue> {
+    let word = Word::new("", &headword(word, &title), &word_class, &url, &phonetics);
+
+    let result = add_to_quick_add_folder(word, false).await?;
+
+    Ok(result.folder)
+}
+
+/// Saves text selected on any web page, `text` is the element around the selection.
+#[wasm_bindgen]
+pub async fn quick_add_selection(
+    url: String,
+    selection: String,
+    text: String,
+) -> Result<JsValue, JsValue> {
+    let selection = selection.split_whitespace().collect::<Vec<&str>>().join(" ");
+    let word = Word::new("", &selection, "", &url, "")
+        .with_source_context(&sentence_around(&text, &selection));
+
+    let result = add_to_quick_add_folder(word, true).await?;
+
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+}
+
+async fn add_to_quick_add_folder(word: Word, lookup: bool) -> Result<QuickAddResult, JsValue> {
+    if word.word.is_empty() {
+        return Err(JsValue::from_str(WORD_IS_EMPTY_ERROR));
+    }
+
+    let storage = Storage::open(&StorageConfig::default()).await.map_err(js_error)?;
+    let result = add_word(&storage, word, lookup).await;
+    storage.close_db();
+
+    debug!("add_to_quick_add_folder: {:?}", result);
+    result.map_err(|err| match err {
+        QuickAddError::FolderIsNotSet => JsValue::from_str(QUICK_ADD_FOLDER_IS_NOT_SET_ERROR),
+        QuickAddError::Storage(err) => js_error(err),
+    })
+}
+
+/// Adds the word to the quick add folder of the settings, it doesn't depend on the browser,
+/// so it can be tested with any storage
+pub(crate) async fn add_word<S: StorageBackend>(
+    storage: &S,
+    mut word: Word,
+    lookup: bool,
+) -> Result<QuickAddResult, QuickAddError> {
+    let settings = storage.load_settings().await?;
+
+    let folder = Some(settings.quick_add_folder)
+        .filter(|folder| !folder.is_empty())
+        .ok_or(QuickAddError::FolderIsNotSet)?;
+
+    word.folder = folder.clone();
+    storage.add::<Word>(&word).await?;
+
+    // the word is saved anyway, so a failed total doesn't fail the quick add
+    let added = DailyTotal { words: 1, reviews: 0 };
+    if let Err(err) = storage.add_to_daily_total(local_offset_seconds(), word.datetime, added).await {
+        warn!("can't save the daily total: {}", err);
+    }
+
+    let lookup_url = match lookup && settings.lookup_after_save {
+        true => Some(lookup_url(&word.word)),
+        false => None,
+    };
+
+    Ok(QuickAddResult { folder, lookup_url })
+}
+
+/// The message is shown in the tooltip of the extension icon
+fn js_error(err: StorageError) -> JsValue {
+    JsValue::from_str(storage_error_message(Locale::browser(), &err))
+}
@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+const MAX_SOURCE_CONTEXT_LEN: usize = 500;
+
+/// Returns the sentence of `text` that contains `selection`,
+/// or the whole text if the selection isn't found in it.
+pub(crate) fn sentence_around(text: &str, selection: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+    let selection = selection.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+    let sentence = match text.find(&selection) {
+        None => text.as_str(),
+        Some(start) => {
+            let end = start + selection.len();
+            let sentence_start = text[..start]
+                .char_indices()
+                .rfind(|&(_, ch)| is_sentence_end(ch))
+                .map(|(index, ch)| index + ch.len_utf8())
+                .unwrap_or(0);
+            let sentence_end = text[end..]
+                .char_indices()
+                .find(|&(_, ch)| is_sentence_end(ch))
+                .map(|(index, ch)| end + index + ch.len_utf8())
+                .unwrap_or(text.len());
+            &text[sentence_start..sentence_end]
+        }
+    };
+
+    truncate(sentence.trim(), MAX_SOURCE_CONTEXT_LEN)
+}
+
+fn is_sentence_end(ch: char) -> bool {
+    matches!(ch, '.' | '!' | '?' | '\u{3002}')
+}
+
+fn truncate(str: &str, max_len: usize) -> String {
+    match str.char_indices().nth(max_len) {
+        None => str.to_string(),
+        Some((index, _)) => format!("{}\u{2026}", &str[..index]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::tests::test_init;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    fn sentence_around_test() {
+        test_init("sentence_around_test");
+
+        let text = "First sentence. The quick brown fox\n jumps over the lazy dog! Last one?";
+        assert_eq!(
+            "The quick brown fox jumps over the lazy dog!",
+            sentence_around(text, "brown fox jumps")
+        );
+        assert_eq!("First sentence.", sentence_around(text, "First"));
+        assert_eq!("Last one?", sentence_around(text, "one"));
+        assert_eq!("no sentence end", sentence_around(" no  sentence end ", "missing"));
+
+        let japanese = "今日は晴れです。猫が好きです。明日は雨です。";
+        assert_eq!("猫が好きです。", sentence_around(japanese, "猫"));
+        assert_eq!("今日は晴れです。", sentence_around(japanese, "晴れ"));
+        assert_eq!("明日は雨です。", sentence_around(japanese, "雨です。"));
+
+        let long_text = "a".repeat(MAX_SOURCE_CONTEXT_LEN + 10);
+        let result = sentence_around(&long_text, "b");
+        assert_eq!(MAX_SOURCE_CONTEXT_LEN + 1, result.chars().count());
+    }
+}
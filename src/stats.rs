@@ -0,0 +1,309 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Aggregates of the words for the statistics view.
+//! The counters are saved and updated with every added, changed or deleted word,
+//! so the statistics are shown without reading all words.
+//! Days are numbered from 1970-01-01 in the local time of the user.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::model::{DailyGoal, DailyTotal, Word};
+
+const MILLIS_PER_DAY: i64 = 24 * 60 * 60 * 1000;
+
+/// How many of the most reviewed words are shown
+pub(crate) const MOST_REVIEWED_COUNT: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub(crate) struct ReviewedWord {
+    pub(crate) word: String,
+    pub(crate) folder: String,
+    pub(crate) count: u32,
+    pub(crate) correct: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct WordStats {
+    /// The days of the counters are local to this offset
+    pub(crate) offset_seconds: i32,
+    pub(crate) word_count: u32,
+    pub(crate) added_per_day: BTreeMap<i64, u32>,
+    /// Only the last review of a word is known
+    pub(crate) reviewed_per_day: BTreeMap<i64, u32>,
+    pub(crate) per_folder: BTreeMap<String, u32>,
+    pub(crate) per_word_class: BTreeMap<String, u32>,
+    pub(crate) review_count: u32,
+    pub(crate) correct_count: u32,
+    /// At most `MOST_REVIEWED_COUNT` words, the most reviewed go first
+    most_reviewed: Vec<ReviewedWord>,
+    /// Places of the words that dropped out of the full list, only all words can fill them
+    #[serde(skip)]
+    missing_reviewed: usize,
+    /// The words outside the list were reviewed at most that many times
+    #[serde(skip)]
+    reviewed_floor: u32,
+}
+
+impl WordStats {
+    pub(crate) fn new(offset_seconds: i32) -> WordStats {
+        WordStats {
+            offset_seconds,
+            ..WordStats::default()
+        }
+    }
+
+    pub(crate) fn day(&self, datetime: i64) -> i64 {
+        local_day(datetime, self.offset_seconds)
+    }
+
+    pub(crate) fn add(&mut self, word: &Word) {
+        self.word_count += 1;
+        *self.added_per_day.entry(self.day(word.datetime)).or_default() += 1;
+        *self.per_folder.entry(word.folder.clone()).or_default() += 1;
+        *self.per_word_class.entry(word.word_class.trim().to_string()).or_default() += 1;
+
+        if word.review.count > 0 {
+            self.review_count += word.review.count;
+            self.correct_count += word.review.correct;
+            *self.reviewed_per_day.entry(self.day(word.review.datetime)).or_default() += 1;
+            self.add_reviewed(word);
+        }
+    }
+
+    fn add_reviewed(&mut self, word: &Word) {
+        let reviewed = ReviewedWord {
+            word: word.word.clone(),
+            folder: word.folder.clone(),
+            count: word.review.count,
+            correct: word.review.correct,
+        };
+        if self.missing_reviewed > 0 {
+            // a word below the floor may be behind some words outside the list
+            if reviewed.count < self.reviewed_floor {
+                return;
+            }
+            self.missing_reviewed -= 1;
+        }
+        let index = self.most_reviewed.partition_point(|x| x.count >= reviewed.count);
+        self.most_reviewed.insert(index, reviewed);
+        self.most_reviewed.truncate(MOST_REVIEWED_COUNT);
+    }
+
+    /// Undoes `add` of the word as it was stored
+    pub(crate) fn remove(&mut self, word: &Word) {
+        let day = self.day(word.datetime);
+        self.word_count = self.word_count.saturating_sub(1);
+        decrement(&mut self.added_per_day, day);
+        decrement(&mut self.per_folder, word.folder.clone());
+        decrement(&mut self.per_word_class, word.word_class.trim().to_string());
+
+        if word.review.count > 0 {
+            self.review_count = self.review_count.saturating_sub(word.review.count);
+            self.correct_count = self.correct_count.saturating_sub(word.review.correct);
+            let review_day = self.day(word.review.datetime);
+            decrement(&mut self.reviewed_per_day, review_day);
+            self.remove_reviewed(word);
+        }
+    }
+
+    fn remove_reviewed(&mut self, word: &Word) {
+        let Some(index) = self
+            .most_reviewed
+            .iter()
+            .position(|reviewed| reviewed.folder == word.folder && reviewed.word == word.word)
+        else {
+            return;
+        };
+        // a list that isn't full holds all reviewed words, so nothing drops out
+        if self.most_reviewed.len() + self.missing_reviewed == MOST_REVIEWED_COUNT {
+            if self.missing_reviewed == 0 {
+                self.reviewed_floor = self.most_reviewed.last().map_or(0, |reviewed| reviewed.count);
+            }
+            self.missing_reviewed += 1;
+        }
+        self.most_reviewed.remove(index);
+    }
+
+    /// The most reviewed go first
+    pub(crate) fn most_reviewed(&self) -> &[ReviewedWord] {
+        &self.most_reviewed
+    }
+
+    /// True when a word dropped out of the most reviewed ones and the list must be collected again
+    pub(crate) fn needs_most_reviewed(&self) -> bool {
+        self.missing_reviewed > 0
+    }
+
+    /// Collects the most reviewed words again from all words
+    pub(crate) fn set_most_reviewed<'a>(&mut self, words: impl IntoIterator<Item = &'a Word>) {
+        self.most_reviewed.clear();
+        self.missing_reviewed = 0;
+        for word in words {
+            if word.review.count > 0 {
+                self.add_reviewed(word);
+            }
+        }
+    }
+
+    /// The days when a word was added or reviewed the last time
+    pub(crate) fn active_days(&self) -> BTreeSet<i64> {
+        self.added_per_day
+            .keys()
+            .chain(self.reviewed_per_day.keys())
+            .copied()
+            .collect()
+    }
+
+    /// The totals known from the words, for the days before the totals were saved
+    pub(crate) fn daily_totals(&self) -> BTreeMap<i64, DailyTotal> {
+        let mut totals: BTreeMap<i64, DailyTotal> = BTreeMap::new();
+        for (day, words) in &self.added_per_day {
+            totals.entry(*day).or_default().words = *words;
+        }
+        for (day, reviews) in &self.reviewed_per_day {
+            totals.entry(*day).or_default().reviews = *reviews;
+        }
+        totals
+    }
+
+    /// Correct answers in percent, None without reviews
+    pub(crate) fn accuracy(&self) -> Option<u32> {
+        (self.review_count > 0).then(|| self.correct_count * 100 / self.review_count)
+    }
+
+    /// Words added on each of the `count` days up to `today`, the days without words included
+    pub(crate) fn last_days(&self, today: i64, count: i64) -> Vec<(i64, u32)> {
+        (today - count + 1..=today)
+            .map(|day| (day, self.added_per_day.get(&day).copied().unwrap_or(0)))
+            .collect()
+    }
+
+    /// Words added in each of the `count` weeks up to the week of `today`, by the first day of the week
+    pub(crate) fn last_weeks(&self, today: i64, count: i64) -> Vec<(i64, u32)> {
+        let first_day = week_start(today) - (count - 1) * 7;
+        (0..count)
+            .map(|week| {
+                let start = first_day + week * 7;
+                let added = self.added_per_day.range(start..start + 7).map(|(_, added)| added).sum();
+                (start, added)
+            })
+            .collect()
+    }
+
+    /// Days in a row with some activity, ending today or yesterday if there is nothing yet today
+    pub(crate) fn current_streak(&self, today: i64) -> u32 {
+        let active_days = self.active_days();
+        let last_day = match active_days.contains(&today) {
+            true => today,
+            false => today - 1,
+        };
+        (0..)
+            .take_while(|days| active_days.contains(&(last_day - days)))
+            .count() as u32
+    }
+
+    pub(crate) fn longest_streak(&self) -> u32 {
+        let mut longest = 0;
+        let mut streak = 0;
+        let mut previous = None;
+        for day in &self.active_days() {
+            streak = match previous {
+                Some(previous) if previous + 1 == *day => streak + 1,
+                _ => 1,
+            };
+            longest = longest.max(streak);
+            previous = Some(*day);
+        }
+        longest
+    }
+}
+
+/// Days in a row with the goal met, ending today or yesterday if the goal isn't met yet today
+pub(crate) fn goal_streak(totals: &BTreeMap<i64, DailyTotal>, goal: &DailyGoal, today: i64) -> u32 {
+    let is_met = |day: i64| totals.get(&day).is_some_and(|total| goal.is_met(total));
+    let last_day = match is_met(today) {
+        true => today,
+        false => today - 1,
+    };
+    (0..)
+        .take_while(|days| is_met(last_day - days))
+        .count() as u32
+}
+
+/// Adds new words and reviews to the total of the day
+pub(crate) fn add_to_day(totals: &mut BTreeMap<i64, DailyTotal>, day: i64, added: DailyTotal) {
+    if added == DailyTotal::default() {
+        return;
+    }
+    let total = totals.entry(day).or_default();
+    total.words += added.words;
+    total.reviews += added.reviews;
+}
+
+/// 1 if the word was reviewed and it wasn't reviewed on that local day before, the unit of `DailyTotal::reviews`
+pub(crate) fn new_review(old: &Word, new: &Word, offset_seconds: i32) -> u32 {
+    let reviewed = new.review.count > old.review.count;
+    let counted = old.review.count > 0
+        && local_day(old.review.datetime, offset_seconds) == local_day(new.review.datetime, offset_seconds);
+    (reviewed && !counted) as u32
+}
+
+/// Counts that drop to zero are removed, so they don't show up as active days or empty bars
+fn decrement<K: Ord>(counts: &mut BTreeMap<K, u32>, key: K) {
+    if let Some(count) = counts.get_mut(&key) {
+        *count = count.saturating_sub(1);
+        if *count == 0 {
+            counts.remove(&key);
+        }
+    }
+}
+
+/// The offset of the local time zone from UTC
+pub(crate) fn local_offset_seconds() -> i32 {
+    Local::now().offset().local_minus_utc()
+}
+
+pub(crate) fn local_day(datetime: i64, offset_seconds: i32) -> i64 {
+    (datetime + offset_seconds as i64 * 1000).div_euclid(MILLIS_PER_DAY)
+}
+
+/// The items with the largest counts first
+pub(crate) fn largest(counts: &BTreeMap<String, u32>, limit: usize) -> Vec<(String, u32)> {
+    let mut counts: Vec<(String, u32)> = counts
+        .iter()
+        .map(|(name, count)| (name.clone(), *count))
+        .collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts.truncate(limit);
+    counts
+}
+
+/// Weeks start on Monday, 1970-01-01 was a Thursday
+pub(crate) fn week_start(day: i64) -> i64 {
+    day - (day + 3).rem_euclid(7)
+}
+
+pub(crate) fn day_to_string(day: i64) -> String {
+    NaiveDate::from_ymd_opt(1970, 1, 1)
+        .and_then(|epoch| epoch.checked_add_signed(chrono::Duration::days(day)))
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
@@ -16,43 +16,56 @@
 
 use rexie::Store;
 
-use crate::storage::{HasId, ObjStoreName, Storage, StorageError};
+use crate::storage::{HasId, ObjStoreName, Storage, StorageError, StorageErrorKind, UniqueKey};
 
 impl Storage {
-    pub(crate) async fn add<T>(&self, value: &T) -> Result<u32, StorageError>
+    pub(super) async fn add<T>(&self, value: &T) -> Result<u32, StorageError>
     where
-        T: serde::Serialize + ObjStoreName + HasId<T>,
+        T: serde::Serialize + ObjStoreName + HasId<T> + UniqueKey,
     {
         let tc = self.get_transaction(T::OBJ_STORE_NAME)?;
 
         let id = self.add_value(&tc.store, value).await?;
 
+        Self::count_word_change(&tc.transaction, T::OBJ_STORE_NAME, &[], &[value.set_id(Some(id))]).await?;
+
         tc.transaction.commit().await?;
 
         Ok(id)
     }
 
-    pub(crate) async fn import<T>(&self, data: &Vec<T>) -> Result<(), StorageError>
+    pub(super) async fn import<T>(&self, data: &[T]) -> Result<(), StorageError>
     where
-        T: serde::Serialize + ObjStoreName + HasId<T>,
+        T: serde::Serialize + ObjStoreName + HasId<T> + UniqueKey,
     {
         let tc = self.get_transaction(T::OBJ_STORE_NAME)?;
 
+        let mut added = Vec::with_capacity(data.len());
         for value in data {
-            self.add_value(&tc.store, value).await?;
+            let id = self.add_value(&tc.store, value).await?;
+            added.push(value.set_id(Some(id)));
         }
 
+        Self::count_word_change(&tc.transaction, T::OBJ_STORE_NAME, &[], &added).await?;
+
         tc.transaction.commit().await?;
 
         Ok(())
     }
 
-    async fn add_value<T>(&self, store: &Store, value: &T) -> Result<u32, StorageError>
+    pub(super) async fn add_value<T>(&self, store: &Store, value: &T) -> Result<u32, StorageError>
     where
-        T: serde::Serialize + HasId<T>,
+        T: serde::Serialize + HasId<T> + UniqueKey,
     {
         let js_value = serde_wasm_bindgen::to_value(value)?;
-        let result = store.add(&js_value, None).await?;
+        let result = store.add(&js_value, None).await.map_err(|error| {
+            match StorageError::from(error) {
+                error if error.kind() == StorageErrorKind::ConstraintViolation => {
+                    StorageError::ConstraintError(value.unique_key().join(", "))
+                }
+                error => error,
+            }
+        })?;
         // debug!("add_vec: result: {:?}", &result);
 
         let id: u32 = serde_wasm_bindgen::from_value(result.clone())?;
@@ -0,0 +1,331 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::model::{
+    default_sort_direction, folder_path, folder_subtree, DailyTotal, Data, Folder, FoldersAndCount,
+    Settings, SmartFolder, Word, WordsAndCount,
+};
+use crate::stats::WordStats;
+use crate::storage::{
+    HasId, IMPORT_EXPORT_DATA_VERSION, INVALID_VERSION_ERROR, ObjStoreName, Storage,
+    StorageError, UniqueKey,
+};
+
+/// Operations shared by the IndexedDB storage and the in-memory one used in native tests
+pub(crate) trait StorageBackend {
+    async fn add<T>(&self, value: &T) -> Result<u32, StorageError>
+    where
+        T: Serialize + DeserializeOwned + ObjStoreName + HasId<T> + UniqueKey;
+
+    async fn import<T>(&self, data: &[T]) -> Result<(), StorageError>
+    where
+        T: Serialize + DeserializeOwned + ObjStoreName + HasId<T> + UniqueKey;
+
+    async fn get_folders(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        direction: String,
+        include_archived: bool,
+    ) -> Result<FoldersAndCount, StorageError>;
+
+    async fn get_words(
+        &self,
+        folder: String,
+        filter: String,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        direction: String,
+    ) -> Result<WordsAndCount, StorageError>;
+
+    async fn update<T>(&self, value: &T) -> Result<(), StorageError>
+    where
+        T: Serialize + ObjStoreName + HasId<T>;
+
+    async fn delete_by_id<T>(&self, id: u32) -> Result<(), StorageError>
+    where
+        T: ObjStoreName;
+
+    /// Deletes the folder, its subfolders and their words at once
+    async fn delete_subtree(&self, id: u32) -> Result<(), StorageError>;
+
+    async fn load_settings(&self) -> Result<Settings, StorageError>;
+
+    async fn save_settings(&self, settings: &Settings) -> Result<(), StorageError>;
+
+    /// None if the totals were never saved
+    async fn load_daily_totals(&self) -> Result<Option<BTreeMap<i64, DailyTotal>>, StorageError>;
+
+    /// Adds new words and reviews done at `datetime` to the totals of that local day
+    /// and returns the totals, the totals are read and written in one transaction.
+    /// Totals that were never saved are filled from the words instead, a change is counted
+    /// after it's stored, so the words already include it
+    async fn add_to_daily_total(
+        &self,
+        offset_seconds: i32,
+        datetime: i64,
+        added: DailyTotal,
+    ) -> Result<BTreeMap<i64, DailyTotal>, StorageError>;
+
+    /// The saved statistics of the words, they are collected from all words the first time
+    /// and when the local time zone changes, `offset_seconds` is the offset of the local time from UTC
+    async fn get_word_stats(&self, offset_seconds: i32) -> Result<WordStats, StorageError>;
+
+    /// All values of the store without ids
+    async fn export_store<T>(&self) -> Result<Vec<T>, StorageError>
+    where
+        T: DeserializeOwned + ObjStoreName + HasId<T>;
+
+    async fn export_data(&self) -> Result<String, StorageError> {
+        let folders = self.get_all_folders().await?;
+        let folder_parents = folder_parents(&folders);
+
+        let folders: Vec<Folder> = self.export_store().await?;
+        let words: Vec<Word> = self.export_store().await?;
+        let smart_folders: Vec<SmartFolder> = self.export_store().await?;
+        let data = Data {
+            version: IMPORT_EXPORT_DATA_VERSION,
+            folders: folders.iter().map(|folder| folder.with_parent(None)).collect(),
+            words,
+            smart_folders,
+            settings: Some(self.load_settings().await?.migrate()),
+            folder_parents,
+        };
+        Ok(serde_json::to_string(&data)?)
+    }
+
+    /// The folder, its subfolders and their words in the format of `export_data`
+    async fn export_subtree(&self, id: u32) -> Result<String, StorageError> {
+        Ok(serde_json::to_string(&self.get_subtree_data(id).await?)?)
+    }
+
+    /// The folder, its subfolders and their words without ids, the folder becomes a root
+    async fn get_subtree_data(&self, id: u32) -> Result<Data, StorageError> {
+        let subtree = self.get_subtree(id).await?;
+
+        let mut words = vec![];
+        for folder in &subtree {
+            words.extend(self.get_folder_words(&folder.folder).await?);
+        }
+
+        let mut folder_parents = folder_parents(&subtree);
+        if let Some(root) = subtree.first() {
+            folder_parents.remove(&root.folder);
+        }
+
+        let data = Data {
+            version: IMPORT_EXPORT_DATA_VERSION,
+            folders: subtree
+                .iter()
+                .map(|folder| folder.set_id(None).with_parent(None))
+                .collect(),
+            words: words.into_iter().map(|word| word.set_id(None)).collect(),
+            smart_folders: vec![],
+            settings: None,
+            folder_parents,
+        };
+        Ok(data)
+    }
+
+    async fn import_data(&self, json: String) -> Result<Data, StorageError> {
+        let data: Data = serde_json::from_str(json.as_str())?;
+        // debug!("import: data: {:?}", &data);
+
+        if data.version != IMPORT_EXPORT_DATA_VERSION {
+            return Err(StorageError::ImportError(INVALID_VERSION_ERROR.to_string()));
+        }
+
+        self.import(&data.folders).await?;
+
+        let words: Vec<Word> = data.words.iter().map(Word::with_canonical_url).collect();
+        self.import(&words).await?;
+
+        self.import(&data.smart_folders).await?;
+
+        if let Some(settings) = &data.settings {
+            self.save_settings(&settings.clone().migrate()).await?;
+        }
+
+        self.set_folder_parents(&data.folder_parents).await?;
+
+        Ok(data)
+    }
+
+    async fn set_folder_parents(&self, parents: &BTreeMap<String, String>) -> Result<(), StorageError> {
+        if parents.is_empty() {
+            return Ok(());
+        }
+
+        let folders = self.get_all_folders().await?;
+        let ids: HashMap<&str, Option<u32>> = folders
+            .iter()
+            .map(|folder| (folder.folder.as_str(), folder.id))
+            .collect();
+
+        for folder in &folders {
+            if let Some(parent_id) = parents.get(&folder.folder).and_then(|parent| ids.get(parent.as_str())) {
+                self.update(&folder.with_parent(*parent_id)).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// All folders including the archived ones in the order they were added
+    async fn get_all_folders(&self) -> Result<Vec<Folder>, StorageError> {
+        let mut folders = self
+            .get_folders(None, None, default_sort_direction.to_string(), true)
+            .await?
+            .folders;
+        folders.sort_by_key(|folder| folder.id);
+        Ok(folders)
+    }
+
+    /// The folder and all its descendants, parents go before their children
+    async fn get_subtree(&self, id: u32) -> Result<Vec<Folder>, StorageError> {
+        let folders = self.get_all_folders().await?;
+        Ok(folder_subtree(&folders, id))
+    }
+
+    /// The ancestors of the folder and the folder itself, starting from the root
+    async fn get_folder_path(&self, name: &str) -> Result<Vec<Folder>, StorageError> {
+        let folders = self.get_all_folders().await?;
+        Ok(folder_path(&folders, name))
+    }
+
+    async fn get_folder_words(&self, name: &str) -> Result<Vec<Word>, StorageError> {
+        Ok(self
+            .get_words(name.to_string(), String::new(), None, None, String::new())
+            .await?
+            .words)
+    }
+
+    /// New words and reviews by local days.
+    /// The first time the totals are filled from the words added before the totals were saved
+    async fn get_daily_totals(&self, offset_seconds: i32) -> Result<BTreeMap<i64, DailyTotal>, StorageError> {
+        if let Some(totals) = self.load_daily_totals().await? {
+            return Ok(totals);
+        }
+        self.add_to_daily_total(offset_seconds, 0, DailyTotal::default()).await
+    }
+}
+
+fn folder_parents(folders: &[Folder]) -> BTreeMap<String, String> {
+    let names: HashMap<Option<u32>, &str> = folders
+        .iter()
+        .map(|folder| (folder.id, folder.folder.as_str()))
+        .collect();
+
+    folders
+        .iter()
+        .filter_map(|folder| {
+            let parent = names.get(&Some(folder.parent_id?))?;
+            Some((folder.folder.clone(), parent.to_string()))
+        })
+        .collect()
+}
+
+impl StorageBackend for Storage {
+    async fn add<T>(&self, value: &T) -> Result<u32, StorageError>
+    where
+        T: Serialize + DeserializeOwned + ObjStoreName + HasId<T> + UniqueKey,
+    {
+        Storage::add(self, value).await
+    }
+
+    async fn import<T>(&self, data: &[T]) -> Result<(), StorageError>
+    where
+        T: Serialize + DeserializeOwned + ObjStoreName + HasId<T> + UniqueKey,
+    {
+        Storage::import(self, data).await
+    }
+
+    async fn get_folders(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        direction: String,
+        include_archived: bool,
+    ) -> Result<FoldersAndCount, StorageError> {
+        Storage::get_folders(self, limit, offset, direction, include_archived).await
+    }
+
+    async fn get_words(
+        &self,
+        folder: String,
+        filter: String,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        direction: String,
+    ) -> Result<WordsAndCount, StorageError> {
+        Storage::get_words(self, folder, filter, limit, offset, direction).await
+    }
+
+    async fn update<T>(&self, value: &T) -> Result<(), StorageError>
+    where
+        T: Serialize + ObjStoreName + HasId<T>,
+    {
+        Storage::update(self, value).await
+    }
+
+    async fn delete_by_id<T>(&self, id: u32) -> Result<(), StorageError>
+    where
+        T: ObjStoreName,
+    {
+        Storage::delete_by_id::<T>(self, id).await
+    }
+
+    async fn delete_subtree(&self, id: u32) -> Result<(), StorageError> {
+        Storage::delete_subtree(self, id).await
+    }
+
+    async fn load_settings(&self) -> Result<Settings, StorageError> {
+        Storage::load_settings(self).await
+    }
+
+    async fn save_settings(&self, settings: &Settings) -> Result<(), StorageError> {
+        Storage::save_settings(self, settings).await
+    }
+
+    async fn load_daily_totals(&self) -> Result<Option<BTreeMap<i64, DailyTotal>>, StorageError> {
+        Storage::load_daily_totals(self).await
+    }
+
+    async fn add_to_daily_total(
+        &self,
+        offset_seconds: i32,
+        datetime: i64,
+        added: DailyTotal,
+    ) -> Result<BTreeMap<i64, DailyTotal>, StorageError> {
+        Storage::add_to_daily_total(self, offset_seconds, datetime, added).await
+    }
+
+    async fn get_word_stats(&self, offset_seconds: i32) -> Result<WordStats, StorageError> {
+        Storage::get_word_stats(self, offset_seconds).await
+    }
+
+    async fn export_store<T>(&self) -> Result<Vec<T>, StorageError>
+    where
+        T: DeserializeOwned + ObjStoreName + HasId<T>,
+    {
+        Storage::export_store(self).await
+    }
+}
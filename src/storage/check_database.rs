@@ -0,0 +1,181 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::mem::discriminant;
+
+use chrono::Utc;
+use gloo_utils::format::JsValueSerdeExt;
+use rexie::TransactionMode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wasm_bindgen::JsValue;
+
+use crate::model::{Folder, Word};
+use crate::storage::read_record::record_key;
+use crate::storage::{
+    ObjStoreName, Storage, StorageError, UniqueKey, OBJ_STORE_QUARANTINE, OBJ_STORE_SETTINGS, OBJ_STORE_WORDS,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CorruptRecord {
+    pub(crate) store: &'static str,
+    pub(crate) key: JsValue,
+    pub(crate) error: String,
+    pub(crate) value: String,
+    /// The record with the broken fields replaced by defaults, if it's still usable
+    pub(crate) repaired: Option<Value>,
+}
+
+impl CorruptRecord {
+    pub(crate) fn key(&self) -> String {
+        record_key(&self.key)
+    }
+}
+
+/// Removed records are kept as they were, so nothing is lost for good
+#[derive(Debug, Serialize, Deserialize)]
+struct QuarantinedRecord {
+    store: String,
+    key: String,
+    value: String,
+    datetime: i64,
+}
+
+impl Storage {
+    pub(crate) async fn check_database(&self) -> Result<Vec<CorruptRecord>, StorageError> {
+        let mut records = self.check_store(Folder::new("", "")).await?;
+        records.extend(self.check_store(Word::new("", "", "", "", "")).await?);
+        Ok(records)
+    }
+
+    async fn check_store<T>(&self, template: T) -> Result<Vec<CorruptRecord>, StorageError>
+    where
+        T: Serialize + DeserializeOwned + ObjStoreName + UniqueKey,
+    {
+        let template = serde_json::to_value(template)?;
+
+        let records = self
+            .get_store(T::OBJ_STORE_NAME)?
+            .get_all(None, None, None, None)
+            .await?
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let error = serde_wasm_bindgen::from_value::<T>(value.clone()).err()?;
+                let json = value.into_serde::<Value>().ok();
+                let repaired = json
+                    .as_ref()
+                    .and_then(|json| repair::<T>(&template, json, &key));
+                Some(CorruptRecord {
+                    store: T::OBJ_STORE_NAME,
+                    key,
+                    error: error.to_string(),
+                    value: json.map_or_else(|| format!("{:?}", value), |json| json.to_string()),
+                    repaired,
+                })
+            })
+            .collect();
+
+        Ok(records)
+    }
+
+    pub(crate) async fn repair_record(&self, record: &CorruptRecord) -> Result<(), StorageError> {
+        let Some(repaired) = &record.repaired else {
+            return Err(StorageError::CorruptRecord {
+                store: record.store.to_string(),
+                key: record.key(),
+            });
+        };
+        let js_value = JsValue::from_serde(repaired)?;
+
+        let tc = self.get_transaction(record.store)?;
+
+        tc.store.put(&js_value, Some(&record.key)).await?;
+
+        if record.store == OBJ_STORE_WORDS {
+            Self::drop_word_stats(&tc.transaction).await?;
+        }
+
+        tc.transaction.commit().await?;
+
+        Ok(())
+    }
+
+    /// Moves the record to the quarantine store
+    pub(crate) async fn quarantine_record(&self, record: &CorruptRecord) -> Result<(), StorageError> {
+        let quarantined = QuarantinedRecord {
+            store: record.store.to_string(),
+            key: record.key(),
+            value: record.value.clone(),
+            datetime: Utc::now().timestamp_millis(),
+        };
+        let js_value = serde_wasm_bindgen::to_value(&quarantined)?;
+
+        let transaction = self.rexie.transaction(
+            &[record.store, OBJ_STORE_QUARANTINE, OBJ_STORE_SETTINGS],
+            TransactionMode::ReadWrite,
+        )?;
+
+        transaction.store(OBJ_STORE_QUARANTINE)?.add(&js_value, None).await?;
+        transaction.store(record.store)?.delete(&record.key).await?;
+        if record.store == OBJ_STORE_WORDS {
+            Self::drop_word_stats(&transaction).await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn quarantined_count(&self) -> Result<u32, StorageError> {
+        Ok(self.get_store(OBJ_STORE_QUARANTINE)?.count(None).await?)
+    }
+}
+
+/// Keeps the fields of the record which have the expected type
+fn repair<T>(template: &Value, value: &Value, key: &JsValue) -> Option<Value>
+where
+    T: DeserializeOwned + UniqueKey,
+{
+    let mut repaired = merge(template, value);
+    repaired["id"] = Value::from(key.as_f64()? as u32);
+
+    let record: T = serde_json::from_value(repaired.clone()).ok()?;
+    record
+        .unique_key()
+        .iter()
+        .all(|field| !field.trim().is_empty())
+        .then_some(repaired)
+}
+
+fn merge(template: &Value, value: &Value) -> Value {
+    match (template, value) {
+        (Value::Object(template), Value::Object(value)) => Value::Object(
+            template
+                .iter()
+                .map(|(name, default)| {
+                    let field = match value.get(name) {
+                        None => default.clone(),
+                        Some(field) => merge(default, field),
+                    };
+                    (name.clone(), field)
+                })
+                .collect(),
+        ),
+        _ if discriminant(template) == discriminant(value) => value.clone(),
+        _ => template.clone(),
+    }
+}
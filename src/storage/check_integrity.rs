@@ -0,0 +1,232 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use rexie::{Store, TransactionMode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::model::{Folder, Word};
+use crate::storage::{
+    HasId, ObjStoreName, Storage, StorageError, OBJ_STORE_FOLDERS, OBJ_STORE_SETTINGS, OBJ_STORE_WORDS,
+};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct IntegrityReport {
+    /// Words whose folder doesn't exist
+    pub(crate) orphan_words: Vec<Word>,
+    pub(crate) mismatched_folders: Vec<MismatchedFolder>,
+    pub(crate) wrong_ids: Vec<WrongId>,
+}
+
+/// A word whose folder differs from an existing one only in case or whitespace
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MismatchedFolder {
+    pub(crate) word: Word,
+    pub(crate) folder: String,
+    /// The folder already has a word with the same name
+    pub(crate) conflict: bool,
+}
+
+/// A record whose id field disagrees with its primary key
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct WrongId {
+    pub(crate) store: &'static str,
+    pub(crate) key: u32,
+    pub(crate) id: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum IntegrityFix {
+    FixIds,
+    RenameMismatchedFolders,
+    /// Takes precedence over DeleteOrphanWords
+    CreateMissingFolders,
+    DeleteOrphanWords,
+}
+
+impl IntegrityReport {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.orphan_words.is_empty() && self.mismatched_folders.is_empty() && self.wrong_ids.is_empty()
+    }
+
+    /// Folders the orphan words refer to, without the empty name
+    pub(crate) fn missing_folders(&self) -> BTreeSet<String> {
+        self.orphan_words
+            .iter()
+            .map(|word| word.folder.trim().to_string())
+            .filter(|folder| !folder.is_empty())
+            .collect()
+    }
+}
+
+impl Storage {
+    pub(crate) async fn check_integrity(&self) -> Result<IntegrityReport, StorageError> {
+        let folders: Vec<(u32, Folder)> = self.read_keys_and_values().await?;
+        let words: Vec<(u32, Word)> = self.read_keys_and_values().await?;
+
+        let mut wrong_ids = find_wrong_ids(&folders);
+        wrong_ids.extend(find_wrong_ids(&words));
+
+        let folder_names: HashSet<&str> = folders.iter().map(|(_, folder)| folder.folder.as_str()).collect();
+        let normalized_names: HashMap<String, &str> = folders
+            .iter()
+            .map(|(_, folder)| (normalize(&folder.folder), folder.folder.as_str()))
+            .collect();
+        // a renamed word mustn't collide with an existing one or with another renamed word
+        let mut word_keys: HashSet<(String, String)> = words
+            .iter()
+            .map(|(_, word)| (word.folder.clone(), word.word.clone()))
+            .collect();
+
+        let mut orphan_words = vec![];
+        let mut mismatched_folders = vec![];
+        for (key, word) in &words {
+            if folder_names.contains(word.folder.as_str()) {
+                continue;
+            }
+            let word = word.set_id(Some(*key));
+            match normalized_names.get(&normalize(&word.folder)) {
+                None => orphan_words.push(word),
+                Some(folder) => mismatched_folders.push(MismatchedFolder {
+                    conflict: !word_keys.insert((folder.to_string(), word.word.clone())),
+                    folder: folder.to_string(),
+                    word,
+                }),
+            }
+        }
+
+        Ok(IntegrityReport { orphan_words, mismatched_folders, wrong_ids })
+    }
+
+    /// Returns the number of changed records
+    pub(crate) async fn repair(
+        &self,
+        report: &IntegrityReport,
+        fixes: &[IntegrityFix],
+    ) -> Result<u32, StorageError> {
+        let transaction = self
+            .rexie
+            .transaction(&[OBJ_STORE_FOLDERS, OBJ_STORE_WORDS, OBJ_STORE_SETTINGS], TransactionMode::ReadWrite)?;
+        let folders = transaction.store(OBJ_STORE_FOLDERS)?;
+        let words = transaction.store(OBJ_STORE_WORDS)?;
+
+        let mut count = 0;
+
+        if fixes.contains(&IntegrityFix::FixIds) {
+            for wrong_id in &report.wrong_ids {
+                let fixed = match wrong_id.store {
+                    OBJ_STORE_FOLDERS => Self::fix_id::<Folder>(&folders, wrong_id.key).await?,
+                    _ => Self::fix_id::<Word>(&words, wrong_id.key).await?,
+                };
+                if fixed {
+                    count += 1;
+                }
+            }
+        }
+
+        if fixes.contains(&IntegrityFix::RenameMismatchedFolders) {
+            for mismatched in report.mismatched_folders.iter().filter(|x| !x.conflict) {
+                let mut word = mismatched.word.clone();
+                word.folder = mismatched.folder.clone();
+                Self::put_with_id(&words, &word).await?;
+                count += 1;
+            }
+        }
+
+        if fixes.contains(&IntegrityFix::CreateMissingFolders) {
+            for folder in report.missing_folders() {
+                self.add_value(&folders, &Folder::new(&folder, "")).await?;
+                count += 1;
+            }
+        } else if fixes.contains(&IntegrityFix::DeleteOrphanWords) {
+            for word in &report.orphan_words {
+                words.delete(&serde_wasm_bindgen::to_value(&word.id)?).await?;
+                count += 1;
+            }
+        }
+
+        if count > 0 {
+            Self::drop_word_stats(&transaction).await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(count)
+    }
+
+    async fn read_keys_and_values<T>(&self) -> Result<Vec<(u32, T)>, StorageError>
+    where
+        T: DeserializeOwned + ObjStoreName,
+    {
+        let records = self
+            .get_store(T::OBJ_STORE_NAME)?
+            .get_all(None, None, None, None)
+            .await?
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let key = key.as_f64()? as u32;
+                // corrupted records are reported by check_database()
+                let value = serde_wasm_bindgen::from_value(value).ok()?;
+                Some((key, value))
+            })
+            .collect();
+
+        Ok(records)
+    }
+
+    async fn fix_id<T>(store: &Store, key: u32) -> Result<bool, StorageError>
+    where
+        T: Serialize + DeserializeOwned + HasId<T>,
+    {
+        let value = store.get(&serde_wasm_bindgen::to_value(&key)?).await?;
+        if value.is_undefined() {
+            return Ok(false);
+        }
+        let value: T = serde_wasm_bindgen::from_value(value)?;
+        Self::put_with_id(store, &value.set_id(Some(key))).await?;
+        Ok(true)
+    }
+
+    async fn put_with_id<T>(store: &Store, value: &T) -> Result<(), StorageError>
+    where
+        T: Serialize + HasId<T>,
+    {
+        let key = serde_wasm_bindgen::to_value(&value.id())?;
+        store.put(&serde_wasm_bindgen::to_value(value)?, Some(&key)).await?;
+        Ok(())
+    }
+}
+
+fn find_wrong_ids<T>(records: &[(u32, T)]) -> Vec<WrongId>
+where
+    T: ObjStoreName + HasId<T>,
+{
+    records
+        .iter()
+        .filter(|(key, value)| value.id() != Some(*key))
+        .map(|(key, value)| WrongId {
+            store: T::OBJ_STORE_NAME,
+            key: *key,
+            id: value.id(),
+        })
+        .collect()
+}
+
+fn normalize(folder: &str) -> String {
+    folder.split_whitespace().collect::<Vec<&str>>().join(" ").to_lowercase()
+}
@@ -18,20 +18,23 @@ use crate::storage::{ObjStoreName, Storage};
 use crate::storage::storage_error::StorageError;
 
 impl Storage {
-
-    // FIXME delete words from folder with the folder
-
-    pub(crate) async fn delete_by_id<T>(&self, id: u32) -> Result<(), StorageError>
+    pub(super) async fn delete_by_id<T>(&self, id: u32) -> Result<(), StorageError>
         where T: ObjStoreName
     {
         let key = serde_wasm_bindgen::to_value(&id)?;
 
         let tc = self.get_transaction(T::OBJ_STORE_NAME)?;
 
-        let result = tc.store.delete(&key).await?;
+        let old_value = tc.store.get(&key).await?;
+
+        tc.store.delete(&key).await?;
+
+        if !old_value.is_undefined() {
+            Self::count_word_change::<()>(&tc.transaction, T::OBJ_STORE_NAME, &[old_value], &[]).await?;
+        }
 
         tc.transaction.commit().await?;
 
-        Ok(result)
+        Ok(())
     }
 }
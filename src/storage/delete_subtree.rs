@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use rexie::TransactionMode;
+
+use crate::model::{folder_subtree, Folder};
+use crate::storage::{INDEX_FOLDER, OBJ_STORE_FOLDERS, OBJ_STORE_SETTINGS, OBJ_STORE_WORDS, Storage, StorageError};
+use crate::storage::read_record::primary_key;
+
+impl Storage {
+    /// Deletes the folder, its subfolders and their words in one transaction,
+    /// so a failed deletion leaves no half of the tree behind
+    pub(super) async fn delete_subtree(&self, id: u32) -> Result<(), StorageError> {
+        let transaction = self.rexie.transaction(
+            &[OBJ_STORE_FOLDERS, OBJ_STORE_WORDS, OBJ_STORE_SETTINGS],
+            TransactionMode::ReadWrite,
+        )?;
+        let folders_store = transaction.store(OBJ_STORE_FOLDERS)?;
+        let words_store = transaction.store(OBJ_STORE_WORDS)?;
+
+        let records = folders_store.get_all(None, None, None, None).await?;
+        let folders: Vec<Folder> = Self::read_records(OBJ_STORE_FOLDERS, records);
+        let subtree = folder_subtree(&folders, id);
+
+        let folder_index = words_store.index(INDEX_FOLDER)?;
+        let mut removed = vec![];
+        for folder in &subtree {
+            let key_range = Self::key_range_only(folder.folder.clone())?;
+            for (_, value) in folder_index.get_all(Some(&key_range), None, None, None).await? {
+                // words without an id are left for check_database()
+                let key = primary_key(&value);
+                if !key.is_undefined() {
+                    words_store.delete(&key).await?;
+                    removed.push(value);
+                }
+            }
+        }
+
+        for folder in subtree.iter().rev() {
+            if let Some(folder_id) = folder.id {
+                folders_store.delete(&serde_wasm_bindgen::to_value(&folder_id)?).await?;
+            }
+        }
+
+        Self::count_word_change::<()>(&transaction, OBJ_STORE_WORDS, &removed, &[]).await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
@@ -14,36 +14,21 @@
  * limitations under the License.
  */
 
-use crate::model::{Data, Folder, Word};
-use crate::storage::{HasId, IMPORT_EXPORT_DATA_VERSION, ObjStoreName, Storage, StorageError};
+use crate::storage::{HasId, ObjStoreName, Storage, StorageError};
 
 impl Storage {
-    pub(crate) async fn export_data(&self) -> Result<String, StorageError> {
-        let folders: Vec<Folder> = self.export_store().await?;
-        let words: Vec<Word> = self.export_store().await?;
-        let data = Data {
-            version: IMPORT_EXPORT_DATA_VERSION,
-            folders,
-            words,
-        };
-        Ok(serde_json::to_string(&data)?)
-    }
-
-    async fn export_store<T>(&self) -> Result<Vec<T>, StorageError>
+    pub(super) async fn export_store<T>(&self) -> Result<Vec<T>, StorageError>
     where
         T: serde::de::DeserializeOwned + ObjStoreName + HasId<T>,
     {
-        let result: Vec<T> = self
-            .get_store(T::OBJ_STORE_NAME)?
+        self.get_store(T::OBJ_STORE_NAME)?
             .get_all(None, None, None, None)
             .await?
             .into_iter()
-            .map(|value| {
-                serde_wasm_bindgen::from_value::<T>(value.1)
-                    .unwrap()
-                    .set_id(None)
+            .map(|(key, value)| {
+                Self::read_record::<T>(T::OBJ_STORE_NAME, &key, value)
+                    .map(|value| value.set_id(None))
             })
-            .collect();
-        Ok(result)
+            .collect()
     }
 }
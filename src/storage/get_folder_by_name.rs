@@ -14,24 +14,25 @@
  * limitations under the License.
  */
 
-use crate::model::Data;
-use crate::storage::{
-    IMPORT_EXPORT_DATA_VERSION, INVALID_VERSION_ERROR, Storage, StorageError,
-};
+use wasm_bindgen::JsValue;
 
-impl Storage {
-    pub(crate) async fn import_data(&self, json: String) -> Result<Data, StorageError> {
-        let data: Data = serde_json::from_str(json.as_str())?;
-        // debug!("import: data: {:?}", &data);
+use crate::model::Folder;
+use crate::storage::{INDEX_FOLDER, OBJ_STORE_FOLDERS, Storage, StorageError};
 
-        if data.version != IMPORT_EXPORT_DATA_VERSION {
-            return Err(StorageError::ImportError(INVALID_VERSION_ERROR.to_string()));
+impl Storage {
+    pub(crate) async fn get_folder_by_name(
+        &self,
+        folder: &str,
+    ) -> Result<Option<Folder>, StorageError> {
+        let js_value = self
+            .get_store(OBJ_STORE_FOLDERS)?
+            .index(INDEX_FOLDER)?
+            .get(&JsValue::from_str(folder))
+            .await?;
+        if js_value.is_undefined() {
+            return Ok(None);
         }
 
-        self.import(&data.folders).await?;
-
-        self.import(&data.words).await?;
-
-        Ok(data)
+        Ok(Some(serde_wasm_bindgen::from_value(js_value)?))
     }
 }
@@ -0,0 +1,153 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use rexie::Direction::{Prev, PrevUnique};
+
+use crate::model::{
+    folder_is_archived, folder_parent, folder_subtree, pinned_first, sort_direction_map, Folder, FolderSort, FolderStats,
+    FolderStatsAndCount, Word,
+};
+use crate::storage::{INDEX_FOLDER, OBJ_STORE_FOLDERS, OBJ_STORE_WORDS, Storage, StorageError};
+
+impl Storage {
+    /// All folders are loaded since IndexedDB can't sort by name or word count,
+    /// the words are counted only for the shown page when it's possible.
+    /// Without a filter only the children of `parent_id` are returned, the root folders for `None`,
+    /// with a filter the matching folders are searched in the whole tree.
+    /// Pinned folders go first
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn get_folder_stats(
+        &self,
+        filter: String,
+        parent_id: Option<u32>,
+        sort: FolderSort,
+        include_archived: bool,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        direction: String,
+    ) -> Result<FolderStatsAndCount, StorageError> {
+        let descending = matches!(
+            sort_direction_map.get(direction.as_str()).unwrap_or(&Prev),
+            Prev | PrevUnique
+        );
+
+        let records = self
+            .get_store(OBJ_STORE_FOLDERS)?
+            .get_all(None, None, None, None)
+            .await?;
+        let all_folders: Vec<Folder> = Self::read_records(OBJ_STORE_FOLDERS, records);
+        let mut folders: Vec<Folder> = all_folders
+            .iter()
+            .filter(|folder| match filter.trim().is_empty() {
+                true => folder_parent(&all_folders, folder) == parent_id,
+                false => folder.matches(&filter),
+            })
+            .filter(|folder| include_archived || !folder_is_archived(&all_folders, folder))
+            .cloned()
+            .collect();
+
+        let count = folders.len() as u32;
+        let page = |folders: Vec<FolderStats>| -> Vec<FolderStats> {
+            folders
+                .into_iter()
+                .skip(offset.unwrap_or(0) as usize)
+                .take(limit.unwrap_or(count) as usize)
+                .collect()
+        };
+
+        let folders = match sort {
+            FolderSort::Name | FolderSort::Created => {
+                match sort {
+                    FolderSort::Name => folders.sort_by_key(|folder| folder.folder.to_lowercase()),
+                    _ => folders.sort_by_key(|folder| (folder.datetime, folder.id)),
+                }
+                if descending {
+                    folders.reverse();
+                }
+                pinned_first(&mut folders);
+                let folders = page(folders.into_iter().map(FolderStats::from).collect());
+                self.add_folder_stats(folders, &all_folders).await?
+            }
+            FolderSort::Modified | FolderSort::WordCount => {
+                let folders = folders.into_iter().map(FolderStats::from).collect();
+                let mut folders = self.add_folder_stats(folders, &all_folders).await?;
+                match sort {
+                    FolderSort::Modified => folders.sort_by_key(|stats| stats.modified),
+                    _ => folders.sort_by_key(|stats| stats.subtree_word_count),
+                }
+                if descending {
+                    folders.reverse();
+                }
+                folders.sort_by_key(|stats| !stats.folder.pinned);
+                page(folders)
+            }
+        };
+
+        Ok(FolderStatsAndCount { folders, count })
+    }
+
+    async fn add_folder_stats(
+        &self,
+        folders: Vec<FolderStats>,
+        all_folders: &[Folder],
+    ) -> Result<Vec<FolderStats>, StorageError> {
+        let store_index = self.store_index(INDEX_FOLDER)?;
+
+        let mut result = Vec::with_capacity(folders.len());
+        for mut stats in folders {
+            let key_range = Self::key_range_only(stats.folder.folder.clone())?;
+
+            stats.word_count = store_index.count(Some(&key_range)).await?;
+
+            stats.subtree_word_count = stats.word_count;
+            if let Some(id) = stats.folder.id {
+                for subfolder in folder_subtree(all_folders, id).iter().skip(1) {
+                    let key_range = Self::key_range_only(subfolder.folder.clone())?;
+                    stats.subtree_word_count += store_index.count(Some(&key_range)).await?;
+                }
+                stats.subfolder_count = all_folders
+                    .iter()
+                    .filter(|folder| folder_parent(all_folders, folder) == Some(id))
+                    .count() as u32;
+            }
+
+            // the words of a folder are ordered by their keys, so the last one was added last
+            let last_words = store_index
+                .get_all(Some(&key_range), Some(1), None, Some(Prev))
+                .await?;
+            stats.modified = Self::read_index_records::<Word>(OBJ_STORE_WORDS, last_words)
+                .iter()
+                .map(|word| word.datetime)
+                .fold(stats.folder.datetime, i64::max);
+
+            result.push(stats);
+        }
+
+        Ok(result)
+    }
+}
+
+impl From<Folder> for FolderStats {
+    fn from(folder: Folder) -> Self {
+        FolderStats {
+            modified: folder.datetime,
+            folder,
+            word_count: 0,
+            subtree_word_count: 0,
+            subfolder_count: 0,
+        }
+    }
+}
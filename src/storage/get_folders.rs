@@ -16,33 +16,42 @@
 
 use rexie::Direction::Prev;
 
-use crate::model::{Folder, FoldersAndCount, sort_direction_map};
+use crate::model::{folder_is_archived, pinned_first, Folder, FoldersAndCount, sort_direction_map};
 use crate::storage::{OBJ_STORE_FOLDERS, Storage, StorageError};
 
 impl Storage {
-    pub(crate) async fn get_folders(
+    /// Pinned folders go first, so the folders are paged in memory
+    pub(super) async fn get_folders(
         &self,
         limit: Option<u32>,
         offset: Option<u32>,
         direction: String,
+        include_archived: bool,
     ) -> Result<FoldersAndCount, StorageError> {
         let direction = sort_direction_map
             .get(direction.as_str())
             .unwrap_or(&Prev)
             .to_owned();
 
-        let store = self.get_store(OBJ_STORE_FOLDERS)?;
+        let records = self
+            .get_store(OBJ_STORE_FOLDERS)?
+            .get_all(None, None, None, Some(direction))
+            .await?;
+        let all_folders: Vec<Folder> = Self::read_records(OBJ_STORE_FOLDERS, records);
 
-        let folders: Vec<Folder> = store
-            .get_all(None, limit, offset, Some(direction))
-            .await?
-            .into_iter()
-            .map(|value| {
-                serde_wasm_bindgen::from_value(value.1).unwrap()
-            })
+        let mut folders: Vec<Folder> = all_folders
+            .iter()
+            .filter(|folder| include_archived || !folder_is_archived(&all_folders, folder))
+            .cloned()
             .collect();
+        pinned_first(&mut folders);
 
-        let count = store.count(None).await?;
+        let count = folders.len() as u32;
+        let folders = folders
+            .into_iter()
+            .skip(offset.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(count) as usize)
+            .collect();
 
         Ok(FoldersAndCount { folders, count })
     }
@@ -0,0 +1,32 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::model::SmartFolder;
+use crate::storage::{OBJ_STORE_SMART_FOLDERS, Storage, StorageError};
+
+impl Storage {
+    /// None if the smart folder doesn't exist
+    pub(crate) async fn get_smart_folder_by_id(&self, id: u32) -> Result<Option<SmartFolder>, StorageError> {
+        let key = serde_wasm_bindgen::to_value(&id)?;
+
+        let js_result = self.get_store(OBJ_STORE_SMART_FOLDERS)?.get(&key).await?;
+        if js_result.is_undefined() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::read_record(OBJ_STORE_SMART_FOLDERS, &key, js_result)?))
+    }
+}
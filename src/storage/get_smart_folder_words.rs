@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use rexie::Direction::Prev;
+
+use crate::model::{sort_direction_map, Word, WordsAndCount};
+use crate::storage::{OBJ_STORE_WORDS, Storage, StorageError};
+
+impl Storage {
+    /// Evaluates the conditions of the smart folder over the whole words store,
+    /// no words are returned if the smart folder doesn't exist
+    pub(crate) async fn get_smart_folder_words(
+        &self,
+        id: u32,
+        now: i64,
+        filter: String,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        direction: String,
+    ) -> Result<WordsAndCount, StorageError> {
+        let Some(smart_folder) = self.get_smart_folder_by_id(id).await? else {
+            return Ok(WordsAndCount { words: vec![], count: 0 });
+        };
+
+        let direction = sort_direction_map
+            .get(direction.as_str())
+            .unwrap_or(&Prev)
+            .to_owned();
+
+        let records = self
+            .get_store(OBJ_STORE_WORDS)?
+            .get_all(None, None, None, Some(direction))
+            .await?;
+        let words: Vec<Word> = Self::read_records::<Word>(OBJ_STORE_WORDS, records)
+            .into_iter()
+            .filter(|word| smart_folder.matches(word, now) && word.matches(&filter))
+            .collect();
+
+        let count = words.len() as u32;
+        let words = words
+            .into_iter()
+            .skip(offset.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(count) as usize)
+            .collect();
+
+        Ok(WordsAndCount { words, count })
+    }
+}
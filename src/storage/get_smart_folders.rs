@@ -0,0 +1,47 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::model::{SmartFolder, SmartFolderStats, Word};
+use crate::storage::{OBJ_STORE_SMART_FOLDERS, OBJ_STORE_WORDS, Storage, StorageError};
+
+impl Storage {
+    /// The words are loaded once and matched against all smart folders
+    pub(crate) async fn get_smart_folders(&self, now: i64) -> Result<Vec<SmartFolderStats>, StorageError> {
+        let records = self
+            .get_store(OBJ_STORE_SMART_FOLDERS)?
+            .get_all(None, None, None, None)
+            .await?;
+        let smart_folders: Vec<SmartFolder> = Self::read_records(OBJ_STORE_SMART_FOLDERS, records);
+
+        if smart_folders.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let records = self
+            .get_store(OBJ_STORE_WORDS)?
+            .get_all(None, None, None, None)
+            .await?;
+        let words: Vec<Word> = Self::read_records(OBJ_STORE_WORDS, records);
+
+        Ok(smart_folders
+            .into_iter()
+            .map(|smart_folder| SmartFolderStats {
+                word_count: words.iter().filter(|word| smart_folder.matches(word, now)).count() as u32,
+                smart_folder,
+            })
+            .collect())
+    }
+}
@@ -16,7 +16,7 @@
 
 use rexie::{Store, Transaction, TransactionMode};
 
-use crate::storage::Storage;
+use crate::storage::{OBJ_STORE_SETTINGS, OBJ_STORE_WORDS, Storage};
 use crate::storage::storage_error::StorageError;
 
 pub(crate) struct TransactionContext {
@@ -25,13 +25,18 @@ pub(crate) struct TransactionContext {
 }
 
 impl Storage {
+    /// The transaction of the words also writes the settings store, where the statistics of the words are kept
     pub(crate) fn get_transaction(
         &self,
         store_name: &'static str,
     ) -> Result<TransactionContext, StorageError> {
+        let store_names = match store_name {
+            OBJ_STORE_WORDS => vec![OBJ_STORE_WORDS, OBJ_STORE_SETTINGS],
+            _ => vec![store_name],
+        };
         let transaction = self
             .rexie
-            .transaction(&[store_name], TransactionMode::ReadWrite)?;
+            .transaction(&store_names, TransactionMode::ReadWrite)?;
         let store = transaction.store(store_name)?;
 
         Ok(TransactionContext { transaction, store })
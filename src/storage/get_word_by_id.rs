@@ -19,13 +19,19 @@ use crate::storage::{OBJ_STORE_WORDS, Storage};
 use crate::storage::storage_error::StorageError;
 
 impl Storage {
-    #[allow(dead_code)] // used in tests
     pub(crate) async fn get_word_by_id(&self, id: u32) -> Result<Word, StorageError> {
         let key = serde_wasm_bindgen::to_value(&id)?;
 
         let js_result = self.get_store(OBJ_STORE_WORDS)?.get(&key).await?;
 
-        let result = serde_wasm_bindgen::from_value(js_result)?;
+        if js_result.is_undefined() {
+            return Err(StorageError::NotFound {
+                store: OBJ_STORE_WORDS.to_string(),
+                key: id.to_string(),
+            });
+        }
+
+        let result = Self::read_record(OBJ_STORE_WORDS, &key, js_result)?;
 
         Ok(result)
     }
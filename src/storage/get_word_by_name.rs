@@ -0,0 +1,35 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::model::Word;
+use crate::storage::{INDEX_FOLDER_WORD, OBJ_STORE_WORDS, Storage, StorageError};
+
+impl Storage {
+    pub(crate) async fn get_word_by_name(
+        &self,
+        folder: &str,
+        word: &str,
+    ) -> Result<Option<Word>, StorageError> {
+        let key = serde_wasm_bindgen::to_value(&[folder, word])?;
+
+        let js_value = self.store_index(INDEX_FOLDER_WORD)?.get(&key).await?;
+        if js_value.is_undefined() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::read_record(OBJ_STORE_WORDS, &key, js_value)?))
+    }
+}
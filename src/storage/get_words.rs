@@ -15,16 +15,17 @@
  */
 
 use rexie::Direction::Prev;
-use rexie::KeyRange;
+use rexie::{Direction, KeyRange, StoreIndex};
 use wasm_bindgen::JsValue;
 
 use crate::model::{sort_direction_map, Word, WordsAndCount};
-use crate::storage::{INDEX_FOLDER, Storage, StorageError};
+use crate::storage::{INDEX_FOLDER, OBJ_STORE_WORDS, Storage, StorageError};
 
 impl Storage {
-    pub(crate) async fn get_words(
+    pub(super) async fn get_words(
         &self,
         folder: String,
+        filter: String,
         limit: Option<u32>,
         offset: Option<u32>,
         direction: String,
@@ -36,23 +37,52 @@ impl Storage {
             .unwrap_or(&Prev)
             .to_owned();
 
-        let store_index = self.store_index(&INDEX_FOLDER)?;
+        let store_index = self.store_index(INDEX_FOLDER)?;
 
-        let words: Vec<Word> = store_index
+        if !filter.trim().is_empty() {
+            return self.filter_words(store_index, key_range, filter, limit, offset, direction).await;
+        }
+
+        let records = store_index
             .get_all(Some(&key_range), limit, offset, Some(direction))
-            .await?
+            .await?;
+        let words: Vec<Word> = Self::read_index_records(OBJ_STORE_WORDS, records);
+
+        let count = store_index.count(Some(&key_range)).await?;
+
+        Ok(WordsAndCount { words, count })
+    }
+
+    /// IndexedDB can't search in text, so all words of the folder are loaded and paged here
+    async fn filter_words(
+        &self,
+        store_index: StoreIndex,
+        key_range: KeyRange,
+        filter: String,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        direction: Direction,
+    ) -> Result<WordsAndCount, StorageError> {
+        let records = store_index
+            .get_all(Some(&key_range), None, None, Some(direction))
+            .await?;
+        let words: Vec<Word> = Self::read_index_records::<Word>(OBJ_STORE_WORDS, records)
             .into_iter()
-            .map(|value| {
-                serde_wasm_bindgen::from_value(value.1).unwrap()
-            })
+            .filter(|word| word.matches(&filter))
             .collect();
 
-        let count = store_index.count(Some(&key_range)).await?;
+        let count = words.len() as u32;
+
+        let words = words
+            .into_iter()
+            .skip(offset.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(count) as usize)
+            .collect();
 
         Ok(WordsAndCount { words, count })
     }
 
-    fn key_range_only(string: String) -> Result<KeyRange, StorageError> {
+    pub(super) fn key_range_only(string: String) -> Result<KeyRange, StorageError> {
         Ok(KeyRange::only(&JsValue::from(&string))?)
     }
 }
@@ -0,0 +1,35 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::model::Word;
+use crate::storage::read_record::primary_key;
+use crate::storage::{INDEX_WORD, OBJ_STORE_WORDS, Storage, StorageError};
+
+impl Storage {
+    pub(crate) async fn get_words_by_headword(&self, word: &str) -> Result<Vec<Word>, StorageError> {
+        let key_range = Self::key_range_only(word.to_string())?;
+
+        let words = self
+            .store_index(INDEX_WORD)?
+            .get_all(Some(&key_range), None, None, None)
+            .await?
+            .into_iter()
+            .map(|(_, value)| Self::read_record(OBJ_STORE_WORDS, &primary_key(&value), value))
+            .collect::<Result<Vec<Word>, _>>()?;
+
+        Ok(words)
+    }
+}
@@ -0,0 +1,337 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use rexie::Direction::{Prev, PrevUnique};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::model::{
+    folder_is_archived, folder_subtree, pinned_first, sort_direction_map, DailyTotal, Folder, FoldersAndCount, Settings,
+    Word, WordsAndCount,
+};
+use crate::stats::{add_to_day, local_day, WordStats};
+use crate::storage::{HasId, ObjStoreName, StorageBackend, StorageError, UniqueKey, OBJ_STORE_FOLDERS, OBJ_STORE_WORDS};
+
+/// Keeps the stores in memory, so the storage logic can be tested without a browser
+#[derive(Debug, Default)]
+pub(crate) struct MemoryStorage {
+    stores: RefCell<HashMap<&'static str, MemoryStore>>,
+    settings: RefCell<Option<Settings>>,
+    daily_totals: RefCell<Option<BTreeMap<i64, DailyTotal>>>,
+    word_stats: RefCell<Option<WordStats>>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MemoryStore {
+    last_id: u32,
+    values: BTreeMap<u32, serde_json::Value>,
+}
+
+impl MemoryStore {
+    fn add_value<T>(&mut self, value: &T) -> Result<u32, StorageError>
+    where
+        T: Serialize + DeserializeOwned + HasId<T> + UniqueKey,
+    {
+        let unique_key = value.unique_key();
+        for existing in self.values::<T>()? {
+            if existing.unique_key() == unique_key {
+                return Err(StorageError::ConstraintError(unique_key.join(", ")));
+            }
+        }
+
+        let id = self.last_id + 1;
+        self.last_id = id;
+        self.values.insert(id, serde_json::to_value(value.set_id(Some(id)))?);
+
+        Ok(id)
+    }
+
+    /// Values in the order of their keys like in IndexedDB
+    fn values<T: DeserializeOwned>(&self) -> Result<Vec<T>, StorageError> {
+        self.values
+            .values()
+            .map(|value| Ok(serde_json::from_value(value.clone())?))
+            .collect()
+    }
+}
+
+impl MemoryStorage {
+    fn values<T>(&self) -> Result<Vec<T>, StorageError>
+    where
+        T: DeserializeOwned + ObjStoreName,
+    {
+        match self.stores.borrow().get(T::OBJ_STORE_NAME) {
+            None => Ok(vec![]),
+            Some(store) => store.values(),
+        }
+    }
+
+    /// Counts the change of the words in the saved statistics like the IndexedDB storage
+    fn count_word_change<T>(&self, store_name: &str, removed: &[serde_json::Value], added: &[T])
+    where
+        T: Serialize,
+    {
+        if store_name != OBJ_STORE_WORDS {
+            return;
+        }
+
+        let mut word_stats = self.word_stats.borrow_mut();
+        let stats = word_stats.take().and_then(|mut stats| {
+            for value in removed {
+                stats.remove(&serde_json::from_value(value.clone()).ok()?);
+            }
+            for value in added {
+                stats.add(&serde_json::to_value(value).and_then(serde_json::from_value).ok()?);
+            }
+            Some(stats)
+        });
+        *word_stats = stats.map(|mut stats| {
+            if stats.needs_most_reviewed() {
+                let words: Vec<Word> = self.values().unwrap_or_default();
+                stats.set_most_reviewed(&words);
+            }
+            stats
+        });
+    }
+
+    fn page<T>(values: Vec<T>, limit: Option<u32>, offset: Option<u32>, direction: String) -> Vec<T> {
+        let direction = sort_direction_map
+            .get(direction.as_str())
+            .unwrap_or(&Prev);
+
+        let mut values = values;
+        if matches!(direction, Prev | PrevUnique) {
+            values.reverse();
+        }
+
+        let count = values.len();
+        values
+            .into_iter()
+            .skip(offset.unwrap_or(0) as usize)
+            .take(limit.map_or(count, |limit| limit as usize))
+            .collect()
+    }
+}
+
+impl StorageBackend for MemoryStorage {
+    async fn add<T>(&self, value: &T) -> Result<u32, StorageError>
+    where
+        T: Serialize + DeserializeOwned + ObjStoreName + HasId<T> + UniqueKey,
+    {
+        let id = self
+            .stores
+            .borrow_mut()
+            .entry(T::OBJ_STORE_NAME)
+            .or_default()
+            .add_value(value)?;
+
+        self.count_word_change(T::OBJ_STORE_NAME, &[], &[value.set_id(Some(id))]);
+
+        Ok(id)
+    }
+
+    /// Nothing is added if any of the values can't be added, like in a transaction
+    async fn import<T>(&self, data: &[T]) -> Result<(), StorageError>
+    where
+        T: Serialize + DeserializeOwned + ObjStoreName + HasId<T> + UniqueKey,
+    {
+        let mut stores = self.stores.borrow_mut();
+        let mut store = stores.get(T::OBJ_STORE_NAME).cloned().unwrap_or_default();
+
+        let mut added = Vec::with_capacity(data.len());
+        for value in data {
+            let id = store.add_value(value)?;
+            added.push(value.set_id(Some(id)));
+        }
+
+        stores.insert(T::OBJ_STORE_NAME, store);
+        drop(stores);
+
+        self.count_word_change(T::OBJ_STORE_NAME, &[], &added);
+
+        Ok(())
+    }
+
+    async fn get_folders(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        direction: String,
+        include_archived: bool,
+    ) -> Result<FoldersAndCount, StorageError> {
+        let all_folders: Vec<Folder> = self.values()?;
+        let folders: Vec<Folder> = all_folders
+            .iter()
+            .filter(|folder| include_archived || !folder_is_archived(&all_folders, folder))
+            .cloned()
+            .collect();
+        let count = folders.len() as u32;
+
+        let mut folders = Self::page(folders, None, None, direction);
+        pinned_first(&mut folders);
+        let folders = folders
+            .into_iter()
+            .skip(offset.unwrap_or(0) as usize)
+            .take(limit.map_or(count as usize, |limit| limit as usize))
+            .collect();
+
+        Ok(FoldersAndCount { folders, count })
+    }
+
+    async fn get_words(
+        &self,
+        folder: String,
+        filter: String,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        direction: String,
+    ) -> Result<WordsAndCount, StorageError> {
+        let words: Vec<Word> = self
+            .values::<Word>()?
+            .into_iter()
+            .filter(|word| word.folder == folder && word.matches(&filter))
+            .collect();
+        let count = words.len() as u32;
+
+        let words = Self::page(words, limit, offset, direction);
+
+        Ok(WordsAndCount { words, count })
+    }
+
+    async fn update<T>(&self, value: &T) -> Result<(), StorageError>
+    where
+        T: Serialize + ObjStoreName + HasId<T>,
+    {
+        let Some(id) = value.id() else {
+            return Err(StorageError::MissingId(T::OBJ_STORE_NAME.to_string()));
+        };
+        let old_value = match self.stores.borrow_mut().get_mut(T::OBJ_STORE_NAME) {
+            Some(store) => store.values.insert(id, serde_json::to_value(value)?),
+            None => return Ok(()),
+        };
+
+        self.count_word_change(T::OBJ_STORE_NAME, &old_value.into_iter().collect::<Vec<_>>(), &[value]);
+
+        Ok(())
+    }
+
+    async fn delete_by_id<T>(&self, id: u32) -> Result<(), StorageError>
+    where
+        T: ObjStoreName,
+    {
+        let old_value = self
+            .stores
+            .borrow_mut()
+            .get_mut(T::OBJ_STORE_NAME)
+            .and_then(|store| store.values.remove(&id));
+
+        if let Some(old_value) = old_value {
+            self.count_word_change::<()>(T::OBJ_STORE_NAME, &[old_value], &[]);
+        }
+
+        Ok(())
+    }
+
+    async fn delete_subtree(&self, id: u32) -> Result<(), StorageError> {
+        let folders: Vec<Folder> = self.values()?;
+        let subtree = folder_subtree(&folders, id);
+        let names: HashSet<&str> = subtree.iter().map(|folder| folder.folder.as_str()).collect();
+        let ids: HashSet<Option<u32>> = subtree.iter().map(|folder| folder.id).collect();
+
+        let mut removed = vec![];
+        let mut stores = self.stores.borrow_mut();
+        if let Some(store) = stores.get_mut(OBJ_STORE_WORDS) {
+            for word in store.values::<Word>()? {
+                if let Some(word_id) = word.id.filter(|_| names.contains(word.folder.as_str())) {
+                    removed.extend(store.values.remove(&word_id));
+                }
+            }
+        }
+        if let Some(store) = stores.get_mut(OBJ_STORE_FOLDERS) {
+            store.values.retain(|folder_id, _| !ids.contains(&Some(*folder_id)));
+        }
+        drop(stores);
+
+        self.count_word_change::<()>(OBJ_STORE_WORDS, &removed, &[]);
+
+        Ok(())
+    }
+
+    async fn load_settings(&self) -> Result<Settings, StorageError> {
+        Ok(self.settings.borrow().clone().unwrap_or_default())
+    }
+
+    async fn save_settings(&self, settings: &Settings) -> Result<(), StorageError> {
+        *self.settings.borrow_mut() = Some(settings.clone());
+        Ok(())
+    }
+
+    async fn load_daily_totals(&self) -> Result<Option<BTreeMap<i64, DailyTotal>>, StorageError> {
+        Ok(self.daily_totals.borrow().clone())
+    }
+
+    async fn add_to_daily_total(
+        &self,
+        offset_seconds: i32,
+        datetime: i64,
+        added: DailyTotal,
+    ) -> Result<BTreeMap<i64, DailyTotal>, StorageError> {
+        let saved = self.daily_totals.borrow().clone();
+        let totals = match saved {
+            None => {
+                let mut stats = WordStats::new(offset_seconds);
+                for word in self.values::<Word>()? {
+                    stats.add(&word);
+                }
+                stats.daily_totals()
+            }
+            Some(mut totals) => {
+                add_to_day(&mut totals, local_day(datetime, offset_seconds), added);
+                totals
+            }
+        };
+        *self.daily_totals.borrow_mut() = Some(totals.clone());
+        Ok(totals)
+    }
+
+    async fn get_word_stats(&self, offset_seconds: i32) -> Result<WordStats, StorageError> {
+        let saved = self.word_stats.borrow().clone();
+        if let Some(stats) = saved.filter(|stats| stats.offset_seconds == offset_seconds) {
+            return Ok(stats);
+        }
+
+        let mut stats = WordStats::new(offset_seconds);
+        for word in self.values::<Word>()? {
+            stats.add(&word);
+        }
+        *self.word_stats.borrow_mut() = Some(stats.clone());
+        Ok(stats)
+    }
+
+    async fn export_store<T>(&self) -> Result<Vec<T>, StorageError>
+    where
+        T: DeserializeOwned + ObjStoreName + HasId<T>,
+    {
+        Ok(self
+            .values::<T>()?
+            .into_iter()
+            .map(|value| value.set_id(None))
+            .collect())
+    }
+}
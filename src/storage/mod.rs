@@ -18,30 +18,62 @@ use std::fmt::Debug;
 
 use rexie::Rexie;
 
-use crate::model::{Folder, Word};
-use crate::storage::storage_error::StorageError;
+use crate::model::{Folder, SmartFolder, Word};
 
 mod add;
+mod backend;
+mod check_database;
+mod check_integrity;
 mod delete_by_id;
+mod delete_subtree;
 mod export_data;
+mod get_folder_by_name;
+mod get_folder_stats;
 mod get_folders;
+mod get_smart_folder_by_id;
+mod get_smart_folder_words;
+mod get_smart_folders;
 mod get_store;
 mod get_transaction;
 mod get_word_by_id;
+mod get_word_by_name;
 mod get_words;
-mod import_data;
+mod get_words_by_headword;
+mod get_words_by_url;
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod memory_storage;
 mod open;
+mod read_record;
+mod settings;
+mod storage_config;
 mod storage_error;
 mod store_index;
 mod tests;
+mod update;
+mod update_canonical_urls;
+mod word_stats;
+
+pub(crate) use backend::StorageBackend;
+pub(crate) use check_database::CorruptRecord;
+pub(crate) use check_integrity::{IntegrityFix, IntegrityReport};
+pub(crate) use storage_config::StorageConfig;
+pub(crate) use storage_error::{storage_error_message, StorageError, StorageErrorKind};
+#[cfg(all(test, not(target_arch = "wasm32")))]
+pub(crate) use memory_storage::MemoryStorage;
 
 const DATABASE_NAME: &str = "dictionary";
-const DATABASE_VERSION: u32 = 1;
+const DATABASE_VERSION: u32 = 6;
 
 const OBJ_STORE_FOLDERS: &str = "folders";
 const OBJ_STORE_WORDS: &str = "words";
+const OBJ_STORE_SETTINGS: &str = "settings";
+const OBJ_STORE_QUARANTINE: &str = "quarantine";
+const OBJ_STORE_SMART_FOLDERS: &str = "smart_folders";
 
 const INDEX_FOLDER: &str = "folder";
+const INDEX_CANONICAL_URL: &str = "canonical_url";
+const INDEX_WORD: &str = "word";
+const INDEX_FOLDER_WORD: &str = "words";
 
 const IMPORT_EXPORT_DATA_VERSION: u32 = 1;
 
@@ -64,11 +96,20 @@ impl ObjStoreName for Word {
     const OBJ_STORE_NAME: &'static str = OBJ_STORE_WORDS;
 }
 
+impl ObjStoreName for SmartFolder {
+    const OBJ_STORE_NAME: &'static str = OBJ_STORE_SMART_FOLDERS;
+}
+
 pub(crate) trait HasId<T> {
+    fn id(&self) -> Option<u32>;
     fn set_id(&self, id: Option<u32>) -> T;
 }
 
 impl HasId<Folder> for Folder {
+    fn id(&self) -> Option<u32> {
+        self.id
+    }
+
     fn set_id(&self, id: Option<u32>) -> Folder {
         let mut folder = self.clone();
         folder.id = id;
@@ -77,9 +118,48 @@ impl HasId<Folder> for Folder {
 }
 
 impl HasId<Word> for Word {
+    fn id(&self) -> Option<u32> {
+        self.id
+    }
+
     fn set_id(&self, id: Option<u32>) -> Word {
         let mut word = self.clone();
         word.id = id;
         word
     }
 }
+
+impl HasId<SmartFolder> for SmartFolder {
+    fn id(&self) -> Option<u32> {
+        self.id
+    }
+
+    fn set_id(&self, id: Option<u32>) -> SmartFolder {
+        let mut smart_folder = self.clone();
+        smart_folder.id = id;
+        smart_folder
+    }
+}
+
+/// Fields of the unique index of the store
+pub(crate) trait UniqueKey {
+    fn unique_key(&self) -> Vec<String>;
+}
+
+impl UniqueKey for Folder {
+    fn unique_key(&self) -> Vec<String> {
+        vec![self.folder.clone()]
+    }
+}
+
+impl UniqueKey for Word {
+    fn unique_key(&self) -> Vec<String> {
+        vec![self.folder.clone(), self.word.clone()]
+    }
+}
+
+impl UniqueKey for SmartFolder {
+    fn unique_key(&self) -> Vec<String> {
+        vec![self.name.clone()]
+    }
+}
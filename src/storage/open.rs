@@ -17,13 +17,15 @@
 use rexie::{Index, ObjectStore, Rexie};
 
 use crate::storage::{
-    DATABASE_NAME, DATABASE_VERSION, OBJ_STORE_FOLDERS, OBJ_STORE_WORDS, Storage,
+    DATABASE_VERSION, INDEX_CANONICAL_URL, INDEX_FOLDER_WORD, INDEX_WORD, OBJ_STORE_FOLDERS,
+    OBJ_STORE_QUARANTINE, OBJ_STORE_SETTINGS, OBJ_STORE_SMART_FOLDERS, OBJ_STORE_WORDS, Storage,
+    StorageConfig,
 };
 use crate::storage::storage_error::StorageError;
 
 impl Storage {
-    pub(crate) async fn open() -> Result<Storage, StorageError> {
-        let rexie = Rexie::builder(DATABASE_NAME)
+    pub(crate) async fn open(config: &StorageConfig) -> Result<Storage, StorageError> {
+        let rexie = Rexie::builder(&config.name)
             .version(DATABASE_VERSION)
             .add_object_store(
                 ObjectStore::new(OBJ_STORE_FOLDERS)
@@ -34,21 +36,32 @@ impl Storage {
                 ObjectStore::new(OBJ_STORE_WORDS)
                     .auto_increment(true)
                     .add_index(Index::new("folder", "folder"))
-                    .add_index(Index::new_array("words", ["folder", "word"]).unique(true)),
+                    .add_index(Index::new_array(INDEX_FOLDER_WORD, ["folder", "word"]).unique(true))
+                    .add_index(Index::new(INDEX_CANONICAL_URL, "canonical_url"))
+                    .add_index(Index::new(INDEX_WORD, "word")),
+            )
+            .add_object_store(ObjectStore::new(OBJ_STORE_SETTINGS))
+            .add_object_store(ObjectStore::new(OBJ_STORE_QUARANTINE).auto_increment(true))
+            .add_object_store(
+                ObjectStore::new(OBJ_STORE_SMART_FOLDERS)
+                    .auto_increment(true)
+                    .add_index(Index::new("name", "name").unique(true)),
             )
             .build()
             .await?;
 
-        Ok(Storage { rexie })
+        let storage = Storage { rexie };
+        storage.update_canonical_urls().await?;
+
+        Ok(storage)
     }
 
-    #[allow(dead_code)]
-    pub(super) fn close_db(self) {
+    pub(crate) fn close_db(self) {
         self.rexie.close();
     }
 
     #[allow(dead_code)]
-    pub(super) async fn delete_db() -> Result<(), rexie::Error> {
-        Rexie::delete(DATABASE_NAME).await
+    pub(super) async fn delete_db(name: &str) -> Result<(), rexie::Error> {
+        Rexie::delete(name).await
     }
 }
@@ -0,0 +1,82 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use log::warn;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use wasm_bindgen::JsValue;
+
+use crate::storage::{Storage, StorageError};
+
+impl Storage {
+    pub(super) fn read_record<T>(store: &str, key: &JsValue, value: JsValue) -> Result<T, StorageError>
+    where
+        T: DeserializeOwned,
+    {
+        serde_wasm_bindgen::from_value(value).map_err(|err| {
+            warn!("read_record: {} {:?}: {}", store, key, err);
+            StorageError::CorruptRecord {
+                store: store.to_string(),
+                key: record_key(key),
+            }
+        })
+    }
+
+    /// A corrupted record mustn't hide the rest of the list,
+    /// it's reported in the log and can be fixed with check_database()
+    pub(super) fn read_records<T>(store: &str, records: Vec<(JsValue, JsValue)>) -> Vec<T>
+    where
+        T: DeserializeOwned,
+    {
+        records
+            .into_iter()
+            .filter_map(|(key, value)| Self::read_record(store, &key, value).ok())
+            .collect()
+    }
+
+    /// Like `read_records`, for the records read through an index
+    pub(super) fn read_index_records<T>(store: &str, records: Vec<(JsValue, JsValue)>) -> Vec<T>
+    where
+        T: DeserializeOwned,
+    {
+        let records = records
+            .into_iter()
+            .map(|(_, value)| (primary_key(&value), value))
+            .collect();
+        Self::read_records(store, records)
+    }
+}
+
+#[derive(Deserialize)]
+struct RecordId {
+    id: Option<u32>,
+}
+
+/// An index cursor returns the key of the index, the primary key of a record is its id.
+/// Undefined if even the id can't be read
+pub(super) fn primary_key(value: &JsValue) -> JsValue {
+    serde_wasm_bindgen::from_value::<RecordId>(value.clone())
+        .ok()
+        .and_then(|record| record.id)
+        .map_or(JsValue::UNDEFINED, JsValue::from)
+}
+
+pub(super) fn record_key(key: &JsValue) -> String {
+    match key.as_f64() {
+        Some(key) => key.to_string(),
+        None => format!("{:?}", key),
+    }
+}
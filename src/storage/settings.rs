@@ -0,0 +1,129 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::collections::BTreeMap;
+
+use rexie::TransactionMode;
+
+use crate::model::{DailyTotal, Settings, Word};
+use crate::stats::{add_to_day, local_day, WordStats};
+use crate::storage::{OBJ_STORE_SETTINGS, OBJ_STORE_WORDS, Storage, StorageError};
+
+const SETTINGS: &str = "settings";
+const DAILY_TOTALS: &str = "daily_totals";
+
+// separate keys used before the settings were stored as one record
+const LEGACY_QUICK_ADD_FOLDER: &str = "quick_add_folder";
+const LEGACY_LOOKUP_AFTER_SAVE: &str = "lookup_after_save";
+
+impl Storage {
+    /// Settings that were never saved are collected from the legacy keys and have version 0
+    pub(super) async fn load_settings(&self) -> Result<Settings, StorageError> {
+        if let Some(settings) = self.get_setting::<Settings>(SETTINGS).await? {
+            return Ok(settings.migrate());
+        }
+
+        Ok(Settings {
+            version: 0,
+            quick_add_folder: self
+                .get_setting::<String>(LEGACY_QUICK_ADD_FOLDER)
+                .await?
+                .unwrap_or_default(),
+            lookup_after_save: self
+                .get_setting::<bool>(LEGACY_LOOKUP_AFTER_SAVE)
+                .await?
+                .unwrap_or_default(),
+            ..Settings::default()
+        })
+    }
+
+    pub(super) async fn save_settings(&self, settings: &Settings) -> Result<(), StorageError> {
+        self.put_setting(SETTINGS, settings).await
+    }
+
+    pub(super) async fn load_daily_totals(&self) -> Result<Option<BTreeMap<i64, DailyTotal>>, StorageError> {
+        self.get_setting(DAILY_TOTALS).await
+    }
+
+    pub(super) async fn add_to_daily_total(
+        &self,
+        offset_seconds: i32,
+        datetime: i64,
+        added: DailyTotal,
+    ) -> Result<BTreeMap<i64, DailyTotal>, StorageError> {
+        let transaction = self
+            .rexie
+            .transaction(&[OBJ_STORE_SETTINGS, OBJ_STORE_WORDS], TransactionMode::ReadWrite)?;
+        let store = transaction.store(OBJ_STORE_SETTINGS)?;
+        let key = serde_wasm_bindgen::to_value(DAILY_TOTALS)?;
+
+        let js_value = store.get(&key).await?;
+        let totals = match js_value.is_undefined() {
+            true => {
+                let records = transaction
+                    .store(OBJ_STORE_WORDS)?
+                    .get_all(None, None, None, None)
+                    .await?;
+                let mut stats = WordStats::new(offset_seconds);
+                for word in Self::read_records::<Word>(OBJ_STORE_WORDS, records) {
+                    stats.add(&word);
+                }
+                stats.daily_totals()
+            }
+            false => {
+                let mut totals = serde_wasm_bindgen::from_value(js_value)?;
+                add_to_day(&mut totals, local_day(datetime, offset_seconds), added);
+                totals
+            }
+        };
+
+        store.put(&serde_wasm_bindgen::to_value(&totals)?, Some(&key)).await?;
+
+        transaction.commit().await?;
+
+        Ok(totals)
+    }
+
+    pub(super) async fn get_setting<T>(&self, name: &str) -> Result<Option<T>, StorageError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let key = serde_wasm_bindgen::to_value(name)?;
+
+        let js_value = self.get_store(OBJ_STORE_SETTINGS)?.get(&key).await?;
+        if js_value.is_undefined() {
+            return Ok(None);
+        }
+
+        Ok(Some(serde_wasm_bindgen::from_value(js_value)?))
+    }
+
+    pub(super) async fn put_setting<T>(&self, name: &str, value: &T) -> Result<(), StorageError>
+    where
+        T: serde::Serialize,
+    {
+        let key = serde_wasm_bindgen::to_value(name)?;
+        let js_value = serde_wasm_bindgen::to_value(value)?;
+
+        let tc = self.get_transaction(OBJ_STORE_SETTINGS)?;
+
+        tc.store.put(&js_value, Some(&key)).await?;
+
+        tc.transaction.commit().await?;
+
+        Ok(())
+    }
+}
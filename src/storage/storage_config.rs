@@ -0,0 +1,36 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::storage::DATABASE_NAME;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct StorageConfig {
+    pub(crate) name: String,
+}
+
+impl StorageConfig {
+    pub(crate) fn new(name: &str) -> StorageConfig {
+        StorageConfig {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        StorageConfig::new(DATABASE_NAME)
+    }
+}
@@ -14,30 +14,108 @@
  * limitations under the License.
  */
 
+use log::warn;
 use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::DomException;
 
+use crate::i18n::{tr, Locale, Msg};
+
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Error, PartialEq, Clone)]
 pub enum StorageError {
     #[error("rexie::Error {0}")]
-    RexieError(#[from] rexie::Error),
+    RexieError(rexie::Error),
     #[error("serde_wasm_bindgen::Error {0}")]
     SerdeWasmBindgenError(String),
     #[error("serde_json::Error {0}")]
     SerdeError(String),
     #[error("ImportError {0}")]
     ImportError(String),
+    #[error("ConstraintError {0}")]
+    ConstraintError(String),
+    #[error("CorruptRecord {key} in {store}")]
+    CorruptRecord { store: String, key: String },
+    #[error("MissingId in {0}")]
+    MissingId(String),
+    #[error("NotFound {key} in {store}")]
+    NotFound { store: String, key: String },
+}
+
+/// Errors are shown to the user by their kind, the details only go to the log
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StorageErrorKind {
+    ConstraintViolation,
+    QuotaExceeded,
+    VersionMismatch,
+    CorruptData,
+    NotFound,
+    Unknown,
+}
+
+impl StorageError {
+    pub(crate) fn kind(&self) -> StorageErrorKind {
+        match self {
+            StorageError::ConstraintError(_) => StorageErrorKind::ConstraintViolation,
+            StorageError::ImportError(_) => StorageErrorKind::VersionMismatch,
+            StorageError::SerdeWasmBindgenError(_)
+            | StorageError::SerdeError(_)
+            | StorageError::CorruptRecord { .. } => StorageErrorKind::CorruptData,
+            StorageError::NotFound { .. } => StorageErrorKind::NotFound,
+            StorageError::MissingId(_) => StorageErrorKind::Unknown,
+            StorageError::RexieError(error) => match dom_exception_name(error).as_deref() {
+                Some("ConstraintError") => StorageErrorKind::ConstraintViolation,
+                Some("QuotaExceededError") => StorageErrorKind::QuotaExceeded,
+                Some("VersionError") => StorageErrorKind::VersionMismatch,
+                Some("DataError") | Some("DataCloneError") => StorageErrorKind::CorruptData,
+                _ => StorageErrorKind::Unknown,
+            },
+        }
+    }
+}
+
+impl StorageErrorKind {
+    pub(crate) fn msg(&self) -> Msg {
+        match self {
+            StorageErrorKind::ConstraintViolation => Msg::ErrorConstraintViolation,
+            StorageErrorKind::QuotaExceeded => Msg::ErrorQuotaExceeded,
+            StorageErrorKind::VersionMismatch => Msg::ErrorVersionMismatch,
+            StorageErrorKind::CorruptData => Msg::ErrorCorruptData,
+            StorageErrorKind::NotFound => Msg::ErrorNotFound,
+            StorageErrorKind::Unknown => Msg::ErrorUnknown,
+        }
+    }
+}
+
+/// The message for the user, the details were logged when the error was created
+pub(crate) fn storage_error_message(locale: Locale, err: &StorageError) -> &'static str {
+    tr(locale, err.kind().msg())
+}
+
+fn dom_exception_name(error: &rexie::Error) -> Option<String> {
+    let value: JsValue = error.clone().into();
+    value
+        .dyn_ref::<DomException>()
+        .map(|exception| exception.name())
+}
+
+impl From<rexie::Error> for StorageError {
+    fn from(value: rexie::Error) -> Self {
+        warn!("rexie::Error {}", value);
+        StorageError::RexieError(value)
+    }
 }
 
 impl From<serde_wasm_bindgen::Error> for StorageError {
     fn from(value: serde_wasm_bindgen::Error) -> Self {
-        print!("serde_wasm_bindgen::Error {}", value);
+        warn!("serde_wasm_bindgen::Error {}", value);
         StorageError::SerdeWasmBindgenError(value.to_string())
     }
 }
 
 impl From<serde_json::Error> for StorageError {
     fn from(value: serde_json::Error) -> Self {
-        print!("serde_json::Error {}", value);
+        warn!("serde_json::Error {}", value);
         StorageError::SerdeError(value.to_string())
     }
 }
@@ -19,29 +19,29 @@ mod tests {
     use wasm_bindgen_test::wasm_bindgen_test;
 
     use crate::model::Folder;
-    use crate::storage::Storage;
+    use crate::storage::tests::TestStorage;
     use crate::model::Word;
     use crate::tests::test_init;
 
     #[wasm_bindgen_test(async)]
     async fn add_folders_and_words_test() {
         test_init("add_folders_and_words_test");
-        let storage = Storage::open().await.unwrap();
+        let storage = TestStorage::open("add_folders_and_words_test").await;
 
         let folder_one_name = "folder-1-5".to_string();
         let folder_two_name = "folder-2-5".to_string();
 
         let err = storage
-            .add::<Folder>(&Folder::new(&folder_one_name, &"folder-note-1".to_string()))
+            .add::<Folder>(&Folder::new(&folder_one_name, "folder-note-1"))
             .await;
         // debug!("err: {:?}", err);
-        assert!(matches!(err, Ok(_)));
+        assert!(err.is_ok());
 
         let err = storage
-            .add::<Folder>(&Folder::new(&folder_two_name, &"folder-note-2".to_string()))
+            .add::<Folder>(&Folder::new(&folder_two_name, "folder-note-2"))
             .await;
         // debug!("err: {:?}", err);
-        assert!(matches!(err, Ok(_)));
+        assert!(err.is_ok());
 
         let word_one_name = "word-1-5".to_string();
         let word_two_name = "word-2-5".to_string();
@@ -51,31 +51,31 @@ mod tests {
         let word_one = Word::new(
             &folder_one_name,
             &word_one_name,
-            &"noun".to_string(),
-            &"url-1".to_string(),
-            &"note-1".to_string(),
+            "noun",
+            "url-1",
+            "note-1",
         );
         let word_two = Word::new(
             &folder_two_name,
             &word_two_name,
-            &"verb".to_string(),
-            &"url-2".to_string(),
-            &"note-2".to_string(),
+            "verb",
+            "url-2",
+            "note-2",
         );
 
         let word_three = Word::new(
             &folder_one_name,
             &word_three_name,
-            &"noun".to_string(),
-            &"url-3".to_string(),
-            &"note-3".to_string(),
+            "noun",
+            "url-3",
+            "note-3",
         );
         let word_four = Word::new(
             &folder_two_name,
             &word_four_name,
-            &"verb".to_string(),
-            &"url-4".to_string(),
-            &"note-4".to_string(),
+            "verb",
+            "url-4",
+            "note-4",
         );
 
         let _word_one_id = storage.add::<Word>(&word_one).await.unwrap();
@@ -91,7 +91,7 @@ mod tests {
         // debug!("word_four: {:?}", &word_four_id);
 
         let result = storage
-            .get_words(folder_one_name, None, None, "ascending".to_string())
+            .get_words(folder_one_name, String::new(), None, None, "ascending".to_string())
             .await
             .unwrap();
         // debug!("result.len(): {:?}", &result.len());
@@ -20,14 +20,14 @@ mod tests {
 
     use wasm_bindgen_test::wasm_bindgen_test;
 
-    use crate::storage::Storage;
-    use crate::model::Word;
+    use crate::storage::tests::TestStorage;
+    use crate::model::{Review, Word};
     use crate::tests::test_init;
 
     #[wasm_bindgen_test(async)]
     async fn add_word_test() {
         test_init("add_word_test");
-        let storage = Storage::open().await.unwrap();
+        let storage = TestStorage::open("add_word_test").await;
 
         let mut data: HashMap<u32, Word> = HashMap::with_capacity(10);
 
@@ -49,7 +49,11 @@ mod tests {
                 word,
                 word_class,
                 url,
+                canonical_url: String::new(),
                 note,
+                source_context: String::new(),
+                examples: vec![],
+                review: Review::default(),
                 datetime: 0,
             };
             data.insert(id, word);
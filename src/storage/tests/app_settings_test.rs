@@ -0,0 +1,148 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use futures_executor::block_on;
+
+    use crate::i18n::Locale;
+    use crate::model::{Data, DataProtection, KeyAction, ProtectedAction, Settings, Theme, SETTINGS_VERSION};
+    use crate::storage::{MemoryStorage, StorageBackend};
+
+    #[test]
+    fn settings_missing_fields_test() {
+        let settings: Settings = serde_json::from_str(r#"{"version":1,"autoplay":false}"#).unwrap();
+        assert!(!settings.autoplay);
+        assert_eq!(DataProtection::Confirm, settings.protection(ProtectedAction::DeleteFolder));
+        assert!(settings.show_add_word_form);
+        assert_eq!(None, settings.locale);
+
+        let settings: Settings = serde_json::from_str(r#"{"locale":"De","theme":"HighContrast"}"#).unwrap();
+        assert_eq!(Some(Locale::De), settings.locale);
+        assert_eq!(Theme::HighContrast, settings.theme);
+        assert_eq!(Some("highcontrast"), settings.theme.data_theme());
+        assert_eq!(None, Settings::default().theme.data_theme());
+    }
+
+    #[test]
+    fn settings_migrate_test() {
+        let settings = Settings {
+            version: 0,
+            folders_page_length: Some(0),
+            words_page_length: Some(25),
+            selected_folder: " folder ".to_string(),
+            ..Settings::default()
+        }
+        .migrate();
+
+        assert_eq!(SETTINGS_VERSION, settings.version);
+        assert_eq!(None, settings.folders_page_length);
+        assert_eq!(Some(25), settings.words_page_length);
+        assert_eq!("folder", settings.selected_folder);
+    }
+
+    #[test]
+    fn settings_export_and_import_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            let settings = Settings {
+                autoplay: false,
+                quick_add_folder: "inbox".to_string(),
+                ..Settings::default()
+            };
+            storage.save_settings(&settings).await.unwrap();
+
+            let json = storage.export_data().await.unwrap();
+            let data: Data = serde_json::from_str(&json).unwrap();
+            assert_eq!(Some(settings.clone()), data.settings);
+
+            let other_storage = MemoryStorage::default();
+            other_storage.import_data(json).await.unwrap();
+            assert_eq!(settings, other_storage.load_settings().await.unwrap());
+        });
+    }
+
+    #[test]
+    fn key_bindings_test() {
+        let mut settings = Settings::default();
+        assert_eq!(Some(KeyAction::Help), settings.key_action("?"));
+        assert_eq!("e", settings.key_binding(KeyAction::Edit));
+
+        settings.bind_key(KeyAction::Edit, "Ctrl+e").unwrap();
+        assert_eq!(Some(KeyAction::Edit), settings.key_action("Ctrl+e"));
+        assert_eq!(None, settings.key_action("e"));
+
+        assert_eq!(Err(KeyAction::ShowWords), settings.bind_key(KeyAction::Edit, "w"));
+        assert_eq!("Ctrl+e", settings.key_binding(KeyAction::Edit));
+
+        settings.bind_key(KeyAction::Edit, "e").unwrap();
+        assert!(settings.key_bindings.is_empty());
+    }
+
+    #[test]
+    fn key_bindings_serde_test() {
+        let mut settings = Settings::default();
+        settings.bind_key(KeyAction::Delete, "x").unwrap();
+        settings.key_bindings.insert(KeyAction::Open, "Enter".to_string());
+        settings.key_bindings.insert(KeyAction::Search, String::new());
+
+        let json = serde_json::to_string(&settings).unwrap();
+        let settings = serde_json::from_str::<Settings>(&json).unwrap().migrate();
+        assert_eq!(1, settings.key_bindings.len());
+        assert_eq!(Some(KeyAction::Delete), settings.key_action("x"));
+        assert_eq!("/", settings.key_binding(KeyAction::Search));
+    }
+
+    #[test]
+    fn protection_test() {
+        let mut settings = Settings::default();
+        settings.set_protection(ProtectedAction::DeleteFolder, DataProtection::TypeName);
+        assert_eq!(DataProtection::TypeName, settings.protection(ProtectedAction::DeleteFolder));
+        assert_eq!(DataProtection::Confirm, settings.protection(ProtectedAction::DeleteWord));
+
+        let json = serde_json::to_string(&settings).unwrap();
+        assert!(!json.contains("data_protection"));
+        assert_eq!(settings, serde_json::from_str::<Settings>(&json).unwrap().migrate());
+
+        settings.set_protection(ProtectedAction::DeleteFolder, DataProtection::Confirm);
+        assert!(settings.protection.is_empty());
+    }
+
+    #[test]
+    fn protection_migrate_test() {
+        let settings: Settings =
+            serde_json::from_str(r#"{"version":1,"data_protection":"Unprotected"}"#).unwrap();
+        let settings = settings.migrate();
+        assert_eq!(None, settings.data_protection);
+        for action in ProtectedAction::ALL {
+            assert_eq!(DataProtection::Unprotected, settings.protection(action));
+        }
+
+        // the global lock becomes a confirmation
+        let settings: Settings =
+            serde_json::from_str(r#"{"version":1,"data_protection":"Protected"}"#).unwrap();
+        let settings = settings.migrate();
+        assert_eq!(SETTINGS_VERSION, settings.version);
+        assert!(settings.protection.is_empty());
+    }
+
+    #[test]
+    fn key_name_test() {
+        assert_eq!("?", KeyAction::key_name("?", false, false, true));
+        assert_eq!("Shift+PageDown", KeyAction::key_name("PageDown", false, false, true));
+        assert_eq!("Ctrl+Alt+e", KeyAction::key_name("e", true, true, false));
+    }
+}
@@ -0,0 +1,107 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod tests {
+    use gloo_utils::format::JsValueSerdeExt;
+    use serde_json::json;
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::model::{Folder, Word};
+    use crate::storage::tests::TestStorage;
+    use crate::storage::{StorageBackend, StorageError, OBJ_STORE_FOLDERS, OBJ_STORE_WORDS};
+    use crate::tests::test_init;
+
+    async fn add_raw(storage: &TestStorage, store: &'static str, value: serde_json::Value) -> u32 {
+        let tc = storage.get_transaction(store).unwrap();
+        let key = tc.store.add(&JsValue::from_serde(&value).unwrap(), None).await.unwrap();
+        tc.transaction.commit().await.unwrap();
+        key.as_f64().unwrap() as u32
+    }
+
+    #[wasm_bindgen_test(async)]
+    async fn check_database_test() {
+        test_init("check_database_test");
+        let storage = TestStorage::open("check_database_test").await;
+
+        storage.add::<Folder>(&Folder::new("folder", "note")).await.unwrap();
+        storage.add::<Word>(&Word::new("folder", "word-1", "noun", "url", "note")).await.unwrap();
+
+        let repairable = add_raw(&storage, OBJ_STORE_WORDS,
+            json!({"folder": "folder", "word": "word-2", "datetime": "yesterday"})).await;
+        let broken = add_raw(&storage, OBJ_STORE_FOLDERS, json!("folder-2")).await;
+
+        let result = storage
+            .get_words("folder".to_string(), String::new(), None, None, String::new())
+            .await
+            .unwrap();
+        assert_eq!(1, result.words.len());
+
+        let result = storage.export_data().await;
+        assert!(matches!(result, Err(StorageError::CorruptRecord { .. })));
+
+        let records = storage.check_database().await.unwrap();
+        assert_eq!(2, records.len());
+
+        let folder_record = &records[0];
+        assert_eq!(OBJ_STORE_FOLDERS, folder_record.store);
+        assert_eq!(broken.to_string(), folder_record.key());
+        assert_eq!(None, folder_record.repaired);
+
+        let word_record = &records[1];
+        assert_eq!(OBJ_STORE_WORDS, word_record.store);
+        assert_eq!(repairable.to_string(), word_record.key());
+        assert!(word_record.repaired.is_some());
+
+        storage.repair_record(word_record).await.unwrap();
+        storage.quarantine_record(folder_record).await.unwrap();
+
+        assert!(storage.check_database().await.unwrap().is_empty());
+        assert_eq!(1, storage.quarantined_count().await.unwrap());
+
+        let word = storage.get_word_by_id(repairable).await.unwrap();
+        assert_eq!(Some(repairable), word.id);
+        assert_eq!("word-2", word.word);
+
+        let result = storage.get_folders(None, None, String::new(), true).await.unwrap();
+        assert_eq!(1, result.count);
+        assert!(storage.export_data().await.is_ok());
+    }
+
+    #[wasm_bindgen_test(async)]
+    async fn corrupt_record_key_test() {
+        test_init("corrupt_record_key_test");
+        let storage = TestStorage::open("corrupt_record_key_test").await;
+
+        let id = add_raw(&storage, OBJ_STORE_WORDS,
+            json!({"folder": "folder", "word": "word", "datetime": "yesterday"})).await;
+        let tc = storage.get_transaction(OBJ_STORE_WORDS).unwrap();
+        let value = json!({"id": id, "folder": "folder", "word": "word", "datetime": "yesterday"});
+        tc.store
+            .put(&JsValue::from_serde(&value).unwrap(), Some(&JsValue::from(id)))
+            .await
+            .unwrap();
+        tc.transaction.commit().await.unwrap();
+
+        // the key of the index is "word", the error names the record
+        let result = storage.get_words_by_headword("word").await;
+        assert_eq!(
+            Err(StorageError::CorruptRecord { store: OBJ_STORE_WORDS.to_string(), key: id.to_string() }),
+            result
+        );
+    }
+}
@@ -0,0 +1,76 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::model::{Folder, Word};
+    use crate::storage::tests::TestStorage;
+    use crate::storage::{HasId, IntegrityFix, OBJ_STORE_WORDS};
+    use crate::tests::test_init;
+
+    #[wasm_bindgen_test(async)]
+    async fn check_integrity_test() {
+        test_init("check_integrity_test");
+        let storage = TestStorage::open("check_integrity_test").await;
+
+        storage.add::<Folder>(&Folder::new("Folder", "note")).await.unwrap();
+        storage.add::<Word>(&Word::new("Folder", "word-1", "", "", "")).await.unwrap();
+        storage.add::<Word>(&Word::new(" folder", "word-1", "", "", "")).await.unwrap();
+        storage.add::<Word>(&Word::new("folder ", "word-2", "", "", "")).await.unwrap();
+        storage.add::<Word>(&Word::new("deleted", "word-3", "", "", "")).await.unwrap();
+
+        let id = storage.add::<Word>(&Word::new("Folder", "word-4", "", "", "")).await.unwrap();
+        let word = storage.get_word_by_id(id).await.unwrap();
+        let tc = storage.get_transaction(OBJ_STORE_WORDS).unwrap();
+        let key = serde_wasm_bindgen::to_value(&id).unwrap();
+        let value = serde_wasm_bindgen::to_value(&word.set_id(Some(id + 100))).unwrap();
+        tc.store.put(&value, Some(&key)).await.unwrap();
+        tc.transaction.commit().await.unwrap();
+
+        let report = storage.check_integrity().await.unwrap();
+        assert_eq!(1, report.orphan_words.len());
+        assert_eq!("word-3", report.orphan_words[0].word);
+        assert_eq!(vec!["deleted".to_string()], report.missing_folders().into_iter().collect::<Vec<_>>());
+        assert_eq!(2, report.mismatched_folders.len());
+        assert_eq!(1, report.mismatched_folders.iter().filter(|x| x.conflict).count());
+        assert_eq!(1, report.wrong_ids.len());
+        assert_eq!(id, report.wrong_ids[0].key);
+        assert_eq!(Some(id + 100), report.wrong_ids[0].id);
+
+        let fixes = [
+            IntegrityFix::FixIds,
+            IntegrityFix::RenameMismatchedFolders,
+            IntegrityFix::CreateMissingFolders,
+        ];
+        assert_eq!(3, storage.repair(&report, &fixes).await.unwrap());
+
+        let report = storage.check_integrity().await.unwrap();
+        assert!(report.orphan_words.is_empty());
+        assert!(report.wrong_ids.is_empty());
+        assert_eq!(1, report.mismatched_folders.len());
+        assert!(report.mismatched_folders[0].conflict);
+
+        let words = storage
+            .get_words("Folder".to_string(), String::new(), None, None, String::new())
+            .await
+            .unwrap();
+        assert_eq!(3, words.count);
+        assert_eq!(Some(id), storage.get_word_by_id(id).await.unwrap().id);
+        assert_eq!(2, storage.get_folders(None, None, String::new(), true).await.unwrap().count);
+    }
+}
@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use futures_executor::block_on;
+
+    use crate::model::{DailyGoal, DailyTotal, Settings, Word};
+    use crate::stats::{goal_streak, new_review};
+    use crate::storage::{MemoryStorage, StorageBackend};
+
+    const DAY: i64 = 24 * 60 * 60 * 1000;
+    const HOUR: i64 = 60 * 60 * 1000;
+    const TODAY: i64 = 100;
+
+    fn total(words: u32, reviews: u32) -> DailyTotal {
+        DailyTotal { words, reviews }
+    }
+
+    #[test]
+    fn daily_totals_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            let mut word = Word::new("folder", "house", "noun", "url", "");
+            word.datetime = (TODAY - 1) * DAY + HOUR;
+            word.review.count = 2;
+            word.review.datetime = TODAY * DAY + HOUR;
+            let id = storage.add(&word).await.unwrap();
+
+            // the totals are filled from the words once
+            let totals = storage.get_daily_totals(0).await.unwrap();
+            assert_eq!(Some(&total(1, 0)), totals.get(&(TODAY - 1)));
+            assert_eq!(Some(&total(0, 1)), totals.get(&TODAY));
+
+            storage.add_to_daily_total(0, TODAY * DAY + 2 * HOUR, total(1, 0)).await.unwrap();
+            storage.add_to_daily_total(0, TODAY * DAY + 3 * HOUR, total(0, 2)).await.unwrap();
+            // 23:00 UTC is the next day two hours east
+            storage.add_to_daily_total(2 * 60 * 60, TODAY * DAY + 23 * HOUR, total(1, 0)).await.unwrap();
+
+            storage.delete_by_id::<Word>(id).await.unwrap();
+            let totals = storage.get_daily_totals(0).await.unwrap();
+            assert_eq!(Some(&total(1, 0)), totals.get(&(TODAY - 1)));
+            assert_eq!(Some(&total(1, 3)), totals.get(&TODAY));
+            assert_eq!(Some(&total(1, 0)), totals.get(&(TODAY + 1)));
+        });
+    }
+
+    #[test]
+    fn add_to_daily_total_before_totals_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            let mut word = Word::new("folder", "house", "noun", "url", "");
+            word.datetime = TODAY * DAY + HOUR;
+            storage.add(&word).await.unwrap();
+
+            // the word is stored before it's counted, so the filled totals already include it
+            let added = total(1, 0);
+            let totals = storage.add_to_daily_total(0, word.datetime, added).await.unwrap();
+            assert_eq!(Some(&total(1, 0)), totals.get(&TODAY));
+
+            let mut word = Word::new("folder", "tree", "noun", "url", "");
+            word.datetime = TODAY * DAY + 2 * HOUR;
+            storage.add(&word).await.unwrap();
+            storage.add_to_daily_total(0, word.datetime, added).await.unwrap();
+
+            let totals = storage.get_daily_totals(0).await.unwrap();
+            assert_eq!(Some(&total(2, 0)), totals.get(&TODAY));
+            assert_eq!(1, totals.len());
+        });
+    }
+
+    #[test]
+    fn new_review_test() {
+        let mut word = Word::new("folder", "house", "noun", "url", "");
+        word.review.count = 2;
+        word.review.datetime = (TODAY - 1) * DAY + HOUR;
+
+        let reviewed = |word: &Word, datetime: i64| {
+            let mut new_word = word.clone();
+            new_word.review.count += 1;
+            new_word.review.datetime = datetime;
+            new_word
+        };
+
+        // like the totals filled from the words, a word counts once a day
+        let today = reviewed(&word, TODAY * DAY + HOUR);
+        assert_eq!(1, new_review(&word, &today, 0));
+        let again = reviewed(&today, TODAY * DAY + 2 * HOUR);
+        assert_eq!(0, new_review(&today, &again, 0));
+        assert_eq!(0, new_review(&word, &word, 0));
+        // 23:00 UTC is the next day two hours east
+        let evening = reviewed(&today, TODAY * DAY + 23 * HOUR);
+        assert_eq!(1, new_review(&today, &evening, 2 * 60 * 60));
+        assert_eq!(1, new_review(&Word::new("folder", "tree", "noun", "url", ""), &today, 0));
+    }
+
+    #[test]
+    fn goal_streak_test() {
+        let totals = BTreeMap::from([
+            (TODAY - 4, total(5, 0)),
+            (TODAY - 3, total(1, 10)),
+            (TODAY - 2, total(5, 10)),
+            (TODAY - 1, total(6, 12)),
+            (TODAY, total(2, 0)),
+        ]);
+
+        let goal = DailyGoal { words: 5, reviews: 10 };
+        // today isn't over yet, so the streak goes on from yesterday
+        assert_eq!(2, goal_streak(&totals, &goal, TODAY));
+        assert_eq!(0, goal_streak(&totals, &goal, TODAY + 2));
+
+        let goal = DailyGoal { words: 5, reviews: 0 };
+        assert_eq!(2, goal_streak(&totals, &goal, TODAY));
+
+        // without a goal any activity counts
+        assert_eq!(5, goal_streak(&totals, &DailyGoal::default(), TODAY));
+    }
+
+    #[test]
+    fn daily_goal_settings_test() {
+        let settings: Settings = serde_json::from_str(r#"{"daily_goal":{"words":5}}"#).unwrap();
+        assert_eq!(DailyGoal { words: 5, reviews: 0 }, settings.daily_goal);
+        assert!(settings.daily_goal.is_set());
+        assert!(!Settings::default().daily_goal.is_set());
+        assert!(settings.daily_goal.is_met(&total(5, 0)));
+        assert!(!settings.daily_goal.is_met(&total(4, 20)));
+    }
+}
@@ -20,14 +20,15 @@ mod tests {
 
     use wasm_bindgen_test::wasm_bindgen_test;
 
-    use crate::model::Word;
-    use crate::storage::Storage;
+    use crate::model::{Review, Word};
+    use crate::storage::StorageError;
+    use crate::storage::tests::TestStorage;
     use crate::tests::test_init;
 
     #[wasm_bindgen_test(async)]
     async fn delete_word_test() {
         test_init("delete_word_test");
-        let storage = Storage::open().await.unwrap();
+        let storage = TestStorage::open("delete_word_test").await;
 
         let mut data: HashMap<u32, Word> = HashMap::with_capacity(10);
 
@@ -49,7 +50,11 @@ mod tests {
                 word,
                 word_class,
                 url,
+                canonical_url: String::new(),
                 note,
+                source_context: String::new(),
+                examples: vec![],
+                review: Review::default(),
                 datetime: 0,
             };
             data.insert(id, word);
@@ -67,7 +72,10 @@ mod tests {
 
             let result = storage.get_word_by_id(word.id.unwrap()).await;
             // debug!("get_by_id: {:?}", &result);
-            assert!(matches!(result, Err(_)));
+            assert_eq!(
+                Err(StorageError::NotFound { store: "words".to_string(), key: id.to_string() }),
+                result
+            );
         }
     }
 }
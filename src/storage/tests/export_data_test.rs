@@ -19,14 +19,15 @@ mod tests {
     use std::collections::HashMap;
     use wasm_bindgen_test::wasm_bindgen_test;
 
-    use crate::model::{Data, Folder, Word};
-    use crate::storage::{IMPORT_EXPORT_DATA_VERSION, Storage};
+    use crate::model::{Data, Folder, Review, Word};
+    use crate::storage::{IMPORT_EXPORT_DATA_VERSION, StorageBackend};
+    use crate::storage::tests::TestStorage;
     use crate::tests::test_init;
 
     #[wasm_bindgen_test(async)]
     async fn export_data_test() {
         test_init("export_data_test");
-        let storage = Storage::open().await.unwrap();
+        let storage = TestStorage::open("export_data_test").await;
 
         let mut folders: Vec<Folder> = vec![];
         let mut words: Vec<Word> = vec![];
@@ -45,8 +46,10 @@ mod tests {
                 let word_class = format!("word-class-{}", j);
                 let url = format!("url-{}", j);
                 let note = format!("note-{}", j);
+                let examples = vec![format!("example-{}", j)];
                 let id = storage
-                    .add::<Word>(&Word::new(&folder_name, &word, &word_class, &url, &note))
+                    .add::<Word>(&Word::new(&folder_name, &word, &word_class, &url, &note)
+                        .with_examples(&examples.join("\n")))
                     .await
                     .unwrap();
 
@@ -56,7 +59,11 @@ mod tests {
                     word,
                     word_class,
                     url,
+                    canonical_url: String::new(),
                     note,
+                    source_context: String::new(),
+                    examples,
+                    review: Review::default(),
                     datetime: 0,
                 });
             }
@@ -93,6 +100,7 @@ mod tests {
             assert_eq!(row.word, result.word);
             assert_eq!(row.word_class, result.word_class);
             assert_eq!(row.note, result.note);
+            assert_eq!(row.examples, result.examples);
             assert_ne!(row.datetime, result.datetime);
         }
     }
@@ -0,0 +1,200 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use futures_executor::block_on;
+
+    use crate::model::{Data, Folder, Word};
+    use crate::storage::{HasId, MemoryStorage, StorageBackend};
+
+    const ASCENDING: &str = "\u{2191}";
+
+    /// course -> unit -> lesson, course -> other unit, and a separate folder
+    async fn add_tree(storage: &MemoryStorage) -> u32 {
+        let course = storage.add::<Folder>(&Folder::new("course", "")).await.unwrap();
+        let unit = storage
+            .add::<Folder>(&Folder::new("unit", "").with_parent(Some(course)))
+            .await
+            .unwrap();
+        storage
+            .add::<Folder>(&Folder::new("lesson", "").with_parent(Some(unit)))
+            .await
+            .unwrap();
+        storage
+            .add::<Folder>(&Folder::new("other unit", "").with_parent(Some(course)))
+            .await
+            .unwrap();
+        storage.add::<Folder>(&Folder::new("separate", "")).await.unwrap();
+
+        for (folder, word) in [("course", "one"), ("lesson", "two"), ("lesson", "three"), ("separate", "four")] {
+            storage.add::<Word>(&Word::new(folder, word, "", "", "")).await.unwrap();
+        }
+
+        course
+    }
+
+    fn names(folders: &[Folder]) -> Vec<&str> {
+        folders.iter().map(|x| x.folder.as_str()).collect()
+    }
+
+    #[test]
+    fn folder_subtree_and_path_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            let course = add_tree(&storage).await;
+
+            let subtree = storage.get_subtree(course).await.unwrap();
+            assert_eq!(vec!["course", "unit", "other unit", "lesson"], names(&subtree));
+
+            let path = storage.get_folder_path("lesson").await.unwrap();
+            assert_eq!(vec!["course", "unit", "lesson"], names(&path));
+
+            assert!(storage.get_folder_path("missing").await.unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn folder_subtree_with_cycle_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            let first = storage.add::<Folder>(&Folder::new("first", "")).await.unwrap();
+            let second = storage
+                .add::<Folder>(&Folder::new("second", "").with_parent(Some(first)))
+                .await
+                .unwrap();
+            let folders = storage.get_folders(None, None, String::new(), true).await.unwrap().folders;
+            let first_folder = folders.iter().find(|x| x.id == Some(first)).unwrap();
+            storage.update(&first_folder.with_parent(Some(second))).await.unwrap();
+
+            assert_eq!(vec!["first", "second"], names(&storage.get_subtree(first).await.unwrap()));
+            assert_eq!(vec!["first", "second"], names(&storage.get_folder_path("second").await.unwrap()));
+        });
+    }
+
+    #[test]
+    fn delete_subtree_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            let course = add_tree(&storage).await;
+            assert_eq!(4, storage.get_word_stats(0).await.unwrap().word_count);
+
+            storage.delete_subtree(course).await.unwrap();
+            assert_eq!(1, storage.get_word_stats(0).await.unwrap().word_count);
+
+            let folders = storage.get_folders(None, None, String::new(), true).await.unwrap().folders;
+            assert_eq!(vec!["separate"], names(&folders));
+
+            let data: Data = serde_json::from_str(&storage.export_data().await.unwrap()).unwrap();
+            let words: Vec<&str> = data.words.iter().map(|x| x.word.as_str()).collect();
+            assert_eq!(vec!["four"], words);
+        });
+    }
+
+    #[test]
+    fn export_and_import_subtree_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            add_tree(&storage).await;
+            let unit = storage.get_folder_path("unit").await.unwrap()[1].id.unwrap();
+
+            let json = storage.export_subtree(unit).await.unwrap();
+            let data: Data = serde_json::from_str(&json).unwrap();
+            assert_eq!(vec!["unit", "lesson"], names(&data.folders));
+            assert_eq!(2, data.words.len());
+            assert_eq!(Some(&"unit".to_string()), data.folder_parents.get("lesson"));
+            assert!(!data.folder_parents.contains_key("unit"));
+
+            let other_storage = MemoryStorage::default();
+            other_storage.import_data(json).await.unwrap();
+            let path = other_storage.get_folder_path("lesson").await.unwrap();
+            assert_eq!(vec!["unit", "lesson"], names(&path));
+        });
+    }
+
+    #[test]
+    fn export_and_import_tree_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            add_tree(&storage).await;
+
+            let json = storage.export_data().await.unwrap();
+            let data: Data = serde_json::from_str(&json).unwrap();
+            assert!(data.folders.iter().all(|x| x.id.is_none() && x.parent_id.is_none()));
+            assert_eq!(3, data.folder_parents.len());
+
+            let other_storage = MemoryStorage::default();
+            other_storage.import_data(json).await.unwrap();
+            let path = other_storage.get_folder_path("lesson").await.unwrap();
+            assert_eq!(vec!["course", "unit", "lesson"], names(&path));
+        });
+    }
+
+    #[test]
+    fn same_name_under_different_parents_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            let course = storage.add::<Folder>(&Folder::new("course", "")).await.unwrap();
+            let folders = storage.get_all_folders().await.unwrap();
+            let mut lessons = vec![];
+            for unit_name in ["unit 1", "unit 2"] {
+                let unit = Folder::new_subfolder(&folders[0], unit_name, "");
+                let unit = unit.set_id(Some(storage.add::<Folder>(&unit).await.unwrap()));
+                let lesson = Folder::new_subfolder(&unit, "lesson", "");
+                storage.add::<Folder>(&lesson).await.unwrap();
+                storage.add::<Word>(&Word::new(&lesson.folder, unit_name, "", "", "")).await.unwrap();
+                lessons.push(lesson);
+            }
+
+            assert_eq!("course / unit 1 / lesson", lessons[0].folder);
+            assert_eq!("lesson", lessons[0].name());
+            assert_eq!("course", Folder::new("course", "").name());
+
+            let path = storage.get_folder_path(&lessons[1].folder).await.unwrap();
+            let path_names: Vec<&str> = path.iter().map(|x| x.name()).collect();
+            assert_eq!(vec!["course", "unit 2", "lesson"], path_names);
+
+            storage.delete_subtree(path[1].id.unwrap()).await.unwrap();
+            let words = storage.get_folder_words(&lessons[0].folder).await.unwrap();
+            assert_eq!(vec!["unit 1"], words.iter().map(|x| x.word.as_str()).collect::<Vec<_>>());
+            assert_eq!(3, storage.get_subtree(course).await.unwrap().len());
+        });
+    }
+
+    #[test]
+    fn pinned_and_archived_folders_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            add_tree(&storage).await;
+
+            let folders = storage.get_all_folders().await.unwrap();
+            let find = |name: &str| folders.iter().find(|x| x.folder == name).unwrap().clone();
+            storage.update(&Folder { pinned: true, ..find("separate") }).await.unwrap();
+            storage.update(&Folder { archived: true, ..find("unit") }).await.unwrap();
+
+            let result = storage.get_folders(None, None, ASCENDING.to_string(), false).await.unwrap();
+            assert_eq!(3, result.count);
+            assert_eq!(vec!["separate", "course", "other unit"], names(&result.folders));
+
+            let result = storage.get_folders(Some(2), None, ASCENDING.to_string(), true).await.unwrap();
+            assert_eq!(5, result.count);
+            assert_eq!(vec!["separate", "course"], names(&result.folders));
+
+            // the archived folders are still a part of the tree
+            assert_eq!(4, storage.get_subtree(find("course").id.unwrap()).await.unwrap().len());
+        });
+    }
+}
@@ -0,0 +1,90 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::model::{Folder, FolderSort, FolderStats, Word};
+    use crate::storage::tests::TestStorage;
+    use crate::tests::test_init;
+
+    #[wasm_bindgen_test(async)]
+    async fn get_folder_stats_test() {
+        test_init("get_folder_stats_test");
+        let storage = TestStorage::open("get_folder_stats_test").await;
+
+        storage.add::<Folder>(&Folder::new("beta", "second")).await.unwrap();
+        storage.add::<Folder>(&Folder::new("Alpha", "first")).await.unwrap();
+        storage.add::<Folder>(&Folder::new("gamma", "third")).await.unwrap();
+
+        storage.add::<Word>(&Word::new("beta", "one", "", "", "")).await.unwrap();
+        storage.add::<Word>(&Word::new("gamma", "one", "", "", "")).await.unwrap();
+        storage.add::<Word>(&Word::new("gamma", "two", "", "", "")).await.unwrap();
+
+        let names = |folders: Vec<FolderStats>| -> Vec<(String, u32)> {
+            folders.into_iter().map(|stats| (stats.folder.folder, stats.word_count)).collect()
+        };
+
+        let result = storage
+            .get_folder_stats(String::new(), None, FolderSort::Name, false, None, None, "↑".to_string())
+            .await
+            .unwrap();
+        assert_eq!(3, result.count);
+        assert_eq!(
+            vec![("Alpha".to_string(), 0), ("beta".to_string(), 1), ("gamma".to_string(), 2)],
+            names(result.folders)
+        );
+
+        let result = storage
+            .get_folder_stats(String::new(), None, FolderSort::WordCount, false, Some(2), None, "↓".to_string())
+            .await
+            .unwrap();
+        assert_eq!(3, result.count);
+        assert_eq!(
+            vec![("gamma".to_string(), 2), ("beta".to_string(), 1)],
+            names(result.folders)
+        );
+
+        let result = storage
+            .get_folder_stats("FIRST".to_string(), None, FolderSort::Created, false, None, None, "↑".to_string())
+            .await
+            .unwrap();
+        assert_eq!(1, result.count);
+        assert_eq!(vec![("Alpha".to_string(), 0)], names(result.folders));
+
+        let folders = storage.get_folders(None, None, String::new(), true).await.unwrap().folders;
+        let find = |name: &str| folders.iter().find(|x| x.folder == name).unwrap().clone();
+        storage.update(&Folder { pinned: true, ..find("gamma") }).await.unwrap();
+        storage.update(&Folder { archived: true, ..find("beta") }).await.unwrap();
+
+        let result = storage
+            .get_folder_stats(String::new(), None, FolderSort::Name, false, None, None, "↑".to_string())
+            .await
+            .unwrap();
+        assert_eq!(2, result.count);
+        assert_eq!(
+            vec![("gamma".to_string(), 2), ("Alpha".to_string(), 0)],
+            names(result.folders)
+        );
+
+        let result = storage
+            .get_folder_stats(String::new(), None, FolderSort::Name, true, None, None, "↑".to_string())
+            .await
+            .unwrap();
+        assert_eq!(3, result.count);
+    }
+}
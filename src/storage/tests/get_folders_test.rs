@@ -20,15 +20,14 @@ mod tests {
 
     use wasm_bindgen_test::wasm_bindgen_test;
 
-    use crate::storage::Storage;
+    use crate::storage::tests::TestStorage;
     use crate::model::Folder;
     use crate::tests::test_init;
 
-    #[ignore = "should run separately"]
     #[wasm_bindgen_test(async)]
     async fn get_folders_test() {
         test_init("get_folders_test");
-        let storage = Storage::open().await.unwrap();
+        let storage = TestStorage::open("get_folders_test").await;
 
         let mut data: Vec<Folder> = vec![];
         let length = 10;
@@ -42,12 +41,12 @@ mod tests {
         }
 
         let err = storage
-            .add::<Folder>(&Folder::new(&"folder-0-4".to_string(), &"note-0".to_string()))
+            .add::<Folder>(&Folder::new("folder-0-4", "note-0"))
             .await;
         // debug!("err: {:?}", err);
-        assert!(matches!(err, Err(_)));
+        assert!(err.is_err());
 
-        let result = storage.get_folders(None, None, String::new()).await.unwrap();
+        let result = storage.get_folders(None, None, String::new(), true).await.unwrap();
         // debug!("result.len(): {:?}", &result.folders.len());
         assert_eq!(&(data.len()), &result.folders.len());
 
@@ -0,0 +1,72 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::model::{QueryCondition, SmartFolder, Word};
+    use crate::storage::tests::TestStorage;
+    use crate::tests::test_init;
+
+    #[wasm_bindgen_test(async)]
+    async fn get_smart_folder_words_test() {
+        test_init("get_smart_folder_words_test");
+        let storage = TestStorage::open("get_smart_folder_words_test").await;
+
+        storage.add::<Word>(&Word::new("first", "give up", "phrasal verb", "", "")).await.unwrap();
+        storage.add::<Word>(&Word::new("second", "house", "noun", "", "")).await.unwrap();
+        storage.add::<Word>(&Word::new("second", "give in", "phrasal verb", "", "note")).await.unwrap();
+
+        let id = storage
+            .add::<SmartFolder>(&SmartFolder::new(
+                "phrasal verbs",
+                vec![QueryCondition::WordClass("phrasal verb".to_string())],
+            ))
+            .await
+            .unwrap();
+        storage
+            .add::<SmartFolder>(&SmartFolder::new("no note", vec![QueryCondition::NoNote]))
+            .await
+            .unwrap();
+
+        let now = Utc::now().timestamp_millis();
+        let result = storage
+            .get_smart_folder_words(id, now, String::new(), None, None, "↑".to_string())
+            .await
+            .unwrap();
+        assert_eq!(2, result.count);
+        let words: Vec<&str> = result.words.iter().map(|x| x.word.as_str()).collect();
+        assert_eq!(vec!["give up", "give in"], words);
+
+        let result = storage
+            .get_smart_folder_words(id, now, "IN".to_string(), None, None, "↑".to_string())
+            .await
+            .unwrap();
+        assert_eq!(1, result.count);
+
+        let smart_folders = storage.get_smart_folders(now).await.unwrap();
+        let counts: Vec<u32> = smart_folders.iter().map(|x| x.word_count).collect();
+        assert_eq!(vec![2, 2], counts);
+
+        let result = storage
+            .get_smart_folder_words(id + 10, now, String::new(), None, None, "↑".to_string())
+            .await
+            .unwrap();
+        assert_eq!(0, result.count);
+    }
+}
@@ -0,0 +1,47 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::model::Word;
+    use crate::storage::tests::TestStorage;
+    use crate::storage::StorageErrorKind;
+    use crate::tests::test_init;
+
+    #[wasm_bindgen_test(async)]
+    async fn get_word_by_name_test() {
+        test_init("get_word_by_name_test");
+        let storage = TestStorage::open("get_word_by_name_test").await;
+
+        let word = Word::new("folder", "word", "noun", "url", "note");
+        let id = storage.add::<Word>(&word).await.unwrap();
+
+        let err = storage
+            .add::<Word>(&Word::new("folder", "word", "verb", "other-url", "other note"))
+            .await
+            .unwrap_err();
+        assert_eq!(StorageErrorKind::ConstraintViolation, err.kind());
+
+        let result = storage.get_word_by_name("folder", "word").await.unwrap().unwrap();
+        assert_eq!(Some(id), result.id);
+        assert_eq!("note", result.note);
+
+        assert_eq!(None, storage.get_word_by_name("folder", "other").await.unwrap());
+        assert_eq!(None, storage.get_word_by_name("other", "word").await.unwrap());
+    }
+}
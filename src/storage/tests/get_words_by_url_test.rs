@@ -0,0 +1,59 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::model::Word;
+    use crate::storage::tests::TestStorage;
+    use crate::tests::test_init;
+
+    #[wasm_bindgen_test(async)]
+    async fn get_words_by_url_test() {
+        test_init("get_words_by_url_test");
+        let storage = TestStorage::open("get_words_by_url_test").await;
+
+        let url = "https://www.oxfordlearnersdictionaries.com/definition/english/get-words-by-url_1";
+
+        let folders = vec!["folder-1-11".to_string(), "folder-2-11".to_string()];
+        for folder in &folders {
+            storage
+                .add::<Word>(&Word::new(folder, "word-1-11", "noun", url, "note-1"))
+                .await
+                .unwrap();
+        }
+
+        let result = storage
+            .get_words_by_url(
+                "https://oxfordlearnersdictionaries.com/definition/english/get-words-by-url_1?q=word#top",
+            )
+            .await
+            .unwrap();
+        // debug!("result: {:?}", &result);
+        assert_eq!(folders.len(), result.len());
+        for word in &result {
+            assert!(folders.contains(&word.folder));
+            assert_eq!(url, word.canonical_url);
+        }
+
+        let result = storage
+            .get_words_by_url("https://www.oxfordlearnersdictionaries.com/definition/english/get-words-by-url_2")
+            .await
+            .unwrap();
+        assert!(result.is_empty());
+    }
+}
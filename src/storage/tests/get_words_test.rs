@@ -20,14 +20,14 @@ mod tests {
 
     use wasm_bindgen_test::wasm_bindgen_test;
 
-    use crate::model::Word;
-    use crate::storage::Storage;
+    use crate::model::{Review, Word};
+    use crate::storage::tests::TestStorage;
     use crate::tests::test_init;
 
     #[wasm_bindgen_test(async)]
     async fn get_words_test() {
         test_init("get_words_test");
-        let storage = Storage::open().await.unwrap();
+        let storage = TestStorage::open("get_words_test").await;
 
         let mut data: Vec<Word> = vec![];
         let length = 10;
@@ -51,13 +51,17 @@ mod tests {
                 word,
                 word_class,
                 url,
+                canonical_url: String::new(),
                 note,
+                source_context: String::new(),
+                examples: vec![],
+                review: Review::default(),
                 datetime: 0,
             });
         }
 
         let result = storage
-            .get_words(folder.clone(), Some(length), Some(0), "ascending".to_string())
+            .get_words(folder.clone(), String::new(), Some(length), Some(0), "ascending".to_string())
             .await
             .unwrap();
         // debug!("result.len(): {:?}", &result.len());
@@ -84,4 +88,35 @@ mod tests {
         // debug!("count: {}", count);
         assert_eq!(length, count);
     }
+
+    #[wasm_bindgen_test(async)]
+    async fn filter_words_test() {
+        test_init("filter_words_test");
+        let storage = TestStorage::open("filter_words_test").await;
+
+        let folder = "folder-0-13".to_string();
+
+        for i in 0..10 {
+            let word = Word::new(&folder, &format!("word-{}-13", i), "noun", "url", "note")
+                .with_examples(&format!("Example {} with a Needle\nsecond example", i % 2));
+            storage.add::<Word>(&word).await.unwrap();
+        }
+
+        let result = storage
+            .get_words(folder.clone(), "example 1 with a needle".to_string(), Some(2), Some(1), "ascending".to_string())
+            .await
+            .unwrap();
+        assert_eq!(5, result.count);
+        assert_eq!(2, result.words.len());
+        for word in &result.words {
+            assert_eq!("Example 1 with a Needle", word.examples[0]);
+        }
+
+        let result = storage
+            .get_words(folder.clone(), "WORD-3".to_string(), None, None, "ascending".to_string())
+            .await
+            .unwrap();
+        assert_eq!(1, result.count);
+        assert_eq!("word-3-13", result.words[0].word);
+    }
 }
@@ -18,15 +18,15 @@
 mod tests {
     use wasm_bindgen_test::wasm_bindgen_test;
 
-    use crate::model::{Folder, Word};
-    use crate::storage::{IMPORT_EXPORT_DATA_VERSION, Storage};
+    use crate::model::{Folder, Review, Word};
+    use crate::storage::{IMPORT_EXPORT_DATA_VERSION, StorageBackend};
+    use crate::storage::tests::TestStorage;
     use crate::tests::test_init;
 
-    #[ignore = "should run separately"]
     #[wasm_bindgen_test(async)]
     async fn import_data_test() {
         test_init("import_data_test");
-        let storage = Storage::open().await.unwrap();
+        let storage = TestStorage::open("import_data_test").await;
 
         let mut folders: Vec<Folder> = vec![];
         let mut words: Vec<Word> = vec![];
@@ -56,7 +56,11 @@ mod tests {
                     word,
                     word_class,
                     url,
+                    canonical_url: String::new(),
                     note,
+                    source_context: String::new(),
+                    examples: vec![],
+                    review: Review::default(),
                     datetime: 0,
                 });
             }
@@ -64,13 +68,11 @@ mod tests {
 
         let json_str = storage.export_data().await.unwrap();
 
-        storage.close_db();
-        Storage::delete_db().await.unwrap();
-        let storage = Storage::open().await.unwrap();
+        let other_storage = TestStorage::open("import_data_test_other").await;
 
-        let data = storage.import_data(json_str).await.unwrap();
+        let data = other_storage.import_data(json_str.clone()).await.unwrap();
         assert_eq!(IMPORT_EXPORT_DATA_VERSION, data.version);
-
-        // TODO compare data in the database with "folders" and "words"
+        assert_eq!(folders, data.folders);
+        assert_eq!(json_str, other_storage.export_data().await.unwrap());
     }
 }
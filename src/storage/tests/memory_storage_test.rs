@@ -0,0 +1,192 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use futures_executor::block_on;
+
+    use crate::model::{Data, Folder, Word};
+    use crate::storage::{MemoryStorage, StorageBackend, StorageError, IMPORT_EXPORT_DATA_VERSION};
+
+    const ASCENDING: &str = "\u{2191}";
+    const DESCENDING: &str = "\u{2193}";
+
+    #[test]
+    fn memory_storage_get_folders_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+
+            for i in 0..5 {
+                let id = storage
+                    .add::<Folder>(&Folder::new(&format!("folder-{}", i), "note"))
+                    .await
+                    .unwrap();
+                assert_eq!(i + 1, id);
+            }
+
+            let result = storage
+                .get_folders(Some(2), Some(1), ASCENDING.to_string(), true)
+                .await
+                .unwrap();
+            assert_eq!(5, result.count);
+            let names: Vec<&str> = result.folders.iter().map(|x| x.folder.as_str()).collect();
+            assert_eq!(vec!["folder-1", "folder-2"], names);
+            assert_eq!(Some(2), result.folders[0].id);
+
+            let result = storage
+                .get_folders(None, None, DESCENDING.to_string(), true)
+                .await
+                .unwrap();
+            assert_eq!("folder-4", result.folders[0].folder);
+            assert_eq!(5, result.folders.len());
+        });
+    }
+
+    #[test]
+    fn memory_storage_get_words_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+
+            for i in 0..10 {
+                let folder = format!("folder-{}", i % 2);
+                let word = Word::new(&folder, &format!("word-{}", i), "noun", "url", "note")
+                    .with_examples(&format!("example {}", i % 3));
+                storage.add::<Word>(&word).await.unwrap();
+            }
+
+            let result = storage
+                .get_words("folder-0".to_string(), String::new(), Some(3), Some(1), ASCENDING.to_string())
+                .await
+                .unwrap();
+            assert_eq!(5, result.count);
+            let words: Vec<&str> = result.words.iter().map(|x| x.word.as_str()).collect();
+            assert_eq!(vec!["word-2", "word-4", "word-6"], words);
+
+            let result = storage
+                .get_words("folder-1".to_string(), "EXAMPLE 0".to_string(), None, None, DESCENDING.to_string())
+                .await
+                .unwrap();
+            assert_eq!(2, result.count);
+            let words: Vec<&str> = result.words.iter().map(|x| x.word.as_str()).collect();
+            assert_eq!(vec!["word-9", "word-3"], words);
+        });
+    }
+
+    #[test]
+    fn memory_storage_unique_index_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+
+            storage.add::<Folder>(&Folder::new("folder", "note")).await.unwrap();
+            let result = storage.add::<Folder>(&Folder::new("folder", "other note")).await;
+            assert_eq!(Err(StorageError::ConstraintError("folder".to_string())), result);
+
+            storage.add::<Word>(&Word::new("folder", "word", "", "", "")).await.unwrap();
+            storage.add::<Word>(&Word::new("other", "word", "", "", "")).await.unwrap();
+
+            let words = vec![
+                Word::new("folder", "new word", "", "", ""),
+                Word::new("folder", "word", "", "", ""),
+            ];
+            let result = storage.import(&words).await;
+            assert_eq!(Err(StorageError::ConstraintError("folder, word".to_string())), result);
+
+            let result = storage
+                .get_words("folder".to_string(), String::new(), None, None, ASCENDING.to_string())
+                .await
+                .unwrap();
+            assert_eq!(1, result.count);
+        });
+    }
+
+    #[test]
+    fn memory_storage_delete_by_id_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+
+            let id = storage.add::<Word>(&Word::new("folder", "word-1", "", "", "")).await.unwrap();
+            storage.add::<Word>(&Word::new("folder", "word-2", "", "", "")).await.unwrap();
+
+            storage.delete_by_id::<Word>(id).await.unwrap();
+            storage.delete_by_id::<Folder>(id).await.unwrap();
+
+            let result = storage
+                .get_words("folder".to_string(), String::new(), None, None, ASCENDING.to_string())
+                .await
+                .unwrap();
+            assert_eq!(1, result.count);
+            assert_eq!("word-2", result.words[0].word);
+
+            let id = storage.add::<Word>(&Word::new("folder", "word-1", "", "", "")).await.unwrap();
+            assert_eq!(3, id);
+        });
+    }
+
+    #[test]
+    fn memory_storage_update_without_id_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            storage.add::<Word>(&Word::new("folder", "word-1", "", "", "")).await.unwrap();
+
+            let result = storage.update::<Word>(&Word::new("folder", "word-2", "", "", "")).await;
+            assert_eq!(Err(StorageError::MissingId("words".to_string())), result);
+
+            let result = storage
+                .get_words("folder".to_string(), String::new(), None, None, ASCENDING.to_string())
+                .await
+                .unwrap();
+            assert_eq!(1, result.count);
+        });
+    }
+
+    #[test]
+    fn memory_storage_export_import_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+
+            let folder = Folder::new("folder", "note");
+            storage.add::<Folder>(&folder).await.unwrap();
+            let word = Word::new("folder", "word", "noun", "https://www.example.com/word/", "note")
+                .with_examples("example");
+            storage.add::<Word>(&word).await.unwrap();
+
+            let json = storage.export_data().await.unwrap();
+            let data: Data = serde_json::from_str(&json).unwrap();
+            assert_eq!(IMPORT_EXPORT_DATA_VERSION, data.version);
+            assert_eq!(vec![folder.clone()], data.folders);
+            assert_eq!(vec![word.clone()], data.words);
+
+            let other_storage = MemoryStorage::default();
+            other_storage.import_data(json.clone()).await.unwrap();
+            assert_eq!(json, other_storage.export_data().await.unwrap());
+
+            let result = other_storage.import_data(json).await;
+            assert_eq!(Err(StorageError::ConstraintError("folder".to_string())), result.map(|_| ()));
+
+            let invalid_version = serde_json::to_string(&Data {
+                version: IMPORT_EXPORT_DATA_VERSION + 1,
+                folders: vec![],
+                words: vec![],
+                smart_folders: vec![],
+                settings: None,
+                folder_parents: Default::default(),
+            })
+            .unwrap();
+            let result = MemoryStorage::default().import_data(invalid_version).await;
+            assert!(matches!(result, Err(StorageError::ImportError(_))));
+        });
+    }
+}
@@ -16,9 +16,31 @@
 
 mod add_folders_and_words_test;
 mod add_word_test;
+mod app_settings_test;
+mod check_database_test;
+mod check_integrity_test;
+mod daily_totals_test;
 mod delete_word_test;
 mod export_data_test;
+mod folder_tree_test;
+mod get_folder_stats_test;
 mod get_folders_test;
+mod get_smart_folder_words_test;
+mod get_word_by_name_test;
+mod get_words_by_url_test;
 mod get_words_test;
 mod import_data_test;
+mod memory_storage_test;
+mod print_test;
+mod quick_add_test;
+mod settings_test;
+mod smart_folder_test;
+mod storage_error_test;
 mod storage_open_test;
+mod update_word_test;
+mod word_stats_test;
+
+#[cfg(test)]
+mod test_storage;
+#[cfg(test)]
+pub(super) use test_storage::TestStorage;
@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use futures_executor::block_on;
+
+    use crate::i18n::Locale;
+    use crate::model::{Data, Folder, Word};
+    use crate::print::{print_html, split_pronunciation, FontFamily, PaperSize, PrintMode, PrintOptions};
+    use crate::storage::{MemoryStorage, StorageBackend};
+
+    fn options(mode: PrintMode) -> PrintOptions {
+        PrintOptions {
+            mode,
+            paper_size: PaperSize::Letter,
+            font_family: FontFamily::SansSerif,
+            font_size: 14,
+            locale: Locale::De,
+        }
+    }
+
+    async fn subtree_data() -> Data {
+        let storage = MemoryStorage::default();
+        let unit = storage.add::<Folder>(&Folder::new("unit <1>", "")).await.unwrap();
+        storage
+            .add::<Folder>(&Folder::new("lesson", "").with_parent(Some(unit)))
+            .await
+            .unwrap();
+        storage.add::<Folder>(&Folder::new("other", "")).await.unwrap();
+
+        for (folder, word, note) in [
+            ("unit <1>", "house", "/haʊs/ a **building**"),
+            ("lesson", "tree", "- green\n- tall"),
+            ("lesson", "bird", ""),
+            ("other", "cat", ""),
+        ] {
+            let word = Word::new(folder, word, "noun", "", note).with_examples("a <small> house");
+            storage.add::<Word>(&word).await.unwrap();
+        }
+
+        storage.get_subtree_data(unit).await.unwrap()
+    }
+
+    #[test]
+    fn word_list_test() {
+        block_on(async {
+            let html = print_html(&subtree_data().await, &options(PrintMode::WordList));
+
+            assert!(html.contains("<html lang=\"de\">"));
+            assert!(html.contains("size: letter;"));
+            assert!(html.contains("font-family: sans-serif; font-size: 14pt;"));
+            assert!(html.contains("<title>unit &lt;1&gt;</title>"));
+            assert!(html.contains("<th>Aussprache</th>"));
+            assert!(html.contains("<td>house</td><td>noun</td><td>/haʊs/</td><td><p>a <b>building</b></p>"));
+            assert!(html.contains("<ul><li>green</li><li>tall</li></ul>"));
+            assert!(html.contains("<li>a &lt;small&gt; house</li>"));
+            assert!(html.find("<h2>unit &lt;1&gt;</h2>") < html.find("<h2>lesson</h2>"));
+            assert!(!html.contains("cat"));
+        });
+    }
+
+    #[test]
+    fn flashcards_test() {
+        block_on(async {
+            let html = print_html(&subtree_data().await, &options(PrintMode::Flashcards));
+
+            assert_eq!(1, html.matches("<div class=\"sheet front\">").count());
+            assert_eq!(3, html.matches("<div class=\"word\">").count());
+            let front = html.find("<div class=\"word\">house</div>").unwrap();
+            assert!(front < html.find("<div class=\"word\">tree</div>").unwrap());
+
+            // the fronts are house, bird / tree, so the backs are bird, house / blank, tree
+            let back = &html[html.find("<div class=\"sheet back\">").unwrap()..];
+            let house = back.find("<div class=\"pronunciation\">/haʊs/</div>").unwrap();
+            let blank = back.find("<div class=\"blank\"></div>").unwrap();
+            let tree = back.find("<li>green</li>").unwrap();
+            assert!(back.find("<div class=\"card\">").unwrap() < house);
+            assert!(house < blank && blank < tree);
+        });
+    }
+
+    #[test]
+    fn split_pronunciation_test() {
+        assert_eq!(("/haʊs/".to_string(), "a building".to_string()), split_pronunciation(" /haʊs/ a building"));
+        assert_eq!(("[tri:]".to_string(), String::new()), split_pronunciation("[tri:]"));
+        assert_eq!((String::new(), "/not closed".to_string()), split_pronunciation("/not closed"));
+        assert_eq!((String::new(), "a note".to_string()), split_pronunciation("a note"));
+    }
+}
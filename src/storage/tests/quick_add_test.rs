@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use futures_executor::block_on;
+
+    use crate::dictionary_source::lookup_url;
+    use crate::model::{Settings, Word};
+    use crate::quick_add::{add_word, QuickAddError, QuickAddResult};
+    use crate::storage::{MemoryStorage, StorageBackend, StorageError};
+
+    #[test]
+    fn quick_add_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            let word = Word::new("", "take off", "", "url", "");
+
+            assert_eq!(Err(QuickAddError::FolderIsNotSet), add_word(&storage, word.clone(), true).await);
+
+            let settings = Settings {
+                quick_add_folder: "inbox".to_string(),
+                lookup_after_save: true,
+                ..Settings::default()
+            };
+            storage.save_settings(&settings).await.unwrap();
+
+            let result = add_word(&storage, word.clone(), true).await;
+            assert_eq!(
+                Ok(QuickAddResult { folder: "inbox".to_string(), lookup_url: Some(lookup_url("take off")) }),
+                result
+            );
+            // a word from the dictionary page isn't looked up again
+            let result = add_word(&storage, Word::new("", "go", "verb", "url", ""), false).await;
+            assert_eq!(None, result.unwrap().lookup_url);
+
+            let words = storage.get_folder_words("inbox").await.unwrap();
+            assert_eq!(2, words.len());
+
+            let totals = storage.get_daily_totals(0).await.unwrap();
+            assert_eq!(2, totals.values().map(|total| total.words).sum::<u32>());
+
+            assert_eq!(
+                Err(QuickAddError::Storage(StorageError::ConstraintError("inbox, take off".to_string()))),
+                add_word(&storage, word, true).await
+            );
+        });
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::model::{Settings, SETTINGS_VERSION};
+    use crate::storage::tests::TestStorage;
+    use crate::tests::test_init;
+
+    #[wasm_bindgen_test(async)]
+    async fn settings_test() {
+        test_init("settings_test");
+        let storage = TestStorage::open("settings_test").await;
+
+        let result: Option<String> = storage.get_setting("setting-1-12").await.unwrap();
+        assert_eq!(None, result);
+
+        storage
+            .put_setting("setting-1-12", &"value-1".to_string())
+            .await
+            .unwrap();
+        let result: Option<String> = storage.get_setting("setting-1-12").await.unwrap();
+        assert_eq!(Some("value-1".to_string()), result);
+
+        storage
+            .put_setting("setting-1-12", &"value-2".to_string())
+            .await
+            .unwrap();
+        let result: Option<String> = storage.get_setting("setting-1-12").await.unwrap();
+        assert_eq!(Some("value-2".to_string()), result);
+    }
+
+    #[wasm_bindgen_test(async)]
+    async fn load_and_save_settings_test() {
+        test_init("load_and_save_settings_test");
+        let storage = TestStorage::open("load_and_save_settings_test").await;
+
+        storage.put_setting("quick_add_folder", &"inbox".to_string()).await.unwrap();
+        storage.put_setting("lookup_after_save", &true).await.unwrap();
+
+        let settings = storage.load_settings().await.unwrap();
+        assert_eq!(0, settings.version);
+        assert_eq!("inbox", settings.quick_add_folder);
+        assert!(settings.lookup_after_save);
+
+        let settings = Settings {
+            words_page_length: Some(20),
+            ..settings.migrate()
+        };
+        storage.save_settings(&settings).await.unwrap();
+
+        let result = storage.load_settings().await.unwrap();
+        assert_eq!(SETTINGS_VERSION, result.version);
+        assert_eq!(settings, result);
+    }
+}
@@ -0,0 +1,100 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use futures_executor::block_on;
+
+    use crate::model::{Data, QueryCondition, SmartFolder, Word};
+    use crate::storage::{MemoryStorage, StorageBackend};
+
+    const DAY: i64 = 24 * 60 * 60 * 1000;
+    const NOW: i64 = 100 * DAY;
+
+    fn word(word: &str, word_class: &str, note: &str, days_ago: i64) -> Word {
+        let mut word = Word::new("folder", word, word_class, "url", note);
+        word.datetime = NOW - days_ago * DAY;
+        word
+    }
+
+    #[test]
+    fn query_condition_test() {
+        let recent = word("give up", "phrasal verb", "#exam, hard", 2);
+        let old = word("house", "noun", "", 30);
+
+        assert!(QueryCondition::AddedWithinDays(7).matches(&recent, NOW));
+        assert!(!QueryCondition::AddedWithinDays(7).matches(&old, NOW));
+
+        assert!(QueryCondition::WordClass("Phrasal Verb".to_string()).matches(&recent, NOW));
+        assert!(!QueryCondition::WordClass("verb".to_string()).matches(&recent, NOW));
+
+        assert!(QueryCondition::Tag("exam".to_string()).matches(&recent, NOW));
+        assert!(QueryCondition::Tag("#EXAM".to_string()).matches(&recent, NOW));
+        assert!(!QueryCondition::Tag("ex".to_string()).matches(&recent, NOW));
+        assert!(QueryCondition::Tag("exam".to_string())
+            .matches(&old.with_examples("the #exam is tomorrow"), NOW));
+
+        assert!(QueryCondition::NoNote.matches(&old, NOW));
+        assert!(!QueryCondition::NoNote.matches(&recent, NOW));
+    }
+
+    #[test]
+    fn due_for_review_test() {
+        let mut word = word("house", "noun", "", 30);
+        assert!(QueryCondition::DueForReview.matches(&word, NOW));
+
+        word.review.count = 3;
+        word.review.correct = 2;
+        word.review.datetime = NOW - 3 * DAY;
+        assert!(!QueryCondition::DueForReview.matches(&word, NOW));
+
+        word.review.datetime = NOW - 4 * DAY;
+        assert!(QueryCondition::DueForReview.matches(&word, NOW));
+    }
+
+    #[test]
+    fn smart_folder_matches_all_conditions_test() {
+        let smart_folder = SmartFolder::new(
+            "new phrasal verbs",
+            vec![
+                QueryCondition::AddedWithinDays(7),
+                QueryCondition::WordClass("phrasal verb".to_string()),
+            ],
+        );
+
+        assert!(smart_folder.matches(&word("give up", "phrasal verb", "", 2), NOW));
+        assert!(!smart_folder.matches(&word("give in", "phrasal verb", "", 20), NOW));
+        assert!(!smart_folder.matches(&word("house", "noun", "", 2), NOW));
+    }
+
+    #[test]
+    fn smart_folders_export_and_import_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            let smart_folder = SmartFolder::new("no note", vec![QueryCondition::NoNote]);
+            storage.add::<SmartFolder>(&smart_folder).await.unwrap();
+            assert!(storage.add::<SmartFolder>(&smart_folder).await.is_err());
+
+            let json = storage.export_data().await.unwrap();
+            let data: Data = serde_json::from_str(&json).unwrap();
+            assert_eq!(vec![smart_folder], data.smart_folders);
+
+            let other_storage = MemoryStorage::default();
+            let data = other_storage.import_data(json).await.unwrap();
+            assert_eq!(1, data.smart_folders.len());
+        });
+    }
+}
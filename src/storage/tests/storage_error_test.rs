@@ -0,0 +1,66 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+    use web_sys::DomException;
+
+    use crate::i18n::Locale;
+    use crate::storage::{storage_error_message, StorageError, StorageErrorKind};
+    use crate::tests::test_init;
+
+    fn dom_exception(name: &str) -> StorageError {
+        let exception = DomException::new_with_message_and_name("message", name).unwrap();
+        StorageError::RexieError(rexie::Error::IndexedDbRequestError(exception.into()))
+    }
+
+    #[wasm_bindgen_test]
+    fn storage_error_kind_test() {
+        test_init("storage_error_kind_test");
+
+        assert_eq!(StorageErrorKind::ConstraintViolation, dom_exception("ConstraintError").kind());
+        assert_eq!(StorageErrorKind::QuotaExceeded, dom_exception("QuotaExceededError").kind());
+        assert_eq!(StorageErrorKind::VersionMismatch, dom_exception("VersionError").kind());
+        assert_eq!(StorageErrorKind::CorruptData, dom_exception("DataError").kind());
+        assert_eq!(StorageErrorKind::Unknown, dom_exception("AbortError").kind());
+        assert_eq!(
+            StorageErrorKind::Unknown,
+            StorageError::RexieError(rexie::Error::AsyncChannelError).kind()
+        );
+
+        assert_eq!(
+            StorageErrorKind::ConstraintViolation,
+            StorageError::ConstraintError("folder".to_string()).kind()
+        );
+        assert_eq!(
+            StorageErrorKind::CorruptData,
+            StorageError::CorruptRecord { store: "words".to_string(), key: "1".to_string() }.kind()
+        );
+        assert_eq!(
+            StorageErrorKind::NotFound,
+            StorageError::NotFound { store: "words".to_string(), key: "1".to_string() }.kind()
+        );
+        assert_eq!(
+            StorageErrorKind::VersionMismatch,
+            StorageError::ImportError("Invalid version".to_string()).kind()
+        );
+        assert_eq!(
+            "The browser storage is full. Export your data and delete what you don't need",
+            storage_error_message(Locale::En, &dom_exception("QuotaExceededError"))
+        );
+    }
+}
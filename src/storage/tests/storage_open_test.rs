@@ -18,24 +18,27 @@
 mod tests {
     use wasm_bindgen_test::wasm_bindgen_test;
 
-    use crate::storage::{DATABASE_NAME, OBJ_STORE_FOLDERS, OBJ_STORE_WORDS};
+    use crate::storage::{OBJ_STORE_FOLDERS, OBJ_STORE_WORDS};
     use crate::storage::DATABASE_VERSION;
-    use crate::storage::Storage;
+    use crate::storage::tests::TestStorage;
     use crate::tests::test_init;
 
     #[wasm_bindgen_test(async)]
     async fn storage_open_test() {
         test_init("storage_open_test");
 
-        let storage = Storage::open().await.unwrap();
-        assert_eq!(DATABASE_NAME, &storage.rexie.name());
+        let storage = TestStorage::open("storage_open_test").await;
+        assert_eq!(storage.name(), &storage.rexie.name());
         assert_eq!(
             <u32 as Into<f64>>::into(DATABASE_VERSION),
             storage.rexie.version()
         );
-        assert_eq!("folders", storage.rexie.store_names().get(0).unwrap());
-        assert_eq!("words", storage.rexie.store_names().get(1).unwrap());
-        assert_eq!(2, storage.rexie.store_names().len());
+        assert_eq!("folders", storage.rexie.store_names().first().unwrap());
+        assert_eq!("quarantine", storage.rexie.store_names().get(1).unwrap());
+        assert_eq!("settings", storage.rexie.store_names().get(2).unwrap());
+        assert_eq!("smart_folders", storage.rexie.store_names().get(3).unwrap());
+        assert_eq!("words", storage.rexie.store_names().get(4).unwrap());
+        assert_eq!(5, storage.rexie.store_names().len());
 
         let folders_index_names = storage.get_store(OBJ_STORE_FOLDERS)
             .unwrap()
@@ -45,6 +48,6 @@ mod tests {
         let words_index_names = storage.get_store(OBJ_STORE_WORDS)
             .unwrap()
             .index_names();
-        assert_eq!(vec!["folder", "words"], words_index_names);
+        assert_eq!(vec!["canonical_url", "folder", "word", "words"], words_index_names);
     }
 }
@@ -0,0 +1,68 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::ops::Deref;
+
+use chrono::Utc;
+use log::debug;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::storage::{Storage, StorageConfig, DATABASE_NAME};
+
+/// Opens a database with a unique name, so the tests don't share data,
+/// and deletes it when dropped
+pub(crate) struct TestStorage {
+    name: String,
+    storage: Option<Storage>,
+}
+
+impl TestStorage {
+    pub(crate) async fn open(test_name: &str) -> TestStorage {
+        let name = format!("{}-{}-{}", DATABASE_NAME, test_name, Utc::now().timestamp_millis());
+        let storage = Storage::open(&StorageConfig::new(&name)).await.unwrap();
+        TestStorage {
+            name,
+            storage: Some(storage),
+        }
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Deref for TestStorage {
+    type Target = Storage;
+
+    fn deref(&self) -> &Self::Target {
+        self.storage.as_ref().unwrap()
+    }
+}
+
+impl Drop for TestStorage {
+    fn drop(&mut self) {
+        if let Some(storage) = self.storage.take() {
+            storage.close_db();
+
+            let name = self.name.clone();
+            spawn_local(async move {
+                if let Err(err) = Storage::delete_db(&name).await {
+                    debug!("TestStorage: can't delete {}: {:?}", name, err);
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::model::Word;
+    use crate::storage::StorageError;
+    use crate::storage::tests::TestStorage;
+    use crate::tests::test_init;
+
+    #[wasm_bindgen_test(async)]
+    async fn update_word_test() {
+        test_init("update_word_test");
+        let storage = TestStorage::open("update_word_test").await;
+
+        let word = Word::new("folder-1-14", "word-1-14", "noun", "url-1", "note-1");
+        let id = storage.add::<Word>(&word).await.unwrap();
+
+        let mut word = storage.get_word_by_id(id).await.unwrap().with_review(true);
+        word.note = "note-2".to_string();
+        storage.update::<Word>(&word).await.unwrap();
+
+        let result = storage.get_word_by_id(id).await.unwrap();
+        assert_eq!(word, result);
+        assert_eq!(1, result.review.count);
+        assert_eq!(1, result.review.correct);
+
+        let word = Word::new("folder-2-14", "word-1-14", "noun", "url-1", "note-1");
+        storage.add::<Word>(&word).await.unwrap();
+
+        let result = storage.get_words_by_headword("word-1-14").await.unwrap();
+        assert_eq!(2, result.len());
+
+        let word = Word::new("folder-3-14", "word-1-14", "noun", "url-1", "note-1");
+        assert_eq!(
+            Err(StorageError::MissingId("words".to_string())),
+            storage.update::<Word>(&word).await
+        );
+    }
+}
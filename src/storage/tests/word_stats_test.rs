@@ -0,0 +1,184 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use futures_executor::block_on;
+
+    use crate::model::Word;
+    use crate::stats::{day_to_string, largest, week_start, WordStats, MOST_REVIEWED_COUNT};
+    use crate::storage::{MemoryStorage, StorageBackend};
+
+    const DAY: i64 = 24 * 60 * 60 * 1000;
+    const HOUR: i64 = 60 * 60 * 1000;
+    /// 1970-04-11, a Saturday
+    const TODAY: i64 = 100;
+
+    fn word(folder: &str, word: &str, word_class: &str, days_ago: i64) -> Word {
+        let mut word = Word::new(folder, word, word_class, &format!("url/{word}"), "");
+        word.datetime = (TODAY - days_ago) * DAY + 12 * HOUR;
+        word
+    }
+
+    fn reviewed(mut word: Word, count: u32, correct: u32, days_ago: i64) -> Word {
+        word.review.count = count;
+        word.review.correct = correct;
+        word.review.datetime = (TODAY - days_ago) * DAY + 12 * HOUR;
+        word
+    }
+
+    #[test]
+    fn word_stats_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            for word in [
+                word("verbs", "go", "verb", 0),
+                word("verbs", "give up", "phrasal verb", 1),
+                reviewed(word("verbs", "take", "verb", 10), 4, 3, 2),
+                reviewed(word("nouns", "house", "noun", 10), 6, 3, 5),
+                word("nouns", "tree", " ", 20),
+            ] {
+                storage.add(&word).await.unwrap();
+            }
+
+            let stats = storage.get_word_stats(0).await.unwrap();
+            assert_eq!(5, stats.word_count);
+            assert_eq!(Some(&3), stats.per_folder.get("verbs"));
+            assert_eq!(Some(&2), stats.per_word_class.get("verb"));
+            assert_eq!(Some(&1), stats.per_word_class.get(""));
+            assert_eq!(vec![("verbs".to_string(), 3), ("nouns".to_string(), 2)], largest(&stats.per_folder, 5));
+
+            assert_eq!(10, stats.review_count);
+            assert_eq!(Some(60), stats.accuracy());
+            assert_eq!(vec!["house", "take"],
+                stats.most_reviewed().iter().map(|reviewed| reviewed.word.as_str()).collect::<Vec<_>>());
+
+            assert_eq!(vec![(98, 0), (99, 1), (100, 1)], stats.last_days(TODAY, 3));
+            // the week of TODAY starts on Monday the 95th day
+            assert_eq!(vec![(88, 2), (95, 2)], stats.last_weeks(TODAY, 2));
+
+            assert_eq!(3, stats.current_streak(TODAY));
+            assert_eq!(3, stats.current_streak(TODAY + 1));
+            assert_eq!(0, stats.current_streak(TODAY + 2));
+            assert_eq!(3, stats.longest_streak());
+        });
+    }
+
+    #[test]
+    fn word_stats_counters_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            let take_id = storage.add(&word("verbs", "take", "verb", 10)).await.unwrap();
+            let tree_id = storage.add(&word("nouns", "tree", "noun", 20)).await.unwrap();
+            // the saved counters are collected from the words once and then follow the changes
+            assert_eq!(2, storage.get_word_stats(0).await.unwrap().word_count);
+
+            storage.add(&word("verbs", "go", "verb", 0)).await.unwrap();
+            storage.import(&[reviewed(word("nouns", "house", "noun", 3), 2, 1, 1)]).await.unwrap();
+            let mut take = reviewed(word("phrasal verbs", "take", "verb", 10), 5, 4, 0);
+            take.id = Some(take_id);
+            storage.update(&take).await.unwrap();
+            storage.delete_by_id::<Word>(tree_id).await.unwrap();
+
+            let mut expected = WordStats::new(0);
+            for word in storage.export_store::<Word>().await.unwrap() {
+                expected.add(&word);
+            }
+
+            let stats = storage.get_word_stats(0).await.unwrap();
+            assert_eq!(3, stats.word_count);
+            assert_eq!(expected.added_per_day, stats.added_per_day);
+            assert_eq!(expected.reviewed_per_day, stats.reviewed_per_day);
+            assert_eq!(expected.per_folder, stats.per_folder);
+            assert_eq!(expected.per_word_class, stats.per_word_class);
+            assert_eq!((7, 5), (stats.review_count, stats.correct_count));
+            assert_eq!(vec!["take", "house"],
+                stats.most_reviewed().iter().map(|reviewed| reviewed.word.as_str()).collect::<Vec<_>>());
+            assert_eq!(expected.active_days(), stats.active_days());
+
+            // the days of the counters are local, so they are collected again in another time zone
+            let stats = storage.get_word_stats(2 * 60 * 60).await.unwrap();
+            assert_eq!(2 * 60 * 60, stats.offset_seconds);
+            assert_eq!(3, stats.word_count);
+        });
+    }
+
+    #[test]
+    fn word_stats_local_day_test() {
+        let mut word = word("verbs", "go", "verb", 0);
+        word.datetime = TODAY * DAY + 23 * HOUR;
+
+        let mut stats = WordStats::new(2 * 60 * 60);
+        stats.add(&word);
+        assert_eq!(Some(&1), stats.added_per_day.get(&(TODAY + 1)));
+
+        let mut stats = WordStats::new(-2 * 60 * 60);
+        word.datetime = TODAY * DAY + HOUR;
+        stats.add(&word);
+        assert_eq!(Some(&1), stats.added_per_day.get(&(TODAY - 1)));
+    }
+
+    #[test]
+    fn most_reviewed_test() {
+        let mut stats = WordStats::new(0);
+        for count in 1..=MOST_REVIEWED_COUNT as u32 + 5 {
+            stats.add(&reviewed(word("verbs", &count.to_string(), "verb", 0), count, 0, 0));
+        }
+        assert_eq!(MOST_REVIEWED_COUNT, stats.most_reviewed().len());
+        assert_eq!(MOST_REVIEWED_COUNT as u32 + 5, stats.most_reviewed()[0].count);
+        assert_eq!(6, stats.most_reviewed()[MOST_REVIEWED_COUNT - 1].count);
+        assert_eq!(None, WordStats::new(0).accuracy());
+    }
+
+    #[test]
+    fn most_reviewed_counters_test() {
+        block_on(async {
+            let storage = MemoryStorage::default();
+            let mut ids = vec![];
+            for count in 1..=MOST_REVIEWED_COUNT as u32 + 2 {
+                let word = reviewed(word("verbs", &count.to_string(), "verb", 0), count, 0, 0);
+                ids.push(storage.add(&word).await.unwrap());
+            }
+            let counts = |stats: &WordStats| stats.most_reviewed().iter().map(|x| x.count).collect::<Vec<_>>();
+            let stats = storage.get_word_stats(0).await.unwrap();
+            assert_eq!((3..=12).rev().collect::<Vec<_>>(), counts(&stats));
+
+            // one more review keeps the word in the list without reading all words
+            let mut word = reviewed(word("verbs", "5", "verb", 0), 20, 0, 0);
+            word.id = Some(ids[4]);
+            storage.update(&word).await.unwrap();
+            let stats = storage.get_word_stats(0).await.unwrap();
+            assert_eq!(vec![20, 12, 11, 10, 9, 8, 7, 6, 4, 3], counts(&stats));
+
+            // a deleted word is replaced by the next one outside the list
+            storage.delete_by_id::<Word>(ids[11]).await.unwrap();
+            storage.delete_by_id::<Word>(ids[4]).await.unwrap();
+            let stats = storage.get_word_stats(0).await.unwrap();
+            assert_eq!(vec![11, 10, 9, 8, 7, 6, 4, 3, 2, 1], counts(&stats));
+            assert!(!stats.needs_most_reviewed());
+        });
+    }
+
+    #[test]
+    fn days_test() {
+        assert_eq!("1970-01-01", day_to_string(0));
+        assert_eq!("1970-04-11", day_to_string(TODAY));
+        // Monday, 1970-04-06
+        assert_eq!(95, week_start(TODAY));
+        assert_eq!(95, week_start(95));
+        assert_eq!(-3, week_start(0));
+    }
+}
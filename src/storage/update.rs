@@ -0,0 +1,43 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::storage::{HasId, ObjStoreName, Storage, StorageError};
+
+impl Storage {
+    pub(crate) async fn update<T>(&self, value: &T) -> Result<(), StorageError>
+    where
+        T: serde::Serialize + ObjStoreName + HasId<T>,
+    {
+        // a value without an id would be added as a new record
+        let Some(id) = value.id() else {
+            return Err(StorageError::MissingId(T::OBJ_STORE_NAME.to_string()));
+        };
+        let key = serde_wasm_bindgen::to_value(&id)?;
+        let js_value = serde_wasm_bindgen::to_value(value)?;
+
+        let tc = self.get_transaction(T::OBJ_STORE_NAME)?;
+
+        let old_value = tc.store.get(&key).await?;
+
+        tc.store.put(&js_value, Some(&key)).await?;
+
+        Self::count_word_change(&tc.transaction, T::OBJ_STORE_NAME, &[old_value], &[value]).await?;
+
+        tc.transaction.commit().await?;
+
+        Ok(())
+    }
+}
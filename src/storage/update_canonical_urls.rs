@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use log::debug;
+
+use crate::model::Word;
+use crate::storage::{INDEX_CANONICAL_URL, OBJ_STORE_WORDS, Storage, StorageError};
+
+impl Storage {
+    /// Words saved before the canonical_url index was added are missing from it,
+    /// so the field is filled in for them once after the database is opened.
+    pub(super) async fn update_canonical_urls(&self) -> Result<(), StorageError> {
+        let tc = self.get_transaction(OBJ_STORE_WORDS)?;
+
+        let count = tc.store.count(None).await?;
+        let indexed_count = tc.store.index(INDEX_CANONICAL_URL)?.count(None).await?;
+
+        if count != indexed_count {
+            debug!("update_canonical_urls: {} of {} words", count - indexed_count, count);
+
+            for (key, value) in tc.store.get_all(None, None, None, None).await? {
+                // corrupted words are left for check_database()
+                let Ok(word) = Self::read_record::<Word>(OBJ_STORE_WORDS, &key, value) else {
+                    continue;
+                };
+                let js_value = serde_wasm_bindgen::to_value(&word.with_canonical_url())?;
+                tc.store.put(&js_value, Some(&key)).await?;
+            }
+        }
+
+        tc.transaction.commit().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use rexie::{Transaction, TransactionMode};
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+
+use crate::model::Word;
+use crate::stats::WordStats;
+use crate::storage::{OBJ_STORE_SETTINGS, OBJ_STORE_WORDS, Storage, StorageError};
+
+const WORD_STATS: &str = "word_stats";
+
+impl Storage {
+    pub(super) async fn get_word_stats(&self, offset_seconds: i32) -> Result<WordStats, StorageError> {
+        let transaction = self
+            .rexie
+            .transaction(&[OBJ_STORE_SETTINGS, OBJ_STORE_WORDS], TransactionMode::ReadWrite)?;
+        let store = transaction.store(OBJ_STORE_SETTINGS)?;
+        let key = serde_wasm_bindgen::to_value(WORD_STATS)?;
+
+        let saved = serde_wasm_bindgen::from_value::<WordStats>(store.get(&key).await?).ok();
+        if let Some(stats) = saved.filter(|stats| stats.offset_seconds == offset_seconds) {
+            return Ok(stats);
+        }
+
+        let records = transaction
+            .store(OBJ_STORE_WORDS)?
+            .get_all(None, None, None, None)
+            .await?;
+        let mut stats = WordStats::new(offset_seconds);
+        for word in Self::read_records::<Word>(OBJ_STORE_WORDS, records) {
+            stats.add(&word);
+        }
+
+        store.put(&serde_wasm_bindgen::to_value(&stats)?, Some(&key)).await?;
+
+        transaction.commit().await?;
+
+        Ok(stats)
+    }
+
+    /// Counts the change of the words in the saved statistics, in the transaction of the change.
+    /// Only the deletion of one of the most reviewed words reads all words again.
+    /// Changes of other stores are ignored. The statistics are dropped, so they are collected again,
+    /// if they were never saved or a changed word can't be read
+    pub(super) async fn count_word_change<T>(
+        transaction: &Transaction,
+        store_name: &str,
+        removed: &[JsValue],
+        added: &[T],
+    ) -> Result<(), StorageError>
+    where
+        T: Serialize,
+    {
+        if store_name != OBJ_STORE_WORDS {
+            return Ok(());
+        }
+
+        let store = transaction.store(OBJ_STORE_SETTINGS)?;
+        let key = serde_wasm_bindgen::to_value(WORD_STATS)?;
+
+        let saved = serde_wasm_bindgen::from_value::<WordStats>(store.get(&key).await?).ok();
+        let stats = saved.and_then(|mut stats| {
+            for value in removed {
+                stats.remove(&serde_wasm_bindgen::from_value(value.clone()).ok()?);
+            }
+            for value in added {
+                stats.add(&serde_wasm_bindgen::to_value(value).and_then(serde_wasm_bindgen::from_value).ok()?);
+            }
+            Some(stats)
+        });
+
+        match stats {
+            Some(mut stats) => {
+                if stats.needs_most_reviewed() {
+                    let records = transaction
+                        .store(OBJ_STORE_WORDS)?
+                        .get_all(None, None, None, None)
+                        .await?;
+                    stats.set_most_reviewed(&Self::read_records::<Word>(OBJ_STORE_WORDS, records));
+                }
+                store.put(&serde_wasm_bindgen::to_value(&stats)?, Some(&key)).await?;
+            }
+            None => store.delete(&key).await?,
+        }
+
+        Ok(())
+    }
+
+    /// For the changes of the words which aren't counted one by one, like the repairs of the database
+    pub(super) async fn drop_word_stats(transaction: &Transaction) -> Result<(), StorageError> {
+        let key = serde_wasm_bindgen::to_value(WORD_STATS)?;
+        transaction.store(OBJ_STORE_SETTINGS)?.delete(&key).await?;
+        Ok(())
+    }
+}
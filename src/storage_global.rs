@@ -17,19 +17,19 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
-use crate::storage::Storage;
+use crate::storage::{Storage, StorageConfig};
 
 thread_local! {
     static STORAGE: RefCell<Option<Rc<Storage>>> = const { RefCell::new(None) }
 }
 
 pub async fn init_storage() {
-    let result = Storage::open().await;
+    let result = Storage::open(&StorageConfig::default()).await;
     match result {
         Ok(storage) => STORAGE.with(|cell| {
             cell.replace(Some(Rc::new(storage)));
         }),
-        Err(err) => panic!("init_storage(): {:?}", &err),
+        Err(err) => panic!("init_storage(): {:?}", err),
     }
 }
 
@@ -27,8 +27,8 @@ pub async fn log_storage_properties() -> Result<JsValue, JsValue> {
         .estimate()?;
     let result = JsFuture::from(promise).await;
     match &result {
-        Ok(value) => console::log_2(&"navigator.storage.estimate\n".into(), &value),
-        Err(err) => console::warn_1(&err),
+        Ok(value) => console::log_2(&"navigator.storage.estimate\n".into(), value),
+        Err(err) => console::warn_1(err),
     }
     result
 }
@@ -20,9 +20,12 @@ use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
 use futures_util::StreamExt;
 
-use crate::model::Folder;
+use crate::model::{Folder, FOLDER_PATH_SEPARATOR};
+use crate::storage::StorageBackend;
 use crate::storage_global::get_storage;
-use crate::ui::msg_folder_name_is_empty;
+use crate::ui::navigation::NavigationMessage;
+use crate::i18n::Msg;
+use crate::ui::t;
 
 #[component]
 pub(crate) fn AddFolderForm(
@@ -31,11 +34,30 @@ pub(crate) fn AddFolderForm(
     folder_error_str: Signal<String>,
     refresh_folders: Signal<u8>,
 ) -> Element {
+    let navigation_message = use_coroutine_handle::<NavigationMessage>();
+
+    let mut parent_id = use_signal(|| None::<u32>);
+
+    let parent_folders = use_resource(move || async move {
+        let _ = refresh_folders();
+        get_storage()
+            .get_folders(None, None, String::new(), false)
+            .await
+            .map(|result| {
+                let mut folders = result.folders;
+                folders.sort_by_key(|folder| folder.folder.to_lowercase());
+                folders
+            })
+            .unwrap_or_default()
+    });
+
     let add_folder = use_coroutine(move |mut rx| {
         to_owned![refresh_folders];
         async move {
             while let Some(folder) = rx.next().await {
-                let _ = get_storage().add::<Folder>(&folder).await;
+                if let Err(err) = get_storage().add::<Folder>(&folder).await {
+                    navigation_message.send(NavigationMessage::storage_error(&err));
+                }
                 refresh_folders.toggle();
             }
         }
@@ -47,13 +69,21 @@ pub(crate) fn AddFolderForm(
             onsubmit: move |event| {
                 event.stop_propagation();
 
-                if folder_str().trim().len() == 0 {
-                    folder_error_str.set(msg_folder_name_is_empty.to_string());
+                let parent = parent_id().and_then(|id| {
+                    parent_folders()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .find(|folder| folder.id == Some(id))
+                });
+                if folder_str().trim().is_empty() {
+                    folder_error_str.set(t(Msg::FolderNameIsEmpty).to_string());
+                } else if folder_str().contains(FOLDER_PATH_SEPARATOR) {
+                    folder_error_str.set(t(Msg::FolderNameHasSeparator).to_string());
                 } else {
-                    add_folder.send(Folder::new(
-                        &folder_str(),
-                        &folder_note_str(),
-                    ));
+                    add_folder.send(match parent {
+                        Some(parent) => Folder::new_subfolder(&parent, folder_str().trim(), &folder_note_str()),
+                        None => Folder::new(&folder_str(), &folder_note_str()),
+                    });
                     folder_str.set(String::new());
                     folder_note_str.set(String::new());
                     folder_error_str.set(String::new());
@@ -66,11 +96,11 @@ pub(crate) fn AddFolderForm(
                         input { class: class!(outline min_w_52),
                             oninput: move |event| {
                                 folder_str.set(event.value());
-                                if folder_str().trim().len() != 0 {
+                                if !folder_str().trim().is_empty() {
                                     folder_error_str.set(String::new());
                                 }
                             },
-                            placeholder: "new folder name",
+                            placeholder: t(Msg::NewFolderName),
                             r#type: "text",
                             id: "folder",
                             value: "{folder_str}"
@@ -85,18 +115,40 @@ pub(crate) fn AddFolderForm(
                         r#for: "folder_note",
                         input { class: class!(outline min_w_52),
                             oninput: move |event| folder_note_str.set(event.value()),
-                            placeholder: "note",
+                            placeholder: t(Msg::FolderNote),
                             r#type: "text",
                             id: "folder_note",
                             value: "{folder_note_str}"
                         }
                     }
                 }
-                div { class: class!(row_span_3 self_center my_4),
+                div { class: class!(col_span_2),
+                    label {
+                        r#for: "folder_parent",
+                        select { class: class!(outline min_w_52),
+                            aria_label: t(Msg::ParentFolder),
+                            id: "folder_parent",
+                            onchange: move |event| parent_id.set(event.value().parse::<u32>().ok()),
+                            option {
+                                value: "",
+                                selected: parent_id().is_none(),
+                                {t(Msg::NoParentFolder)}
+                            }
+                            for folder in parent_folders().unwrap_or_default() {
+                                option {
+                                    value: "{folder.id.unwrap_or_default()}",
+                                    selected: folder.id == parent_id(),
+                                    "{folder.folder}"
+                                }
+                            }
+                        }
+                    }
+                }
+                div { class: class!(row_span_4 self_center my_4),
                     label {
-                        title: "Add folder",
+                        title: t(Msg::AddFolder),
                         button { class: class!(btn btn_sm btn_outline),
-                            "Add folder"
+                            {t(Msg::AddFolder)}
                         }
                     }
                 }
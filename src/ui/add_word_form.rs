@@ -20,9 +20,14 @@ use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
 use futures_util::StreamExt;
 
-use crate::model::Word;
+use crate::model::{DailyTotal, Word};
+use crate::storage::{StorageBackend, StorageErrorKind};
 use crate::storage_global::get_storage;
-use crate::ui::{BASE_URL, CURRENT_TAB_DATA, msg_folder_name_is_empty, msg_word_is_empty, openUrl};
+use crate::ui::error_message::storage_error_message;
+use crate::ui::goals::add_to_today;
+use crate::ui::navigation::{NAVIGATION_MESSAGE_NOTIFICATION, NavigationMessage, NavigationState};
+use crate::i18n::Msg;
+use crate::ui::{BASE_URL, CURRENT_TAB_DATA, openUrl, t, t_args};
 
 #[component]
 pub(crate) fn AddWordForm(
@@ -33,14 +38,41 @@ pub(crate) fn AddWordForm(
     word_error_str: Signal<String>,
     word_class_str: Signal<String>,
     note_str: Signal<String>,
+    examples_str: Signal<String>,
     refresh_words: Signal<u8>,
 ) -> Element {
-    let add_word = use_coroutine(move |mut rx| {
+    let navigation_message = use_coroutine_handle::<NavigationMessage>();
+    let mut duplicate_word = use_signal(|| None::<DuplicateWord>);
+
+    let mut clear_form = move || {
+        word_str.set(String::new());
+        word_class_str.set(String::new());
+        note_str.set(String::new());
+        examples_str.set(String::new());
+        selected_folder_error_str.set(String::new());
+        word_error_str.set(String::new());
+        duplicate_word.set(None);
+    };
+
+    let add_word = use_coroutine(move |mut rx: UnboundedReceiver<Word>| {
         to_owned![refresh_words];
         async move {
             while let Some(word) = rx.next().await {
-                let _ = get_storage().add::<Word>(&word).await;
-                refresh_words.toggle();
+                match get_storage().add::<Word>(&word).await {
+                    Ok(_) => {
+                        add_to_today(DailyTotal { words: 1, reviews: 0 }).await;
+                        clear_form();
+                        refresh_words.toggle();
+                    }
+                    Err(err) if err.kind() == StorageErrorKind::ConstraintViolation => {
+                        match get_storage().get_word_by_name(&word.folder, &word.word).await {
+                            Ok(Some(existing)) => duplicate_word.set(Some(DuplicateWord { existing, word })),
+                            Ok(None) => navigation_message.send(NavigationMessage::storage_error(&err)),
+                            Err(err) => navigation_message.send(NavigationMessage::storage_error(&err)),
+                        }
+                    }
+                    Err(err) => navigation_message.send(NavigationMessage::storage_error(&err)),
+                }
             }
         }
     });
@@ -51,26 +83,33 @@ pub(crate) fn AddWordForm(
         }
     });
 
-    let current_tab_data = use_memo(move || CURRENT_TAB_DATA());
+    let current_tab_data = use_memo(move || CURRENT_TAB_DATA.cloned());
     let show_url = current_tab_data().url;
     let show_url = match show_url.strip_prefix(BASE_URL) {
         None => show_url,
         Some(str) => str.to_string(),
     };
 
-    let mut clear_form = move || {
-        word_str.set(String::new());
-        word_class_str.set(String::new());
-        note_str.set(String::new());
-        selected_folder_error_str.set(String::new());
-        word_error_str.set(String::new());
+    let saved_words = use_resource(move || async move {
+        let _ = refresh_words();
+        get_storage()
+            .get_words_by_url(&current_tab_data().url)
+            .await
+    });
+
+    let saved_in_folders = match &*saved_words.read_unchecked() {
+        Some(Ok(words)) if !words.is_empty() => {
+            let folders: Vec<&str> = words.iter().map(|word| word.folder.as_str()).collect();
+            t_args(Msg::AlreadySavedInFolders, &[&folders.join(", ")])
+        }
+        _ => String::new(),
     };
 
     let mut on_form_submit = move || {
-        if selected_folder_str().trim().len() == 0 {
-            selected_folder_error_str.set(msg_folder_name_is_empty.to_string());
-        } else if word_str().trim().len() == 0 {
-            word_error_str.set(msg_word_is_empty.to_string());
+        if selected_folder_str().trim().is_empty() {
+            selected_folder_error_str.set(t(Msg::FolderNameIsEmpty).to_string());
+        } else if word_str().trim().is_empty() {
+            word_error_str.set(t(Msg::WordIsEmpty).to_string());
         } else {
             add_word.send(Word::new(
                 &selected_folder_str(),
@@ -78,8 +117,7 @@ pub(crate) fn AddWordForm(
                 &word_class_str(),
                 &current_tab_data().url,
                 &note_str(),
-            ));
-            clear_form();
+            ).with_examples(&examples_str()));
         }
     };
 
@@ -89,7 +127,7 @@ pub(crate) fn AddWordForm(
             onsubmit: move |event| event.stop_propagation(),
             div { class: class!(grid grid_cols_3 grid_flow_row_dense gap_1 mx_1 my_2 text_base),
                 div { class: class!(col_span_3),
-                    "Folder: "
+                    {t(Msg::FolderPath)}
                     span { class: class!(italic),
                         "{selected_folder_str}"
                     }
@@ -99,12 +137,31 @@ pub(crate) fn AddWordForm(
                 }
                 div { class: class!(col_span_3),
                     p { class: class!(text_sm),
-                        "URL to add:"
+                        {t(Msg::UrlToAdd)}
                     }
                     button { class: class!(text_xs text_blue_600 font_bold font_mono),
                         onclick: move |_| open_url.send(current_tab_data().url),
                         "{show_url}"
                     }
+                    p { class: class!(text_xs text_orange_500),
+                        "{saved_in_folders}"
+                    }
+                }
+                if let Some(duplicate) = duplicate_word() {
+                    div { class: class!(col_span_3),
+                        DuplicateWordMessage {
+                            duplicate,
+                            on_updated: move |_| {
+                                clear_form();
+                                refresh_words.toggle();
+                                navigation_message.send(NavigationMessage {
+                                    message: t(Msg::WordWasUpdated),
+                                    color: NAVIGATION_MESSAGE_NOTIFICATION,
+                                });
+                            },
+                            on_cancel: move |_| duplicate_word.set(None),
+                        }
+                    }
                 }
                 div { class: class!(col_span_2),
                     label {
@@ -112,11 +169,11 @@ pub(crate) fn AddWordForm(
                         input { class: class!(outline min_w_52),
                             oninput: move |event|  {
                                 word_str.set(event.value());
-                                if word_str().trim().len() != 0 {
+                                if !word_str().trim().is_empty() {
                                     word_error_str.set(String::new());
                                 }
                             },
-                            placeholder: "word or link name",
+                            placeholder: t(Msg::WordOrLinkName),
                             r#type: "text",
                             id: "word",
                             value: "{word_str}"
@@ -133,7 +190,7 @@ pub(crate) fn AddWordForm(
                         r#for: "word_class",
                         input { class: class!(outline min_w_52),
                             oninput: move |event| word_class_str.set(event.value()),
-                            placeholder: "word class",
+                            placeholder: t(Msg::WordClass),
                             r#type: "text",
                             id: "word_class",
                             value: "{word_class_str}"
@@ -143,36 +200,48 @@ pub(crate) fn AddWordForm(
                 div { class: class!(col_span_3 my_1),
                     label {
                         r#for: "note",
-                        input { class: class!(outline min_w_52),
+                        textarea { class: class!(outline min_w_52),
                             oninput: move |event| note_str.set(event.value()),
-                            placeholder: "note or pronunciation",
-                            r#type: "text",
+                            placeholder: t(Msg::WordNote),
+                            rows: "3",
                             id: "note",
                             value: "{note_str}"
                         }
                     }
                 }
+                div { class: class!(col_span_3 my_1),
+                    label {
+                        r#for: "examples",
+                        textarea { class: class!(outline min_w_52),
+                            oninput: move |event| examples_str.set(event.value()),
+                            placeholder: t(Msg::WordExamples),
+                            rows: "2",
+                            id: "examples",
+                            value: "{examples_str}"
+                        }
+                    }
+                }
                 div { class: class!(row_span_1 self_center),
                     label {
-                        title: "Add word",
+                        title: t(Msg::AddWord),
                         button { class: class!(btn btn_sm btn_outline),
                             onclick: move |event| {
                                 event.stop_propagation();
                                 on_form_submit();
                             },
-                            "Add word"
+                            {t(Msg::AddWord)}
                         }
                     }
                 }
                 div { class: class!(row_span_1 self_center),
                     label {
-                        title: "Clear",
+                        title: t(Msg::Clear),
                         button { class: class!(btn btn_sm btn_outline),
                             onclick: move |event| {
                                 event.stop_propagation();
                                 clear_form();
                             },
-                            "Clear"
+                            {t(Msg::Clear)}
                         }
                     }
                 }
@@ -180,3 +249,68 @@ pub(crate) fn AddWordForm(
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq)]
+struct DuplicateWord {
+    existing: Word,
+    word: Word,
+}
+
+#[component]
+fn DuplicateWordMessage(
+    duplicate: DuplicateWord,
+    on_updated: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let navigation = use_coroutine_handle::<NavigationState>();
+    let mut error_str = use_signal(String::new);
+
+    let existing = duplicate.existing.clone();
+    let id = existing.id.unwrap_or_default();
+
+    let update_word = move |_| {
+        let mut updated = duplicate.existing.clone();
+        updated.note = duplicate.word.note.clone();
+        updated.url = duplicate.word.url.clone();
+        let updated = updated.with_canonical_url();
+        async move {
+            match get_storage().update::<Word>(&updated).await {
+                Ok(_) => on_updated.call(()),
+                Err(err) => error_str.set(storage_error_message(&err).to_string()),
+            }
+        }
+    };
+
+    rsx! {
+        div { class: class!(text_xs),
+            span { class: class!(text_orange_500),
+                "{t_args(Msg::WordAlreadyInFolder, &[&existing.word])} "
+            }
+            button { class: class!(text_blue_600 font_bold),
+                onclick: move |event| {
+                    event.stop_propagation();
+                    navigation.send(NavigationState::WordDetail(id));
+                },
+                {t(Msg::Show)}
+            }
+            div {
+                margin_top: "5px",
+                button { class: class!(btn btn_xs btn_outline),
+                    onclick: update_word,
+                    {t(Msg::UpdateNoteAndUrl)}
+                }
+                button { class: class!(btn btn_xs btn_outline),
+                    margin_left: "5px",
+                    onclick: move |event| {
+                        event.stop_propagation();
+                        on_cancel.call(());
+                    },
+                    {t(Msg::Cancel)}
+                }
+            }
+            p { class: class!(text_red_500),
+                "{error_str}"
+            }
+        }
+    }
+}
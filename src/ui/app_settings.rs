@@ -0,0 +1,126 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+
+use dioxus::prelude::*;
+use dioxus_sdk::storage::{LocalStorage, StorageBacking};
+use log::warn;
+use serde::de::DeserializeOwned;
+
+use crate::i18n::Locale;
+use crate::model::Settings;
+use crate::storage::{StorageBackend, StorageError};
+use crate::storage_global::get_storage;
+use crate::ui::setTheme;
+
+/// Provides the settings to all components, they are loaded once and saved after every change.
+/// Returns the error if the settings can't be loaded, then nothing is saved,
+/// so the defaults don't overwrite the stored settings
+pub(crate) fn use_settings_provider() -> Signal<Option<StorageError>> {
+    let mut settings = use_context_provider(|| Signal::new(Settings::default()));
+    let mut loaded = use_signal(|| false);
+    let mut load_error = use_signal(|| None);
+
+    let locale = use_memo(move || settings().locale.unwrap_or_else(Locale::browser));
+    use_context_provider(|| locale);
+
+    let theme = use_memo(move || settings().theme);
+    use_effect(move || setTheme(theme().data_theme().map(str::to_string)));
+
+    let _load_settings = use_resource(move || async move {
+        let stored = match get_storage().load_settings().await {
+            Ok(stored) => stored,
+            Err(err) => {
+                warn!("can't load settings: {}", err);
+                load_error.set(Some(err));
+                return;
+            }
+        };
+        let stored = match stored.version {
+            0 => with_legacy_local_storage(stored),
+            _ => stored,
+        };
+        settings.set(stored.migrate());
+        loaded.set(true);
+    });
+
+    use_effect(move || {
+        let settings = settings();
+        if loaded() {
+            spawn(async move {
+                if let Err(err) = get_storage().save_settings(&settings).await {
+                    warn!("can't save settings: {}", err);
+                }
+            });
+        }
+    });
+
+    load_error
+}
+
+pub(crate) fn use_settings() -> Signal<Settings> {
+    use_context::<Signal<Settings>>()
+}
+
+/// A signal bound to one field of the settings, for the components that take the value as a prop
+pub(crate) fn use_setting<T>(get: fn(&Settings) -> T, set: fn(&mut Settings, T)) -> Signal<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    let mut settings = use_settings();
+    let mut value = use_signal(|| get(&settings.peek()));
+
+    // the settings are loaded or imported
+    use_effect(move || {
+        let new_value = get(&settings.read());
+        if *value.peek() != new_value {
+            value.set(new_value);
+        }
+    });
+
+    // the value is changed by a component
+    use_effect(move || {
+        let new_value = value();
+        if get(&settings.peek()) != new_value {
+            set(&mut settings.write(), new_value);
+        }
+    });
+
+    value
+}
+
+/// The settings were kept in separate LocalStorage keys before they were moved to the database
+fn with_legacy_local_storage(settings: Settings) -> Settings {
+    fn get<T: DeserializeOwned + Clone + 'static>(key: &str) -> Option<T> {
+        LocalStorage::get(&key.to_string())
+    }
+
+    Settings {
+        autoplay: get("autoplay").unwrap_or(settings.autoplay),
+        folders_page_length: get("folders_page_length").unwrap_or(settings.folders_page_length),
+        words_page_length: get("page_length").unwrap_or(settings.words_page_length),
+        show_add_folder_form: get::<u8>("show_add_folder_form")
+            .map_or(settings.show_add_folder_form, |value| value != 0),
+        show_add_word_form: get::<u8>("show_add_word_form")
+            .map_or(settings.show_add_word_form, |value| value != 0),
+        selected_folder: get("selected_folder").unwrap_or(settings.selected_folder),
+        show_use_keyboard_message: get("show_use_keyboard_message")
+            .unwrap_or(settings.show_use_keyboard_message),
+        folders_sort: get("folders_sort").unwrap_or(settings.folders_sort),
+        show_archived_folders: get("show_archived_folders").unwrap_or(settings.show_archived_folders),
+        ..settings
+    }
+}
@@ -0,0 +1,155 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_daisyui::prelude::*;
+
+use crate::i18n::Msg;
+use crate::storage::CorruptRecord;
+use crate::storage_global::get_storage;
+use crate::ui::{t, t_args};
+use crate::ui::error_message::{storage_error_message, StorageErrorMessage};
+
+#[component]
+pub(crate) fn CheckDatabase() -> Element {
+    let mut check_requested = use_signal(|| false);
+    let mut message_str = use_signal(String::new);
+
+    let mut corrupt_records = use_resource(move || async move {
+        match check_requested() {
+            true => Some(get_storage().check_database().await),
+            false => None,
+        }
+    });
+
+    let mut quarantined_count = use_resource(move || async move {
+        get_storage().quarantined_count().await
+    });
+
+    let mut refresh = move || {
+        corrupt_records.restart();
+        quarantined_count.restart();
+    };
+
+    rsx! {
+        div {
+            button { class: class!(btn btn_sm),
+                margin_top: "5px",
+                onclick: move |_| {
+                    message_str.set(String::new());
+                    check_requested.set(true);
+                    refresh();
+                },
+                {t(Msg::CheckDatabase)}
+            }
+            match &*quarantined_count.read_unchecked() {
+                Some(Ok(count)) if *count > 0 => rsx! {
+                    p { class: class!(text_xs),
+                        {t_args(Msg::QuarantinedRecords, &[&count.to_string()])}
+                    }
+                },
+                Some(Err(err)) => rsx! {
+                    StorageErrorMessage {
+                        error: err.clone()
+                    }
+                },
+                _ => VNode::empty(),
+            }
+            p { class: class!(text_xs text_red_500),
+                "{message_str}"
+            }
+            match &*corrupt_records.read_unchecked() {
+                Some(Some(Ok(records))) if records.is_empty() => rsx! {
+                    p { class: class!(text_xs),
+                        {t(Msg::NoCorruptedRecords)}
+                    }
+                },
+                Some(Some(Ok(records))) => rsx! {
+                    p { class: class!(text_xs),
+                        {t_args(Msg::CorruptedRecords, &[&records.len().to_string()])}
+                    }
+                    p { class: class!(text_xs),
+                        {t(Msg::QuarantineHint)}
+                    }
+                    for record in records.iter().cloned() {
+                        ShowCorruptRecord {
+                            record,
+                            on_done: move |result: Result<(), String>| {
+                                match result {
+                                    Ok(_) => message_str.set(String::new()),
+                                    Err(err) => message_str.set(err),
+                                }
+                                refresh();
+                            },
+                        }
+                    }
+                },
+                Some(Some(Err(err))) => rsx! {
+                    StorageErrorMessage {
+                        error: err.clone()
+                    }
+                },
+                _ => VNode::empty(),
+            }
+        }
+    }
+}
+
+#[component]
+fn ShowCorruptRecord(record: CorruptRecord, on_done: EventHandler<Result<(), String>>) -> Element {
+    let repair_record = record.clone();
+    let quarantine_record = record.clone();
+
+    rsx! {
+        div { class: class!(text_xs),
+            margin_top: "5px",
+            p { class: class!(font_bold),
+                "{record.store} #{record.key()}"
+            }
+            p { class: class!(text_red_500),
+                "{record.error}"
+            }
+            p { class: class!(break_all),
+                "{record.value}"
+            }
+            if record.repaired.is_some() {
+                button { class: class!(btn btn_xs btn_outline),
+                    onclick: move |_| {
+                        let record = repair_record.clone();
+                        async move {
+                            let result = get_storage().repair_record(&record).await;
+                            on_done.call(result.map_err(|err| storage_error_message(&err).to_string()));
+                        }
+                    },
+                    {t(Msg::Repair)}
+                }
+            }
+            button { class: class!(btn btn_xs btn_outline),
+                margin_left: "5px",
+                onclick: move |_| {
+                    let record = quarantine_record.clone();
+                    async move {
+                        let result = get_storage().quarantine_record(&record).await;
+                        on_done.call(result.map_err(|err| storage_error_message(&err).to_string()));
+                    }
+                },
+                {t(Msg::Quarantine)}
+            }
+        }
+    }
+}
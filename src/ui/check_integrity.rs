@@ -0,0 +1,188 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+use dioxus::prelude::*;
+use dioxus_daisyui::prelude::*;
+
+use crate::i18n::Msg;
+use crate::storage::{IntegrityFix, IntegrityReport};
+use crate::storage_global::get_storage;
+use crate::ui::{t, t_args};
+use crate::ui::error_message::{storage_error_message, ErrorMessage};
+
+/// The values of the options for the words without a folder
+const orphans_keep: &str = "keep";
+const orphans_create_folders: &str = "create_folders";
+const orphans_delete_words: &str = "delete_words";
+
+const orphans_options: [(&str, Msg); 3] = [
+    (orphans_keep, Msg::OrphansKeep),
+    (orphans_create_folders, Msg::OrphansCreateFolders),
+    (orphans_delete_words, Msg::OrphansDeleteWords),
+];
+
+#[component]
+pub(crate) fn CheckIntegrity() -> Element {
+    let mut report = use_signal(|| None::<IntegrityReport>);
+    let mut error_str = use_signal(String::new);
+    let mut message_str = use_signal(String::new);
+
+    let mut fix_ids = use_signal(|| true);
+    let mut rename_folders = use_signal(|| true);
+    let mut orphans_fix = use_signal(|| None::<IntegrityFix>);
+
+    let check = move || async move {
+        error_str.set(String::new());
+        match get_storage().check_integrity().await {
+            Ok(result) => report.set(Some(result)),
+            Err(err) => {
+                report.set(None);
+                error_str.set(storage_error_message(&err).to_string());
+            }
+        }
+    };
+
+    let repair = move |_| async move {
+        let Some(current_report) = report() else {
+            return;
+        };
+        let mut fixes = vec![];
+        if fix_ids() {
+            fixes.push(IntegrityFix::FixIds);
+        }
+        if rename_folders() {
+            fixes.push(IntegrityFix::RenameMismatchedFolders);
+        }
+        if let Some(fix) = orphans_fix() {
+            fixes.push(fix);
+        }
+        match get_storage().repair(&current_report, &fixes).await {
+            Ok(count) => message_str.set(t_args(Msg::RecordsChanged, &[&count.to_string()])),
+            Err(err) => message_str.set(storage_error_message(&err).to_string()),
+        }
+        check().await;
+    };
+
+    rsx! {
+        div {
+            button { class: class!(btn btn_sm),
+                margin_top: "5px",
+                onclick: move |_| async move {
+                    message_str.set(String::new());
+                    check().await;
+                },
+                {t(Msg::CheckIntegrity)}
+            }
+            p { class: class!(text_xs),
+                "{message_str}"
+            }
+            if !error_str().is_empty() {
+                ErrorMessage {
+                    message: error_str()
+                }
+            }
+            if let Some(report) = report() {
+                if report.is_empty() {
+                    p { class: class!(text_xs),
+                        {t(Msg::NoIntegrityProblems)}
+                    }
+                } else {
+                    form { class: class!(text_xs),
+                        action: "",
+                        onsubmit: move |event| event.stop_propagation(),
+                        if !report.wrong_ids.is_empty() {
+                            div {
+                                margin_top: "5px",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: fix_ids(),
+                                    onchange: move |event| fix_ids.set(event.checked()),
+                                }
+                                " {t_args(Msg::WrongIds, &[&report.wrong_ids.len().to_string()])}"
+                            }
+                        }
+                        if !report.mismatched_folders.is_empty() {
+                            div {
+                                margin_top: "5px",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: rename_folders(),
+                                    onchange: move |event| rename_folders.set(event.checked()),
+                                }
+                                " {t_args(Msg::MismatchedFolders, &[&report.mismatched_folders.len().to_string()])}"
+                                ShowConflicts { report: report.clone() }
+                            }
+                        }
+                        if !report.orphan_words.is_empty() {
+                            div {
+                                margin_top: "5px",
+                                p { {t_args(Msg::OrphanWords, &[&report.orphan_words.len().to_string()])} }
+                                ShowMissingFolders { report: report.clone() }
+                                select { class: class!(outline),
+                                    onchange: move |event| {
+                                        orphans_fix.set(match event.value().as_str() {
+                                            orphans_create_folders => Some(IntegrityFix::CreateMissingFolders),
+                                            orphans_delete_words => Some(IntegrityFix::DeleteOrphanWords),
+                                            _ => None,
+                                        });
+                                    },
+                                    for (option_str, option_msg) in orphans_options {
+                                        option {
+                                            value: "{option_str}",
+                                            {t(option_msg)}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        button { class: class!(btn btn_sm btn_outline),
+                            margin_top: "5px",
+                            onclick: repair,
+                            {t(Msg::Repair)}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ShowConflicts(report: IntegrityReport) -> Element {
+    let conflicts = report.mismatched_folders.iter().filter(|x| x.conflict).count();
+    if conflicts == 0 {
+        return VNode::empty();
+    }
+    rsx! {
+        p { class: class!(text_orange_500),
+            {t_args(Msg::Conflicts, &[&conflicts.to_string()])}
+        }
+    }
+}
+
+#[component]
+fn ShowMissingFolders(report: IntegrityReport) -> Element {
+    let folders = report.missing_folders().into_iter().collect::<Vec<String>>();
+    if folders.is_empty() {
+        return VNode::empty();
+    }
+    rsx! {
+        p { {t_args(Msg::MissingFolders, &[&folders.join(", ")])} }
+    }
+}
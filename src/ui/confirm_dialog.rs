@@ -0,0 +1,126 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_daisyui::prelude::*;
+
+use crate::i18n::Msg;
+use crate::model::{DataProtection, ProtectedAction};
+use crate::ui::{focusElement, t, t_args};
+
+/// A destructive action, it's sent to the coroutine of `Navigation` once it's confirmed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DeleteRequest {
+    Word(u32),
+    Folder(u32),
+    SmartFolder(u32),
+}
+
+impl DeleteRequest {
+    pub(crate) fn action(&self) -> ProtectedAction {
+        match self {
+            DeleteRequest::Word(_) => ProtectedAction::DeleteWord,
+            DeleteRequest::Folder(_) => ProtectedAction::DeleteFolder,
+            DeleteRequest::SmartFolder(_) => ProtectedAction::DeleteSmartFolder,
+        }
+    }
+}
+
+/// A destructive action waiting for the user
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Confirmation {
+    pub(crate) request: DeleteRequest,
+    pub(crate) protection: DataProtection,
+    /// What will be deleted
+    pub(crate) description: String,
+    /// The name to type with `DataProtection::TypeName`
+    pub(crate) name: String,
+}
+
+#[component]
+pub(crate) fn ConfirmDialog(confirmation: Signal<Option<Confirmation>>) -> Element {
+    let delete = use_coroutine_handle::<DeleteRequest>();
+    let mut name_str = use_signal(String::new);
+
+    use_effect(move || {
+        if let Some(confirmation) = confirmation() {
+            focusElement(match confirmation.protection {
+                DataProtection::TypeName => "confirm-name".to_string(),
+                _ => "confirm-cancel".to_string(),
+            });
+        }
+    });
+
+    let mut close = move || {
+        name_str.set(String::new());
+        confirmation.set(None);
+    };
+
+    let Some(Confirmation { request, protection, description, name }) = confirmation() else {
+        return rsx! {};
+    };
+    let type_name = protection == DataProtection::TypeName;
+    let confirmed = !type_name || name_str().trim() == name.trim();
+
+    rsx! {
+        div { class: class!(modal modal_open),
+            role: "alertdialog",
+            aria_modal: "true",
+            aria_describedby: "confirm-description",
+            // the shortcuts of the lists don't work while the dialog is open
+            onkeydown: move |event| {
+                event.stop_propagation();
+                if event.key().to_string() == "Escape" {
+                    close();
+                }
+            },
+            div { class: class!(modal_box text_sm),
+                p { id: "confirm-description",
+                    "{description}"
+                }
+                if type_name {
+                    label { class: class!(flex flex_col gap_1),
+                        margin_top: "10px",
+                        {t_args(Msg::TypeNameToConfirm, &[&name])}
+                        input { class: class!(outline),
+                            id: "confirm-name",
+                            r#type: "text",
+                            value: "{name_str}",
+                            oninput: move |event| name_str.set(event.value()),
+                        }
+                    }
+                }
+                div { class: class!(modal_action),
+                    button { class: class!(btn btn_sm),
+                        id: "confirm-cancel",
+                        onclick: move |_| close(),
+                        {t(Msg::Cancel)}
+                    }
+                    button { class: class!(btn btn_sm btn_error),
+                        disabled: !confirmed,
+                        onclick: move |_| {
+                            delete.send(request);
+                            close();
+                        },
+                        {t(Msg::Delete)}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -19,6 +19,14 @@
 use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
 
+use crate::storage::StorageError;
+use crate::ui::current_locale;
+
+/// The message for the user in the language of the user interface
+pub(crate) fn storage_error_message(err: &StorageError) -> &'static str {
+    crate::storage::storage_error_message(current_locale(), err)
+}
+
 #[component]
 pub(crate) fn ErrorMessage(message: String) -> Element {
     rsx! {
@@ -27,3 +35,12 @@ pub(crate) fn ErrorMessage(message: String) -> Element {
         }
     }
 }
+
+#[component]
+pub(crate) fn StorageErrorMessage(error: StorageError) -> Element {
+    rsx! {
+        ErrorMessage {
+            message: storage_error_message(&error).to_string()
+        }
+    }
+}
@@ -18,14 +18,17 @@
 
 use dioxus::prelude::*;
 use gloo_utils::format::JsValueSerdeExt;
-use log::debug;
 use wasm_bindgen::JsValue;
 use web_sys::{console, File, FilePropertyBag, Url};
 
+use crate::storage::StorageBackend;
 use crate::storage_global::get_storage;
-use crate::ui::error_message::ErrorMessage;
+use crate::ui::error_message::{ErrorMessage, StorageErrorMessage};
 use crate::ui::navigation::NavigationState;
-use crate::ui::{startDownload, EXPORT_FILE_NAME, EXPORT_FILE_TYPE, js_value_to_string};
+use crate::ui::{
+    js_value_to_string, openTab, startDownload, EXPORT_FILE_NAME, EXPORT_FILE_TYPE, PRINT_FILE_NAME,
+    PRINT_FILE_TYPE,
+};
 
 #[component]
 pub(crate) fn ExportData() -> Element {
@@ -43,10 +46,9 @@ pub(crate) fn ExportData() -> Element {
             }
         }
         Some(Err(err)) => {
-            debug!("Err: {}", err);
             rsx! {
-                ErrorMessage {
-                    message: err.to_string()
+                StorageErrorMessage {
+                    error: err.clone()
                 }
             }
         }
@@ -74,24 +76,37 @@ pub(crate) fn StartDownload(data: String) -> Element {
     }
 }
 
-fn open_download_window(data: &str) -> Result<(), JsValue> {
+pub(crate) fn open_download_window(data: &str) -> Result<(), JsValue> {
+    let url = file_url(data, EXPORT_FILE_NAME, EXPORT_FILE_TYPE)?;
+
+    startDownload(url, EXPORT_FILE_NAME.to_string());
+
+    // FIXME Url::revoke_object_url(&url);
+
+    Ok(())
+}
+
+pub(crate) fn open_print_window(html: &str) -> Result<(), JsValue> {
+    let url = file_url(html, PRINT_FILE_NAME, PRINT_FILE_TYPE)?;
+
+    // the tab loads the page later, so the url can't be revoked here
+    openTab(url);
+
+    Ok(())
+}
+
+fn file_url(data: &str, file_name: &str, file_type: &str) -> Result<String, JsValue> {
     let js_value = JsValue::from_serde(&vec![data])
         .map_err(|err| err.to_string())?;   // FIXME to_string() ?
 
-    let file_type = FilePropertyBag::new();
-    file_type.set_type(EXPORT_FILE_TYPE);
+    let file_property_bag = FilePropertyBag::new();
+    file_property_bag.set_type(file_type);
 
     let file = File::new_with_str_sequence_and_options(
         &js_value,
-        EXPORT_FILE_NAME, // this name isn't used, look at file_type
-        &file_type,
+        file_name, // this name isn't used, look at file_type
+        &file_property_bag,
     )?;
 
-    let url = Url::create_object_url_with_blob(&file)?;
-
-    startDownload(url, EXPORT_FILE_NAME.to_string());
-
-    // FIXME Url::revoke_object_url(&url);
-
-    Ok(())
+    Url::create_object_url_with_blob(&file)
 }
@@ -0,0 +1,65 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_daisyui::prelude::*;
+
+use crate::i18n::Msg;
+use crate::storage::StorageBackend;
+use crate::storage_global::get_storage;
+use crate::ui::list_selection::WORDS_SELECTION;
+use crate::ui::t;
+
+/// The path from the root folder to the selected one, the parent folders can be selected
+#[component]
+pub(crate) fn FolderBreadcrumbs(
+    selected_folder_str: Signal<String>,
+    offset: Signal<Option<u32>>,
+) -> Element {
+    let folder_path = use_resource(move || async move {
+        get_storage().get_folder_path(&selected_folder_str()).await
+    });
+
+    let (parents, name) = match &*folder_path.read_unchecked() {
+        Some(Ok(path)) if !path.is_empty() => {
+            (path[..path.len() - 1].to_vec(), path[path.len() - 1].name().to_string())
+        }
+        _ => (vec![], selected_folder_str()),
+    };
+
+    rsx! {
+        div { class: class!(text_base),
+            margin_top: "5px",
+            {t(Msg::FolderPath)}
+            for parent in parents {
+                button { class: class!(underline),
+                    onclick: move |_| {
+                        offset.set(None);
+                        WORDS_SELECTION.clear();
+                        selected_folder_str.set(parent.folder.to_owned());
+                    },
+                    "{parent.name()}"
+                }
+                " / "
+            }
+            span { class: class!(italic),
+                "{name}"
+            }
+        }
+    }
+}
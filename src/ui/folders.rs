@@ -15,16 +15,44 @@
  */
 
 #![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+use std::collections::HashSet;
 
 use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
-use log::debug;
 
+use crate::i18n::Msg;
+use crate::model::{FolderKey, FolderSort, KeyAction};
 use crate::storage_global::get_storage;
 use crate::ui::add_folder_form::AddFolderForm;
-use crate::ui::error_message::ErrorMessage;
-use crate::ui::pager::{Pager, PagerMode};
-use crate::ui::show_folder::ShowFolder;
+use crate::ui::app_settings::{use_setting, use_settings};
+use crate::ui::error_message::StorageErrorMessage;
+use crate::ui::keymap::key_action;
+use crate::ui::list_selection::FOLDERS_SELECTION;
+use crate::ui::pager::{page_left, page_right, Pager, PagerMode};
+use crate::ui::show_folder::{open_folder, ShowFolder};
+use crate::ui::smart_folders::SmartFolders;
+use crate::ui::t;
+
+const folder_sort_options: [(FolderSort, Msg); 4] = [
+    (FolderSort::Name, Msg::SortByName),
+    (FolderSort::Created, Msg::SortByCreationDate),
+    (FolderSort::Modified, Msg::SortByLastAddedWord),
+    (FolderSort::WordCount, Msg::SortByWordCount),
+];
+
+/// The state shared by all levels of the folder tree
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FolderTree {
+    pub(crate) expanded_folders: Signal<HashSet<u32>>,
+    pub(crate) sort: FolderSort,
+    pub(crate) include_archived: bool,
+    pub(crate) direction: String,
+    pub(crate) refresh_folders: Signal<u8>,
+    /// The tree isn't shown for the search results
+    pub(crate) enabled: bool,
+}
 
 #[component]
 pub(crate) fn Folders(
@@ -36,15 +64,41 @@ pub(crate) fn Folders(
     offset: Signal<Option<u32>>,
     direction: Signal<String>,
     refresh_folders: Signal<u8>,
-    show_add_folder_form: Signal<u8>,
+    show_add_folder_form: Signal<bool>,
     words_page_offset: Signal<Option<u32>>,
 ) -> Element {
     // debug!("Folders: {} {}", refresh_folders.peek(), show_add_folder_form.peek());
 
+    let mut filter_str = use_signal(String::new);
+    let mut folder_sort = use_setting(|s| s.folders_sort, |s, v| s.folders_sort = v);
+    let mut include_archived = use_setting(
+        |s| s.show_archived_folders, |s, v| s.show_archived_folders = v);
+
+    let expanded_folders = use_signal(HashSet::<u32>::new);
+    let settings = use_settings();
+    let folder_key = use_coroutine_handle::<FolderKey>();
+
+    let tree = use_memo(move || FolderTree {
+        expanded_folders,
+        sort: folder_sort(),
+        include_archived: include_archived(),
+        direction: direction(),
+        refresh_folders,
+        enabled: filter_str().trim().is_empty(),
+    });
+
     let folders = use_resource(move || async move {
         let _ = refresh_folders();
         get_storage()
-            .get_folders(page_length(), offset(), direction())
+            .get_folder_stats(
+                filter_str(),
+                None,
+                folder_sort(),
+                include_archived(),
+                page_length(),
+                offset(),
+                direction(),
+            )
             .await
     });
 
@@ -54,33 +108,38 @@ pub(crate) fn Folders(
             (rsx! {}, 0u32)
         }
         Some(Err(err)) => {
-            debug!("Err: {}", err);
             (
                 rsx! {
-                    ErrorMessage {
-                        message: err.to_string()
+                    StorageErrorMessage {
+                        error: err.clone()
                     }
                 },
                 0u32,
             )
         }
-        Some(Ok(result)) => (
-            rsx! {
-                for (index, folder) in result.folders.iter().enumerate() {
-                    ShowFolder {
-                        index: index as i32,
-                        folder: folder.to_owned(),
-                        selected_folder_str,
-                        words_page_offset,
+        Some(Ok(result)) => {
+            FOLDERS_SELECTION.set_len(result.folders.len());
+            (
+                rsx! {
+                    for (index, folder_stats) in result.folders.iter().enumerate() {
+                        ShowFolder {
+                            key: "{folder_stats.folder.id:?}",
+                            index: index as i32,
+                            folder_stats: folder_stats.to_owned(),
+                            depth: 0,
+                            tree: tree(),
+                            selected_folder_str,
+                            words_page_offset,
+                        }
                     }
-                }
-            },
-            result.count,
-        ),
+                },
+                result.count,
+            )
+        },
     };
 
     rsx! {
-        if show_add_folder_form() != 0u8 {
+        if show_add_folder_form() {
             AddFolderForm {
                 folder_str: folder_str,
                 folder_note_str: folder_note_str,
@@ -90,9 +149,84 @@ pub(crate) fn Folders(
         }
         div { class: class!(text_lg),
             margin_top: "5px",
+            onkeydown: move |event| {
+                let Some(action) = key_action(&event, &settings.peek()) else {
+                    return;
+                };
+                let selected = match (&*folders.peek(), *FOLDERS_SELECTION.index.peek()) {
+                    (Some(Ok(result)), Some(index)) => result.folders
+                        .get(index as usize)
+                        .map(|folder_stats| folder_stats.folder.clone()),
+                    _ => None,
+                };
+                match (action, selected) {
+                    (KeyAction::NextItem, _) => FOLDERS_SELECTION.next(),
+                    (KeyAction::PreviousItem, _) => FOLDERS_SELECTION.previous(),
+                    (KeyAction::PageLeft, _) => page_left(PagerMode::Folders, page_length, offset),
+                    (KeyAction::PageRight, _) =>
+                        page_right(PagerMode::Folders, count, page_length, offset, true),
+                    (KeyAction::Open, Some(folder)) =>
+                        open_folder(&folder.folder, selected_folder_str, words_page_offset),
+                    (KeyAction::Delete, Some(folder)) => {
+                        let Some(id) = folder.id else { return };
+                        folder_key.send(FolderKey { id });
+                    }
+                    _ => return,
+                }
+                event.prevent_default();
+                event.stop_propagation();
+            },
             div { class: class!(hidden),
                 "{refresh_folders}"
             }
+            SmartFolders {
+                refresh_folders,
+                words_page_offset,
+            }
+            div { class: class!(flex flex_wrap items_center gap_2 text_sm mx_1 my_2),
+                label {
+                    r#for: "filter-folders",
+                    input { class: class!(outline min_w_52),
+                        oninput: move |event| {
+                            filter_str.set(event.value());
+                            offset.set(None);
+                            FOLDERS_SELECTION.clear();
+                        },
+                        placeholder: t(Msg::FilterFolders),
+                        r#type: "text",
+                        id: "filter-folders",
+                        value: "{filter_str}"
+                    }
+                }
+                select { class: class!(outline),
+                    aria_label: t(Msg::SortFolders),
+                    onchange: move |event| {
+                        if let Some((sort, _)) = folder_sort_options.iter()
+                            .find(|(sort, _)| format!("{:?}", sort) == event.value()) {
+                            folder_sort.set(*sort);
+                            offset.set(None);
+                        }
+                    },
+                    for (sort, sort_label) in folder_sort_options {
+                        option {
+                            value: "{sort:?}",
+                            selected: sort == folder_sort(),
+                            {t(sort_label)}
+                        }
+                    }
+                }
+                label {
+                    input {
+                        r#type: "checkbox",
+                        checked: include_archived(),
+                        onchange: move |event| {
+                            include_archived.set(event.checked());
+                            offset.set(None);
+                        },
+                    }
+                    " {t(Msg::ShowArchived)}"
+                }
+            }
             Pager {
                 mode: PagerMode::Folders,
                 page_length: page_length,
@@ -101,8 +235,52 @@ pub(crate) fn Folders(
                 count: count,
             }
             div { class: "main-content",
+                id: "folders-list",
+                tabindex: "0",
+                role: "listbox",
+                aria_label: t(Msg::Folders),
+                aria_activedescendant: FOLDERS_SELECTION.active_descendant(),
                 {folders_to_show}
             }
         }
     }
 }
+
+#[component]
+pub(crate) fn SubFolders(
+    parent_id: u32,
+    depth: u32,
+    tree: ReadOnlySignal<FolderTree>,
+    selected_folder_str: Signal<String>,
+    words_page_offset: Signal<Option<u32>>,
+) -> Element {
+    let folders = use_resource(move || async move {
+        let FolderTree { sort, include_archived, direction, refresh_folders, .. } = tree();
+        let _ = refresh_folders();
+        get_storage()
+            .get_folder_stats(String::new(), Some(parent_id), sort, include_archived, None, None, direction)
+            .await
+    });
+
+    match &*folders.read_unchecked() {
+        None => VNode::empty(),
+        Some(Err(err)) => rsx! {
+            StorageErrorMessage {
+                error: err.clone()
+            }
+        },
+        Some(Ok(result)) => rsx! {
+            for (index, folder_stats) in result.folders.iter().enumerate() {
+                ShowFolder {
+                    key: "{folder_stats.folder.id:?}",
+                    index: index as i32,
+                    folder_stats: folder_stats.to_owned(),
+                    depth,
+                    tree,
+                    selected_folder_str,
+                    words_page_offset,
+                }
+            }
+        },
+    }
+}
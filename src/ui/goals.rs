@@ -0,0 +1,140 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![allow(non_snake_case)]
+
+use chrono::Utc;
+use dioxus::prelude::*;
+use dioxus_daisyui::prelude::*;
+use log::warn;
+
+use crate::i18n::Msg;
+use crate::model::{DailyGoal, DailyTotal};
+use crate::stats::{goal_streak, local_day, local_offset_seconds};
+use crate::storage::StorageBackend;
+use crate::storage_global::get_storage;
+use crate::ui::app_settings::use_settings;
+use crate::ui::{t, t_args};
+
+/// Counts new words and reviews done now for the daily goal, an error doesn't undo them, so it's only logged
+pub(crate) async fn add_to_today(added: DailyTotal) {
+    let result = get_storage()
+        .add_to_daily_total(local_offset_seconds(), Utc::now().timestamp_millis(), added)
+        .await;
+    if let Err(err) = result {
+        warn!("can't save the daily total: {}", err);
+    }
+}
+
+/// The progress of today and the streak in the header, shown when a goal is set
+#[component]
+pub(crate) fn DailyProgress(refresh_words: Signal<u8>) -> Element {
+    let settings = use_settings();
+
+    let totals = use_resource(move || async move {
+        let _ = refresh_words();
+        get_storage().get_daily_totals(local_offset_seconds()).await
+    });
+
+    let goal = settings().daily_goal;
+    let Some(Ok(totals)) = &*totals.read_unchecked() else {
+        return VNode::empty();
+    };
+    if !goal.is_set() {
+        return VNode::empty();
+    }
+
+    let today = local_day(Utc::now().timestamp_millis(), local_offset_seconds());
+    let total = totals.get(&today).copied().unwrap_or_default();
+    let streak = goal_streak(totals, &goal, today);
+
+    rsx! {
+        div { class: class!(flex flex_row flex_wrap items_center gap_4 text_xs pt_2),
+            if goal.words > 0 {
+                GoalProgress {
+                    title: t_args(Msg::GoalWordsProgress, &[&total.words.to_string(), &goal.words.to_string()]),
+                    value: total.words,
+                    max: goal.words,
+                }
+            }
+            if goal.reviews > 0 {
+                GoalProgress {
+                    title: t_args(Msg::GoalReviewsProgress, &[&total.reviews.to_string(), &goal.reviews.to_string()]),
+                    value: total.reviews,
+                    max: goal.reviews,
+                }
+            }
+            span { {t_args(Msg::GoalStreak, &[&streak.to_string()])} }
+        }
+    }
+}
+
+#[component]
+fn GoalProgress(title: String, value: u32, max: u32) -> Element {
+    rsx! {
+        label { class: class!(flex flex_row items_center gap_1),
+            "{title}"
+            progress { class: class!(progress progress_primary w_16),
+                value: "{value.min(max)}",
+                max: "{max}",
+            }
+        }
+    }
+}
+
+/// The goals in the settings
+#[component]
+pub(crate) fn DailyGoalForm() -> Element {
+    let mut settings = use_settings();
+
+    let mut set_goal = move |value: String, set: fn(&mut DailyGoal, u32)| {
+        let value = value.trim().parse().unwrap_or(0);
+        set(&mut settings.write().daily_goal, value);
+    };
+
+    rsx! {
+        table { class: class!(text_sm),
+            margin_top: "5px",
+            tr {
+                td { class: class!(pr_4), {t(Msg::DailyGoalWords)} }
+                td {
+                    input { class: class!(outline w_16),
+                        r#type: "number",
+                        min: "0",
+                        aria_label: t(Msg::DailyGoalWords),
+                        value: "{settings().daily_goal.words}",
+                        onchange: move |event| set_goal(event.value(), |goal, value| goal.words = value),
+                    }
+                }
+            }
+            tr {
+                td { class: class!(pr_4), {t(Msg::DailyGoalReviews)} }
+                td {
+                    input { class: class!(outline w_16),
+                        r#type: "number",
+                        min: "0",
+                        aria_label: t(Msg::DailyGoalReviews),
+                        value: "{settings().daily_goal.reviews}",
+                        onchange: move |event| set_goal(event.value(), |goal, value| goal.reviews = value),
+                    }
+                }
+            }
+        }
+        p { class: class!(text_xs),
+            {t(Msg::DailyGoalHint)}
+        }
+    }
+}
@@ -19,24 +19,30 @@
 use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
 
+use crate::storage::StorageBackend;
+use crate::model::Settings;
 use crate::storage_global::get_storage;
-use crate::ui::msg_data_successfully_imported;
+use crate::ui::app_settings::use_settings;
+use crate::ui::error_message::storage_error_message;
+use crate::i18n::Msg;
+use crate::ui::t;
 
 #[component]
 pub(crate) fn ImportData() -> Element {
-    let message_str = use_signal(|| String::new());
+    let message_str = use_signal(String::new);
+    let settings = use_settings();
 
     rsx! {
         div { class: class!(text_base),
             margin_top: "5px",
-            "Import"
+            {t(Msg::Import)}
             div {
                 margin_top: "5px",
                 input {
                     r#type: "file",
                     accept: ".json",
                     onchange: move |event| async move {
-                        import(message_str, event).await;
+                        import(message_str, settings, event).await;
                     }
                 }
             }
@@ -50,6 +56,7 @@ pub(crate) fn ImportData() -> Element {
 
 async fn import(
     mut message_str: Signal<String>,
+    mut settings: Signal<Settings>,
     event: Event<FormData>,
 ) {
     if let Some(file_engine) = event.files() {
@@ -58,8 +65,13 @@ async fn import(
             if let Some(file) = file_engine.read_file_to_string(file_name).await {
                 let result = get_storage().import_data(file).await;
                 match result {
-                    Ok(_) => message_str.set(msg_data_successfully_imported.to_string()),
-                    Err(err) => message_str.set(err.to_string()),
+                    Ok(data) => {
+                        if let Some(imported) = data.settings {
+                            settings.set(imported.migrate());
+                        }
+                        message_str.set(t(Msg::DataSuccessfullyImported).to_string());
+                    }
+                    Err(err) => message_str.set(storage_error_message(&err).to_string()),
                 }
             }
         }
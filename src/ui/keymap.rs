@@ -0,0 +1,162 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_daisyui::prelude::*;
+
+use crate::i18n::Msg;
+use crate::model::{KeyAction, Settings};
+use crate::ui::app_settings::use_settings;
+use crate::ui::{focusedElementTag, t, t_args};
+
+/// Keys that only modify other keys and can't be bound on their own
+const MODIFIER_KEYS: [&str; 5] = ["Control", "Shift", "Alt", "Meta", "AltGraph"];
+
+pub(crate) fn key_action_title(action: KeyAction) -> &'static str {
+    t(match action {
+        KeyAction::ShowFolders => Msg::KeyShowFolders,
+        KeyAction::ShowWords => Msg::KeyShowWords,
+        KeyAction::ShowSettings => Msg::KeyShowSettings,
+        KeyAction::PreviousItem => Msg::KeyPreviousItem,
+        KeyAction::NextItem => Msg::KeyNextItem,
+        KeyAction::PageLeft => Msg::KeyPageLeft,
+        KeyAction::PageRight => Msg::KeyPageRight,
+        KeyAction::Open => Msg::KeyOpen,
+        KeyAction::Search => Msg::KeySearch,
+        KeyAction::Delete => Msg::KeyDelete,
+        KeyAction::Edit => Msg::KeyEdit,
+        KeyAction::Help => Msg::KeyHelp,
+    })
+}
+
+fn key_name(event: &Event<KeyboardData>) -> String {
+    let modifiers = event.modifiers();
+    KeyAction::key_name(
+        &event.key().to_string(),
+        modifiers.ctrl(),
+        modifiers.alt(),
+        modifiers.shift(),
+    )
+}
+
+/// The action bound to the pressed key.
+/// Keys typed into a text field and Enter on a button are left to the browser
+pub(crate) fn key_action(event: &Event<KeyboardData>, settings: &Settings) -> Option<KeyAction> {
+    let tag = focusedElementTag();
+    if matches!(tag.as_str(), "INPUT" | "TEXTAREA" | "SELECT") {
+        return None;
+    }
+    let action = settings.key_action(&key_name(event));
+    if action == Some(KeyAction::Open) && matches!(tag.as_str(), "BUTTON" | "A") {
+        return None;
+    }
+    action
+}
+
+#[component]
+pub(crate) fn ShortcutHelp(show_shortcut_help: Signal<bool>) -> Element {
+    let settings = use_settings();
+
+    rsx! {
+        div { class: class!(text_sm border rounded p_2 my_2),
+            table {
+                for action in KeyAction::ALL {
+                    tr {
+                        td { class: class!(pr_4),
+                            kbd { class: class!(kbd kbd_sm),
+                                "{settings().key_binding(action)}"
+                            }
+                        }
+                        td { {key_action_title(action)} }
+                    }
+                }
+            }
+            button { class: class!(btn btn_sm btn_outline),
+                margin_top: "5px",
+                onclick: move |_| show_shortcut_help.set(false),
+                {t(Msg::Close)}
+            }
+        }
+    }
+}
+
+#[component]
+pub(crate) fn KeyBindings() -> Element {
+    let mut settings = use_settings();
+    let mut capturing = use_signal(|| None::<KeyAction>);
+    let mut message_str = use_signal(String::new);
+
+    rsx! {
+        div {
+            table { class: class!(text_sm),
+                for action in KeyAction::ALL {
+                    tr {
+                        td { class: class!(pr_4), {key_action_title(action)} }
+                        td {
+                            button { class: class!(btn btn_xs btn_outline min_w_16),
+                                onclick: move |_| {
+                                    capturing.set(Some(action));
+                                    message_str.set(t(Msg::PressAKey).to_string());
+                                },
+                                onkeydown: move |event| {
+                                    if capturing() != Some(action) {
+                                        return;
+                                    }
+                                    event.prevent_default();
+                                    event.stop_propagation();
+                                    let key = event.key().to_string();
+                                    if MODIFIER_KEYS.contains(&key.as_str()) {
+                                        return;
+                                    }
+                                    capturing.set(None);
+                                    message_str.set(String::new());
+                                    if key == "Escape" {
+                                        return;
+                                    }
+                                    let key = key_name(&event);
+                                    if let Err(other) = settings.write().bind_key(action, &key) {
+                                        message_str.set(
+                                            t_args(Msg::KeyIsAlreadyBound, &[&key, key_action_title(other)]));
+                                    }
+                                },
+                                if capturing() == Some(action) {
+                                    "\u{2026}"
+                                } else {
+                                    "{settings().key_binding(action)}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            p { class: class!(text_xs),
+                "{message_str}"
+            }
+            button { class: class!(btn btn_sm btn_outline),
+                margin_top: "5px",
+                onclick: move |_| {
+                    settings.write().key_bindings.clear();
+                    capturing.set(None);
+                    message_str.set(String::new());
+                },
+                {t(Msg::ResetKeyBindings)}
+            }
+        }
+    }
+}
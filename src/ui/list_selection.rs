@@ -0,0 +1,124 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+
+use dioxus::prelude::*;
+
+use crate::ui::scrollTo;
+
+/// The keyboard selection in a paged list.
+/// The index may leave the page for a moment, then the `Pager` turns the page
+pub(crate) struct ListSelection {
+    /// The selected item on the current page
+    pub(crate) index: GlobalSignal<Option<i32>>,
+    /// The number of items on the current page
+    pub(crate) len: GlobalSignal<i32>,
+    /// The id of the scrolled element with the list
+    list_id: &'static str,
+    /// The items have the ids like "word-0", "word-1", ...
+    item_id_prefix: &'static str,
+}
+
+pub(crate) static WORDS_SELECTION: ListSelection = ListSelection {
+    index: Signal::global(|| None),
+    len: Signal::global(|| 0),
+    list_id: "words-list",
+    item_id_prefix: "word",
+};
+
+pub(crate) static FOLDERS_SELECTION: ListSelection = ListSelection {
+    index: Signal::global(|| None),
+    len: Signal::global(|| 0),
+    list_id: "folders-list",
+    item_id_prefix: "folder",
+};
+
+impl ListSelection {
+    pub(crate) fn item_id(&self, index: i32) -> String {
+        format!("{}-{}", self.item_id_prefix, index)
+    }
+
+    /// The element id of the selected item, for `aria-activedescendant` of the list.
+    pub(crate) fn active_descendant(&self) -> Option<String> {
+        (self.index)().map(|index| self.item_id(index))
+    }
+
+    pub(crate) fn select(&self, index: i32) {
+        *self.index.write() = Some(index);
+    }
+
+    pub(crate) fn clear(&self) {
+        *self.index.write() = None;
+    }
+
+    pub(crate) fn set_len(&self, len: usize) {
+        if *self.len.peek() != len as i32 {
+            *self.len.write() = len as i32;
+        }
+    }
+
+    pub(crate) fn previous(&self) {
+        self.index.with_mut(move |v| match *v {
+            Some(x) => *v = Some(x - 1),
+            None => *v = Some(0)
+        });
+        self.scroll_to_selected();
+    }
+
+    pub(crate) fn next(&self) {
+        self.index.with_mut(move |v| match *v {
+            Some(x) => *v = Some(x + 1),
+            None => *v = Some(0)
+        });
+        self.scroll_to_selected();
+    }
+
+    /// The index is before the first item, the previous page is needed
+    pub(crate) fn is_before_page(&self) -> bool {
+        self.index.read().is_some_and(|v| v < 0)
+    }
+
+    /// The index is after the last item, the next page is needed
+    pub(crate) fn is_after_page(&self) -> bool {
+        self.index.read().is_some_and(|v| v >= *self.len.read())
+    }
+
+    pub(crate) fn to_first(&self) {
+        if self.index.peek().is_some() {
+            *self.index.write() = Some(0);
+        }
+    }
+
+    pub(crate) fn to_last(&self) {
+        if self.index.peek().is_some() {
+            *self.index.write() = Some(*self.len.peek() - 1);
+        }
+    }
+
+    pub(crate) fn step_back(&self) {
+        self.index.with_mut(move |v| {
+            if let Some(x) = *v {
+                *v = Some(x - 1)
+            }
+        });
+    }
+
+    fn scroll_to_selected(&self) {
+        if let Some(v) = *self.index.peek() {
+            scrollTo(self.list_id.to_string(), self.item_id(v));
+        }
+    }
+}
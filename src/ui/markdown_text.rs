@@ -0,0 +1,71 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_daisyui::prelude::*;
+
+use crate::markdown::{Block, Inline, parse};
+
+#[component]
+pub(crate) fn MarkdownText(text: String) -> Element {
+    let blocks = parse(&text);
+
+    rsx! {
+        for markdown_block in blocks {
+            match markdown_block {
+                Block::Paragraph(lines) => rsx! {
+                    p {
+                        for (index, line) in lines.into_iter().enumerate() {
+                            if index > 0 {
+                                br {}
+                            }
+                            InlineText { inlines: line }
+                        }
+                    }
+                },
+                Block::List { ordered: false, items } => rsx! {
+                    ul { class: class!(list_disc list_inside),
+                        for item in items {
+                            li { InlineText { inlines: item } }
+                        }
+                    }
+                },
+                Block::List { ordered: true, items } => rsx! {
+                    ol { class: class!(list_decimal list_inside),
+                        for item in items {
+                            li { InlineText { inlines: item } }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn InlineText(inlines: Vec<Inline>) -> Element {
+    rsx! {
+        for part in inlines {
+            match part {
+                Inline::Text(text) => rsx! { "{text}" },
+                Inline::Bold(text) => rsx! { b { "{text}" } },
+                Inline::Italic(text) => rsx! { i { "{text}" } },
+            }
+        }
+    }
+}
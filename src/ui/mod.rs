@@ -15,30 +15,47 @@
  */
 
 #![allow(non_snake_case)]
-#![allow(non_upper_case_globals)]
 
 use dioxus::prelude::*;
-use dioxus_sdk::storage::{LocalStorage, use_synced_storage};
 use log::debug;
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
+use crate::dictionary_source::headword;
+use crate::i18n::{tr, tr_args, Locale, Msg};
+use crate::model::Settings;
+use crate::ui::app_settings::use_settings_provider;
+use crate::ui::error_message::{storage_error_message, ErrorMessage};
 use crate::ui::navigation::Navigation;
 
 mod add_folder_form;
 mod add_word_form;
-mod error_message;
+mod app_settings;
+mod check_database;
+mod check_integrity;
+mod confirm_dialog;
+pub(crate) mod error_message;
 mod export_data;
+mod folder_breadcrumbs;
 mod folders;
+mod goals;
 mod import_data;
+mod keymap;
+mod list_selection;
+mod markdown_text;
 mod navigation;
+mod print_layout;
 mod search_form;
 mod settings;
 mod show_folder;
 mod show_word;
+mod smart_folders;
+mod stats;
 mod words;
 mod pager;
 mod page_length;
+mod quick_add_folder;
 mod show_copyright;
+mod word_detail;
 
 #[derive(Default, Clone, PartialEq)]
 struct CurrentTabData {
@@ -48,40 +65,25 @@ struct CurrentTabData {
     phonetics: String,
 }
 
-const msg_folder_name_is_empty: &str = "Folder name is empty";
-const msg_word_is_empty: &str = "Word is empty";
-const msg_select_folder_first: &str = "Please select a folder first";
-const msg_data_successfully_imported: &str = "Data successfully imported";
-const msg_data_protection_is_set: &str = "Data protection is set. Check the settings to disable it";
-const msg_word_was_deleted: &str = "Word was deleted";
-const msg_folder_was_deleted: &str = "Folder was deleted";
-const msg_use_arrow_keys_to_navigate: &str = "Use the up and down arrow keys to navigate the list";
-
 const BASE_URL: &str = "https://www.oxfordlearnersdictionaries.com";
 
 const EXPORT_FILE_NAME: &str = "export.json";
 const EXPORT_FILE_TYPE: &str = "application/json";
 
-static CURRENT_TAB_DATA: GlobalSignal<CurrentTabData> = Signal::global(|| CurrentTabData::default());
+const PRINT_FILE_NAME: &str = "print.html";
+const PRINT_FILE_TYPE: &str = "text/html";
+
+static CURRENT_TAB_DATA: GlobalSignal<CurrentTabData> = Signal::global(CurrentTabData::default);
 
-static SELECTED_WORD_INDEX: GlobalSignal<Option<i32>> = Signal::global(|| None);
-static MAX_WORD_INDEX: GlobalSignal<i32> = Signal::global(|| 0);
+/// The words of the smart folder are shown instead of the selected folder
+static SELECTED_SMART_FOLDER: GlobalSignal<Option<u32>> = Signal::global(|| None);
 
-static AUTOPLAY: GlobalSignal<bool> = Signal::global(|| true);
+static PLAY_ON_TAB_LOADED: GlobalSignal<bool> = Signal::global(|| false);
 
 
 #[wasm_bindgen]
 pub fn on_tab_loaded(url: String, word: String, word_class: String, title: String, phonetics: String) {
-    let mut word = word;
-    if word.len() == 0 {
-        match title.split_once(" - ") {
-            Some((str, _)) => word = str.to_string(),
-            None => match title.split_once(" | ") {
-                Some((str, _)) => word = str.to_string(),
-                None => {}
-            }
-        }
-    }
+    let word = headword(word, &title);
 
     // debug!("tab is loaded: word={} class={} phonetics={} url={}", word, word_class, phonetics, url);
 
@@ -91,33 +93,31 @@ pub fn on_tab_loaded(url: String, word: String, word_class: String, title: Strin
             CURRENT_TAB_DATA.with_mut(move |v|
                 *v = CurrentTabData { url, word, word_class, phonetics }
             );
-            if AUTOPLAY() {
+            let autoplay = ScopeId::APP
+                .consume_context::<Signal<Settings>>()
+                .is_some_and(|settings| settings.peek().autoplay);
+            if autoplay || PLAY_ON_TAB_LOADED() {
+                *PLAY_ON_TAB_LOADED.write() = false;
                 spawn(playPhonetics());
             }
         })
     }
 }
 
-pub(crate) fn previous_word() {
-    SELECTED_WORD_INDEX.with_mut(move |v| match *v {
-        Some(x) => *v = Some(x - 1),
-        None => *v = Some(0)
-    });
-    scroll_to(SELECTED_WORD_INDEX());
+/// The text of the message in the language chosen in the settings
+pub(crate) fn t(msg: Msg) -> &'static str {
+    tr(current_locale(), msg)
 }
 
-pub(crate) fn next_word() {
-    SELECTED_WORD_INDEX.with_mut(move |v| match *v {
-        Some(x) => *v = Some(x + 1),
-        None => *v = Some(0)
-    });
-    scroll_to(SELECTED_WORD_INDEX());
+/// The text of the message with its parameters, see `tr_args`
+pub(crate) fn t_args(msg: Msg, args: &[&str]) -> String {
+    tr_args(current_locale(), msg, args)
 }
 
-fn scroll_to(id: Option<i32>) {
-    if let Some(v) = id {
-        scrollTo(format!("word-{}", v));
-    }
+pub(crate) fn current_locale() -> Locale {
+    ScopeId::APP
+        .consume_context::<Memo<Locale>>()
+        .map_or(Locale::En, |locale| locale())
 }
 
 #[wasm_bindgen(module = "/helper.js")]
@@ -127,7 +127,11 @@ extern "C" {
     pub async fn updateCurrentTabData();
     async fn playPhonetics();
     pub fn startDownload(url: String, filename: String);
-    fn scrollTo(id: String);
+    fn scrollTo(listId: String, id: String);
+    fn focusElement(id: String);
+    fn focusedElementTag() -> String;
+    fn setTheme(theme: Option<String>);
+    fn openTab(url: String);
 }
 
 #[wasm_bindgen]
@@ -138,14 +142,14 @@ extern "C" {
 
 #[component]
 pub fn App() -> Element {
-    let autoplay = use_synced_storage::<LocalStorage, bool>(
-        "autoplay".to_string(), || true);
-
-    use_effect(move || {
-        AUTOPLAY.with_mut(move |v| *v = autoplay());
-    });
+    let settings_error = use_settings_provider();
 
     rsx! {
+        if let Some(error) = settings_error() {
+            ErrorMessage {
+                message: format!("{} {}", t(Msg::ErrorSettingsNotLoaded), storage_error_message(&error)),
+            }
+        }
         Navigation {}
     }
 }
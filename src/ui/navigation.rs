@@ -18,20 +18,29 @@ use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
 use dioxus_free_icons::Icon;
 use dioxus_free_icons::icons::md_action_icons::MdSettings;
-use dioxus_free_icons::icons::md_editor_icons::MdNotes;
+use dioxus_free_icons::icons::md_editor_icons::{MdInsertChart, MdNotes};
 use dioxus_free_icons::icons::md_file_icons::MdFolder;
 use dioxus_free_icons::icons::md_navigation_icons::{MdArrowDropDown, MdArrowDropUp};
-use dioxus_sdk::storage::{LocalStorage, use_synced_storage};
 use futures_util::StreamExt;
 
-use crate::model::{default_sort_direction, Folder, FolderKey, Word, WordKey};
+use crate::i18n::Msg;
+use crate::model::{default_sort_direction, DataProtection, Folder, FolderKey, KeyAction, SmartFolder, SmartFolderKey, Word, WordKey};
+use crate::storage::{ObjStoreName, StorageBackend, StorageError};
 use crate::storage_global::get_storage;
-use crate::ui::{CURRENT_TAB_DATA, msg_data_protection_is_set, msg_folder_was_deleted, msg_select_folder_first, msg_word_was_deleted};
+use crate::ui::{CURRENT_TAB_DATA, focusElement, SELECTED_SMART_FOLDER, t, t_args};
+use crate::ui::app_settings::{use_setting, use_settings};
+use crate::ui::confirm_dialog::{Confirmation, ConfirmDialog, DeleteRequest};
+use crate::ui::error_message::storage_error_message;
 use crate::ui::export_data::ExportData;
 use crate::ui::folders::Folders;
+use crate::ui::goals::DailyProgress;
 use crate::ui::import_data::ImportData;
+use crate::ui::keymap::{key_action, ShortcutHelp};
+use crate::ui::print_layout::PrintLayout;
 use crate::ui::settings::Settings;
 use crate::ui::show_copyright::ShowCopyright;
+use crate::ui::stats::Stats;
+use crate::ui::word_detail::WordDetail;
 use crate::ui::words::Words;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -39,14 +48,13 @@ pub(crate) enum NavigationState {
     Folders,
     Words,
     Settings,
+    Stats,
     ExportData,
     ImportData,
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub(crate) enum DataProtection {
-    Protected,
-    Unprotected,
+    PrintLayout,
+    WordDetail(u32),
+    /// The word detail opened in the edit mode
+    EditWord(u32),
 }
 
 pub(crate) const NAVIGATION_MESSAGE_ERROR: &str = text_red_500;
@@ -58,10 +66,19 @@ pub(crate) struct NavigationMessage {
     pub(crate) color: &'static str,
 }
 
+impl NavigationMessage {
+    pub(crate) fn storage_error(err: &StorageError) -> NavigationMessage {
+        NavigationMessage {
+            message: storage_error_message(err),
+            color: NAVIGATION_MESSAGE_ERROR,
+        }
+    }
+}
+
 #[component]
 pub fn Navigation() -> Element {
     let mut navigation_state = use_signal(|| NavigationState::Folders);
-    let mut navigation_message = use_signal(|| NavigationMessage::default());
+    let mut navigation_message = use_signal(NavigationMessage::default);
     let _navigation_message_send = use_coroutine(move |mut rx| async move {
         while let Some(message) = rx.next().await {
             navigation_message.set(message);
@@ -75,87 +92,165 @@ pub fn Navigation() -> Element {
         }
     });
 
-    let folder_str = use_signal(|| String::new());
-    let folder_note_str = use_signal(|| String::new());
-    let folder_error_str = use_signal(|| String::new());
+    let folder_str = use_signal(String::new);
+    let folder_note_str = use_signal(String::new);
+    let folder_error_str = use_signal(String::new);
 
-    let folders_page_length = use_synced_storage::<LocalStorage, Option<u32>>(
-        "folders_page_length".to_string(), || None::<u32>);
+    let folders_page_length = use_setting(
+        |s| s.folders_page_length, |s, v| s.folders_page_length = v);
     let folders_offset = use_signal(|| None::<u32>);
     let folders_direction = use_signal(|| default_sort_direction.to_string());
     let refresh_folders = use_signal(|| 0u8);
 
-    let show_add_folder_form = use_synced_storage::<LocalStorage, u8>(
-        "show_add_folder_form".to_string(), || 255u8);
+    let show_add_folder_form = use_setting(
+        |s| s.show_add_folder_form, |s, v| s.show_add_folder_form = v);
 
-    let selected_folder_str = use_synced_storage::<LocalStorage, String>(
-        "selected_folder".to_string(), || String::new());
-    let selected_folder_error_str = use_signal(|| String::new());
-    let mut word_str = use_signal(|| String::new());
-    let word_error_str = use_signal(|| String::new());
-    let mut word_class_str = use_signal(|| String::new());
-    let mut note_str = use_signal(|| String::new());
+    let selected_folder_str = use_setting(
+        |s| s.selected_folder.clone(), |s, v| s.selected_folder = v);
+    let selected_folder_error_str = use_signal(String::new);
+    let mut word_str = use_signal(String::new);
+    let word_error_str = use_signal(String::new);
+    let mut word_class_str = use_signal(String::new);
+    let mut note_str = use_signal(String::new);
+    let mut examples_str = use_signal(String::new);
 
-    let words_page_length = use_synced_storage::<LocalStorage, Option<u32>>(
-        "page_length".to_string(), || None::<u32>);
+    let words_page_length = use_setting(
+        |s| s.words_page_length, |s, v| s.words_page_length = v);
     let words_page_offset = use_signal(|| None::<u32>);
     let words_direction = use_signal(|| default_sort_direction.to_string());
     let refresh_words = use_signal(|| 0u8);
 
-    let show_add_word_form = use_synced_storage::<LocalStorage, u8>(
-        "show_add_word_form".to_string(), || 255u8);
+    let show_add_word_form = use_setting(
+        |s| s.show_add_word_form, |s, v| s.show_add_word_form = v);
 
-    let data_protection = use_signal(|| DataProtection::Protected);
-    let data_protection_error = move ||
-    navigation_message.set(NavigationMessage {
-        message: msg_data_protection_is_set,
-        color: NAVIGATION_MESSAGE_ERROR,
-    });
+    let settings = use_settings();
+    let mut confirmation = use_signal(|| None::<Confirmation>);
 
-    let _delete_word = use_coroutine(move |mut rx: UnboundedReceiver<WordKey>| {
-        to_owned![refresh_words, data_protection_error];
+    let delete = use_coroutine(move |mut rx: UnboundedReceiver<DeleteRequest>| {
+        to_owned![refresh_words, refresh_folders];
         async move {
-            while let Some(word_key) = rx.next().await {
-                match data_protection() {
-                    DataProtection::Protected => data_protection_error(),
-                    DataProtection::Unprotected => {
-                        let _ = get_storage().delete_by_id::<Word>(word_key.id).await;
+            while let Some(request) = rx.next().await {
+                let (result, message) = match request {
+                    DeleteRequest::Word(id) =>
+                        (get_storage().delete_by_id::<Word>(id).await, Msg::WordWasDeleted),
+                    DeleteRequest::Folder(id) =>
+                        (get_storage().delete_subtree(id).await, Msg::FolderWasDeleted),
+                    DeleteRequest::SmartFolder(id) =>
+                        (get_storage().delete_by_id::<SmartFolder>(id).await, Msg::SmartFolderWasDeleted),
+                };
+                match result {
+                    Ok(_) => navigation_message.set(NavigationMessage {
+                        message: t(message),
+                        color: NAVIGATION_MESSAGE_ERROR,
+                    }),
+                    Err(err) => navigation_message.set(NavigationMessage::storage_error(&err)),
+                }
+                match request {
+                    DeleteRequest::Word(id) => {
+                        // the deleted word can't be shown anymore
+                        if let NavigationState::WordDetail(shown) | NavigationState::EditWord(shown) = navigation_state() {
+                            if shown == id {
+                                navigation_state.set(NavigationState::Words);
+                            }
+                        }
                         refresh_words.toggle();
-                        navigation_message.set(NavigationMessage {
-                            message: msg_word_was_deleted,
-                            color: NAVIGATION_MESSAGE_ERROR,
-                        });
+                    }
+                    DeleteRequest::Folder(_) => refresh_folders.toggle(),
+                    DeleteRequest::SmartFolder(id) => {
+                        if SELECTED_SMART_FOLDER() == Some(id) {
+                            *SELECTED_SMART_FOLDER.write() = None;
+                        }
+                        refresh_folders.toggle();
                     }
                 }
             }
         }
     });
 
-    let _delete_folder = use_coroutine(move |mut rx: UnboundedReceiver<FolderKey>| {
-        to_owned![refresh_folders, data_protection_error];
-        async move {
-            while let Some(folder_key) = rx.next().await {
-                match data_protection() {
-                    DataProtection::Protected => data_protection_error(),
-                    DataProtection::Unprotected => {
-                        let _ = get_storage().delete_by_id::<Folder>(folder_key.id).await;
-                        refresh_folders.toggle();
-                        navigation_message.set(NavigationMessage {
-                            message: msg_folder_was_deleted,
-                            color: NAVIGATION_MESSAGE_ERROR,
-                        });
-                    }
+    // deletes at once or asks the user first, as set for the action
+    let mut request_delete = move |request: DeleteRequest, description: String, name: String| {
+        match settings.peek().protection(request.action()) {
+            DataProtection::Protected => navigation_message.set(NavigationMessage {
+                message: t(Msg::DataProtectionIsSet),
+                color: NAVIGATION_MESSAGE_ERROR,
+            }),
+            DataProtection::Unprotected => delete.send(request),
+            protection => confirmation.set(Some(Confirmation { request, protection, description, name })),
+        }
+    };
+
+    let _delete_word = use_coroutine(move |mut rx: UnboundedReceiver<WordKey>| async move {
+        while let Some(WordKey { id }) = rx.next().await {
+            match get_storage().get_word_by_id(id).await {
+                Ok(word) => request_delete(
+                    DeleteRequest::Word(id),
+                    t_args(Msg::DeleteWordQuestion, &[&word.word]),
+                    word.word,
+                ),
+                Err(err) => navigation_message.set(NavigationMessage::storage_error(&err)),
+            }
+        }
+    });
+
+    let _delete_folder = use_coroutine(move |mut rx: UnboundedReceiver<FolderKey>| async move {
+        while let Some(FolderKey { id }) = rx.next().await {
+            match folder_deletion(id).await {
+                Ok((description, name)) => request_delete(DeleteRequest::Folder(id), description, name),
+                Err(err) => navigation_message.set(NavigationMessage::storage_error(&err)),
+            }
+        }
+    });
+
+    let _delete_smart_folder = use_coroutine(move |mut rx: UnboundedReceiver<SmartFolderKey>| async move {
+        while let Some(SmartFolderKey { id }) = rx.next().await {
+            match get_storage().get_smart_folder_by_id(id).await {
+                Ok(Some(SmartFolder { name, .. })) => {
+                    request_delete(
+                        DeleteRequest::SmartFolder(id),
+                        t_args(Msg::DeleteSmartFolderQuestion, &[&name]),
+                        name,
+                    );
                 }
+                // an empty name would let an empty confirmation input delete it
+                Ok(None) => navigation_message.set(NavigationMessage {
+                    message: t(Msg::ErrorNotFound),
+                    color: NAVIGATION_MESSAGE_ERROR,
+                }),
+                Err(err) => navigation_message.set(NavigationMessage::storage_error(&err)),
             }
         }
     });
 
-    let current_tab_data = use_memo(move || CURRENT_TAB_DATA());
+    let mut show_shortcut_help = use_signal(|| false);
+
+    let mut show_words = move || {
+        if !selected_folder_str().is_empty() {
+            navigation.send(NavigationState::Words);
+        } else {
+            navigation_message.set(NavigationMessage {
+                message: t(Msg::SelectFolderFirst),
+                color: NAVIGATION_MESSAGE_ERROR,
+            });
+        }
+    };
+
+    // moves the focus to the shown view, so the screen readers and the keyboard follow it
+    use_effect(move || {
+        let focus_id = match navigation_state() {
+            NavigationState::Folders => "folders-list",
+            NavigationState::Words => "words-list",
+            _ => "navigation-view",
+        };
+        focusElement(focus_id.to_string());
+    });
+
+    let current_tab_data = use_memo(move || CURRENT_TAB_DATA.cloned());
 
     use_effect(move || {
         word_str.set(current_tab_data().word);
         word_class_str.set(current_tab_data().word_class);
         note_str.set(current_tab_data().phonetics);
+        examples_str.set(String::new());
     });
 
     rsx! {
@@ -163,114 +258,181 @@ pub fn Navigation() -> Element {
             tabindex: "-1",
             position: "relative",
             margin_top: "5px",
+            onkeydown: move |event| {
+                let Some(action) = key_action(&event, &settings.peek()) else {
+                    return;
+                };
+                match action {
+                    KeyAction::ShowFolders => navigation.send(NavigationState::Folders),
+                    KeyAction::ShowWords => show_words(),
+                    KeyAction::ShowSettings => navigation.send(NavigationState::Settings),
+                    KeyAction::Help => show_shortcut_help.toggle(),
+                    KeyAction::Search => match navigation_state() {
+                        NavigationState::Folders => focusElement("filter-folders".to_string()),
+                        NavigationState::Words => focusElement("filter-text".to_string()),
+                        _ => return,
+                    },
+                    _ => return,
+                }
+                event.prevent_default();
+            },
             form { action: "",
-                tabindex: "-1",
                 onsubmit: move |event| event.stop_propagation(),
                 div { class: class!(flex flex_row items_baseline),
-                    tabindex: "-1",
-                    label { title: "Folders",
+                    role: "navigation",
+                    label { title: t(Msg::Folders),
                         button { class: class!(btn btn_sm flex_none),
+                            aria_current: navigation_state() == NavigationState::Folders,
                             onclick: move |_| navigation.send(NavigationState::Folders),
                             Icon { icon: MdFolder }
-                            "Folders"
+                            {t(Msg::Folders)}
                         }
                     }
                     ShowFormButton {
-                        title: "Show add folder form",
+                        title: Msg::ShowAddFolderForm,
                         navigation_state: navigation_state,
                         form_state: NavigationState::Folders,
                         show_form: show_add_folder_form,
                     }
-                    label { title: "Words",
+                    label { title: t(Msg::Words),
                         button { class: class!(btn btn_sm flex_none),
                             margin_left: "1px",
                             margin_right: "1px",
-                            onclick: move |_| {
-                                if selected_folder_str().len() != 0 {
-                                    navigation.send(NavigationState::Words);
-                                } else {
-                                    navigation_message.set(NavigationMessage {
-                                        message: msg_select_folder_first,
-                                        color: NAVIGATION_MESSAGE_ERROR,
-                                    });
-                                }
-                            },
+                            aria_current: navigation_state() == NavigationState::Words,
+                            onclick: move |_| show_words(),
                             Icon { icon: MdNotes }
-                            "Words"
+                            {t(Msg::Words)}
                         }
                     }
                     ShowFormButton {
-                        title: "Show add word form",
+                        title: Msg::ShowAddWordForm,
                         navigation_state: navigation_state,
                         form_state: NavigationState::Words,
                         show_form: show_add_word_form,
                     }
-                    label { title: "Settings",
+                    label { title: t(Msg::Statistics),
                         button { class: class!(btn btn_sm flex_none),
                             margin_left: "5px",
+                            aria_label: t(Msg::Statistics),
+                            aria_current: navigation_state() == NavigationState::Stats,
+                            onclick: move |_| navigation.send(NavigationState::Stats),
+                            Icon { icon: MdInsertChart }
+                        }
+                    }
+                    label { title: t(Msg::Settings),
+                        button { class: class!(btn btn_sm flex_none),
+                            margin_left: "1px",
+                            aria_label: t(Msg::Settings),
+                            aria_current: navigation_state() == NavigationState::Settings,
                             onclick: move |_| navigation.send(NavigationState::Settings),
                             Icon { icon: MdSettings }
                         }
                     }
                 }
 
+                DailyProgress { refresh_words }
+
                 p { class: class!(text_xs pt_2 navigation_message().color),
+                    role: "status",
+                    aria_live: "polite",
+                    aria_atomic: "true",
                     {navigation_message().message}
                 }
             }
-            match navigation_state() {
-                NavigationState::Folders => {
-                    rsx! {
-                        Folders {
-                            folder_str: folder_str,
-                            folder_note_str: folder_note_str,
-                            folder_error_str: folder_error_str,
-                            selected_folder_str: selected_folder_str,
-                            page_length: folders_page_length,
-                            offset: folders_offset,
-                            direction: folders_direction,
-                            refresh_folders: refresh_folders,
-                            show_add_folder_form: show_add_folder_form,
-                            words_page_offset: words_page_offset,
+            ConfirmDialog { confirmation }
+            if show_shortcut_help() {
+                ShortcutHelp {
+                    show_shortcut_help,
+                }
+            }
+            div {
+                id: "navigation-view",
+                tabindex: "-1",
+                match navigation_state() {
+                    NavigationState::Folders => {
+                        rsx! {
+                            Folders {
+                                folder_str: folder_str,
+                                folder_note_str: folder_note_str,
+                                folder_error_str: folder_error_str,
+                                selected_folder_str: selected_folder_str,
+                                page_length: folders_page_length,
+                                offset: folders_offset,
+                                direction: folders_direction,
+                                refresh_folders: refresh_folders,
+                                show_add_folder_form: show_add_folder_form,
+                                words_page_offset: words_page_offset,
+                            }
                         }
                     }
-                }
-                NavigationState::Words => {
-                    rsx! {
-                        Words {
-                            selected_folder_str: selected_folder_str,
-                            selected_folder_error_str: selected_folder_error_str,
-                            word_str: word_str,
-                            word_error_str: word_error_str,
-                            word_class_str: word_class_str,
-                            note_str: note_str,
-                            page_length: words_page_length,
-                            offset: words_page_offset,
-                            direction: words_direction,
-                            refresh_words: refresh_words,
-                            show_add_word_form: show_add_word_form,
+                    NavigationState::Words => {
+                        rsx! {
+                            Words {
+                                selected_folder_str: selected_folder_str,
+                                selected_folder_error_str: selected_folder_error_str,
+                                word_str: word_str,
+                                word_error_str: word_error_str,
+                                word_class_str: word_class_str,
+                                note_str: note_str,
+                                examples_str: examples_str,
+                                page_length: words_page_length,
+                                offset: words_page_offset,
+                                direction: words_direction,
+                                refresh_words: refresh_words,
+                                show_add_word_form: show_add_word_form,
+                            }
                         }
                     }
-                }
-                NavigationState::Settings => {
-                    rsx! {
-                        Settings {
-                            folders_page_length: folders_page_length,
-                            folders_offset: folders_offset,
-                            words_page_length: words_page_length,
-                            words_offset: words_page_offset,
-                            data_protection: data_protection,
+                    NavigationState::Settings => {
+                        rsx! {
+                            Settings {
+                                folders_page_length: folders_page_length,
+                                folders_offset: folders_offset,
+                                words_page_length: words_page_length,
+                                words_offset: words_page_offset,
+                            }
                         }
                     }
-                }
-                NavigationState::ExportData => {
-                    rsx! {
-                        ExportData {}
+                    NavigationState::Stats => {
+                        rsx! {
+                            Stats {
+                                refresh_words: refresh_words,
+                            }
+                        }
                     }
-                }
-                NavigationState::ImportData => {
-                    rsx! {
-                        ImportData {}
+                    NavigationState::ExportData => {
+                        rsx! {
+                            ExportData {}
+                        }
+                    }
+                    NavigationState::ImportData => {
+                        rsx! {
+                            ImportData {}
+                        }
+                    }
+                    NavigationState::PrintLayout => {
+                        rsx! {
+                            PrintLayout {}
+                        }
+                    }
+                    NavigationState::WordDetail(id) => {
+                        rsx! {
+                            WordDetail {
+                                id: id,
+                                edit: false,
+                                refresh_words: refresh_words,
+                            }
+                        }
+                    }
+                    NavigationState::EditWord(id) => {
+                        rsx! {
+                            WordDetail {
+                                key: "edit-{id}",
+                                id: id,
+                                edit: true,
+                                refresh_words: refresh_words,
+                            }
+                        }
                     }
                 }
             }
@@ -281,21 +443,46 @@ pub fn Navigation() -> Element {
     }
 }
 
+/// The question about deleting the folder with its subfolders and words, and the name of the folder
+async fn folder_deletion(id: u32) -> Result<(String, String), StorageError> {
+    let subtree = get_storage().get_subtree(id).await?;
+    // an empty name would let an empty confirmation input delete it
+    let Some(root) = subtree.first() else {
+        return Err(StorageError::NotFound {
+            store: Folder::OBJ_STORE_NAME.to_string(),
+            key: id.to_string(),
+        });
+    };
+    let mut word_count = 0;
+    for folder in &subtree {
+        word_count += get_storage().get_folder_words(&folder.folder).await?.len();
+    }
+    let name = root.name().to_string();
+    let subfolder_count = subtree.len().saturating_sub(1);
+    let description = t_args(
+        Msg::DeleteFolderQuestion,
+        &[&name, &subfolder_count.to_string(), &word_count.to_string()],
+    );
+    Ok((description, name))
+}
+
 #[component]
 pub fn ShowFormButton(
-    title: &'static str,
+    title: Msg,
     navigation_state: Signal<NavigationState>,
     form_state: NavigationState,
-    show_form: Signal<u8>,
+    show_form: Signal<bool>,
 ) -> Element {
     rsx! {
         if navigation_state() == form_state {
-            label { title: title,
+            label { title: t(title),
                 button { class: class!(btn btn_sm flex_none pl_2 pr_2),
                     margin_left: "1px",
                     margin_right: "1px",
+                    aria_label: t(title),
+                    aria_expanded: show_form(),
                     onclick: move |_| show_form.toggle(),
-                    if show_form() == 0u8 {
+                    if !show_form() {
                         Icon { icon: MdArrowDropDown }
                     } else {
                         Icon { icon: MdArrowDropUp }
@@ -15,30 +15,26 @@
  */
 
 #![allow(non_snake_case)]
-#![allow(non_upper_case_globals)]
 
 use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
 
-const msg_unlimited_page_length: &str = "unlimited";
-const msg_page_length_updated_to_unlimited: &str = "page length updated to unlimited";
-const msg_page_length_updated_to_number: fn(&u32) -> String =
-    |val| format!("page length updated to {}", &val);
-const button_change: &str = "Change";
+use crate::i18n::Msg;
+use crate::ui::{t, t_args};
 
 #[component]
 pub(crate)
 fn PageLength(
-    placeholder_str: &'static str,
+    placeholder_str: Msg,
     page_length: Signal<Option<u32>>,
     offset: Signal<Option<u32>>,
 ) -> Element {
     let get_page_length = move || match page_length() {
-        None => msg_unlimited_page_length.to_string(),
+        None => t(Msg::UnlimitedPageLength).to_string(),
         Some(val) => val.to_string(),
     };
-    let mut page_length_val = use_signal(|| get_page_length());
-    let mut message_str = use_signal(|| String::new());
+    let mut page_length_val = use_signal(get_page_length);
+    let mut message_str = use_signal(String::new);
 
     rsx! {
         div {
@@ -48,11 +44,11 @@ fn PageLength(
                     match page_length_val().trim().parse() {
                         Err(_) | Ok(0) => {
                             page_length.set(None);
-                            message_str.set(msg_page_length_updated_to_unlimited.to_string());
+                            message_str.set(t(Msg::PageLengthUpdatedToUnlimited).to_string());
                         }
                         Ok(val) => {
                             page_length.set(Some(val));
-                            message_str.set(msg_page_length_updated_to_number(&val));
+                            message_str.set(t_args(Msg::PageLengthUpdatedToNumber, &[&val.to_string()]));
                         }
                     }
                     offset.set(None);
@@ -61,13 +57,13 @@ fn PageLength(
                 input { class: class!(outline),
                     margin_top: "5px",
                     oninput: move |event| page_length_val.set(event.value()),
-                    placeholder: "{placeholder_str}",
+                    placeholder: t(placeholder_str),
                     r#type: "text",
                     value: "{page_length_val}"
                 }
                 button { class: class!(btn btn_sm btn_outline),
                     margin_left: "5px",
-                    "{button_change}"
+                    {t(Msg::Change)}
                 }
                 p { class: class!(text_xs),
                     "{message_str}"
@@ -14,16 +14,27 @@
  * limitations under the License.
  */
 
-use crate::ui::{MAX_WORD_INDEX, SELECTED_WORD_INDEX};
+use crate::i18n::Msg;
+use crate::ui::t;
+use crate::ui::list_selection::{ListSelection, FOLDERS_SELECTION, WORDS_SELECTION};
 use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 pub(crate) enum PagerMode {
     Folders,
     Words,
 }
 
+impl PagerMode {
+    pub(crate) fn selection(&self) -> &'static ListSelection {
+        match self {
+            PagerMode::Folders => &FOLDERS_SELECTION,
+            PagerMode::Words => &WORDS_SELECTION,
+        }
+    }
+}
+
 #[component]
 pub(crate) fn Pager(
     mode: ReadOnlySignal<PagerMode>,
@@ -32,40 +43,37 @@ pub(crate) fn Pager(
     direction: Signal<String>,
     count: u32,
 ) -> Element {
-    if mode() == PagerMode::Words {
-        if let Some(v) = SELECTED_WORD_INDEX() {
-            if v < 0 {
-                page_left(mode, page_length, offset);
-            } else if v >= MAX_WORD_INDEX() {
-                page_right(mode, count, page_length, offset, true);
-            }
-        }
+    let selection = mode().selection();
+    if selection.is_before_page() {
+        page_left(mode(), page_length, offset);
+    } else if selection.is_after_page() {
+        page_right(mode(), count, page_length, offset, true);
     }
 
     let pager = match page_length() {
         Some(length) => {
             let page_number = offset().unwrap_or(0) / length + 1;
-            let total_pages = (count + length - 1) / length;
+            let total_pages = count.div_ceil(length);
             let last_page_offset = (total_pages - 1) * length;
             rsx! {
                 div { class: class!(flex_none self_center w_5),
-                    label { title: "First page",
+                    label { title: t(Msg::FirstPage),
                         button {
-                            onclick: move |_| first_page(mode, offset),
+                            onclick: move |_| first_page(mode(), offset),
                             "\u{21E4}"
                         }
                     }
                 }
                 div { class: class!(flex_none self_center w_5),
-                    label { title: "Page left",
+                    label { title: t(Msg::PageLeft),
                         button {
-                            onclick: move |_| page_left(mode, page_length, offset),
+                            onclick: move |_| page_left(mode(), page_length, offset),
                             "\u{2190}"
                         }
                     }
                 }
                 div { class: class!(flex_none self_center),
-                    label { title: "Page number",
+                    label { title: t(Msg::PageNumber),
                         "{page_number}"
                     }
                 }
@@ -73,22 +81,22 @@ pub(crate) fn Pager(
                     "/"
                 }
                 div { class: class!(flex_none self_center),
-                    label { title: "Total pages",
+                    label { title: t(Msg::TotalPages),
                         "{total_pages}"
                     }
                 }
                 div { class: class!(flex_none self_center w_5),
-                    label { title: "Page right",
+                    label { title: t(Msg::PageRight),
                         button {
-                            onclick: move |_| page_right(mode, count, page_length, offset, false),
+                            onclick: move |_| page_right(mode(), count, page_length, offset, false),
                             "\u{2192}"
                         }
                     }
                 }
                 div { class: class!(flex_none self_center w_5),
-                    label { title: "Last page",
+                    label { title: t(Msg::LastPage),
                         button {
-                            onclick: move |_| last_page(mode, offset, last_page_offset),
+                            onclick: move |_| last_page(mode(), offset, last_page_offset),
                             "\u{21E5}"
                         }
                     }
@@ -103,7 +111,7 @@ pub(crate) fn Pager(
             div { class: class!(flex_none self_center w_5),
                 SortElement {
                     mode,
-                    title: "Sort order: ascending",
+                    title: Msg::SortAscending,
                     element: "\u{2191}",
                     direction: direction,
                 }
@@ -111,14 +119,14 @@ pub(crate) fn Pager(
             div { class: class!(flex_none self_center w_5),
                 SortElement {
                     mode,
-                    title: "Sort order: descending",
+                    title: Msg::SortDescending,
                     element: "\u{2193}",
                     direction: direction,
                 }
             }
             {pager}
             div { class: class!(flex_none self_center),
-                label { title: "Count",
+                label { title: t(Msg::Count),
                     "{count}"
                 }
             }
@@ -129,20 +137,18 @@ pub(crate) fn Pager(
 #[component]
 fn SortElement(
     mode: ReadOnlySignal<PagerMode>,
-    title: &'static str,
+    title: Msg,
     element: &'static str,
     direction: Signal<String>,
 ) -> Element {
     let cls = if direction().as_str() == element { invert } else { "" };
 
     rsx! {
-        label { title: "{title}",
+        label { title: t(title),
             button { class: class!(cls),
                 onclick: move |_| {
                     direction.set(element.to_string());
-                    if mode() == PagerMode::Words {
-                        *SELECTED_WORD_INDEX.write() = None;
-                    }
+                    mode().selection().clear();
                 },
                 "{element}"
             }
@@ -150,85 +156,57 @@ fn SortElement(
     }
 }
 
-fn first_page(mode: ReadOnlySignal<PagerMode>, mut offset: Signal<Option<u32>>) {
+fn first_page(mode: PagerMode, mut offset: Signal<Option<u32>>) {
     offset.set(None);
-    set_index_to_zero(mode);
+    mode.selection().to_first();
 }
 
 fn last_page(
-    mode: ReadOnlySignal<PagerMode>,
+    mode: PagerMode,
     mut offset: Signal<Option<u32>>,
     last_page_offset: u32,
 ) {
     offset.set(Some(last_page_offset));
-    set_index_to_zero(mode);
+    mode.selection().to_first();
 }
 
-fn page_left(
-    mode: ReadOnlySignal<PagerMode>,
+pub(crate) fn page_left(
+    mode: PagerMode,
     page_length: Signal<Option<u32>>,
     mut offset: Signal<Option<u32>>,
 ) {
-    if page_length() == None {
-        set_index_to_zero(mode);
+    if page_length().is_none() {
+        mode.selection().to_first();
         return;
     }
     let page_length = page_length().unwrap() as i32;
     let new_off = offset().unwrap_or(0) as i32 - page_length;
     if new_off >= 0 {
         offset.set(Some(new_off as u32));
-        *MAX_WORD_INDEX.write() = page_length;
-        set_index_to_max(mode);
+        *mode.selection().len.write() = page_length;
+        mode.selection().to_last();
     } else {
-        set_index_to_zero(mode);
+        mode.selection().to_first();
     }
 }
 
-fn page_right(
-    mode: ReadOnlySignal<PagerMode>,
+pub(crate) fn page_right(
+    mode: PagerMode,
     count: u32,
     page_length: Signal<Option<u32>>,
     mut offset: Signal<Option<u32>>,
     is_key_pressed: bool,
 ) {
-    if page_length() == None {
-        set_index_to_one_step_back(mode);
+    if page_length().is_none() {
+        mode.selection().step_back();
         return;
     }
     let page_length = page_length().unwrap();
     let new_off = offset().unwrap_or(0) + page_length;
     if new_off < count {
         offset.set(Some(new_off));
-        set_index_to_zero(mode);
+        mode.selection().to_first();
     } else if is_key_pressed {
-        set_index_to_one_step_back(mode);
-    }
-}
-
-fn set_index_to_zero(mode: ReadOnlySignal<PagerMode>) {
-    if mode() != PagerMode::Words {
-        return;
-    }
-    if let Some(_) = SELECTED_WORD_INDEX() {
-        *SELECTED_WORD_INDEX.write() = Some(0)
-    }
-}
-
-fn set_index_to_max(mode: ReadOnlySignal<PagerMode>) {
-    if mode() != PagerMode::Words {
-        return;
-    }
-    if let Some(_) = SELECTED_WORD_INDEX() {
-        *SELECTED_WORD_INDEX.write() = Some(MAX_WORD_INDEX() - 1);
-    }
-}
-
-fn set_index_to_one_step_back(mode: ReadOnlySignal<PagerMode>) {
-    if mode() != PagerMode::Words {
-        return;
+        mode.selection().step_back();
     }
-    SELECTED_WORD_INDEX.with_mut(move |v| match *v {
-        Some(x) => *v = Some(x - 1),
-        None => {}
-    });
 }
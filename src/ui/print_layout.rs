@@ -0,0 +1,208 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_daisyui::prelude::*;
+use web_sys::console;
+
+use crate::i18n::Msg;
+use crate::print::{print_html, FontFamily, PaperSize, PrintMode, PrintOptions};
+use crate::storage::{StorageBackend, StorageError};
+use crate::storage_global::get_storage;
+use crate::ui::app_settings::use_settings;
+use crate::ui::error_message::storage_error_message;
+use crate::ui::export_data::open_print_window;
+use crate::ui::{current_locale, js_value_to_string, t};
+
+const FONT_SIZES: [u32; 4] = [10, 12, 14, 16];
+const DEFAULT_FONT_SIZE: u32 = 12;
+
+/// The words of a folder with its subfolders as a page to print, opened in a new tab
+#[component]
+pub(crate) fn PrintLayout() -> Element {
+    let mut error_str = use_signal(String::new);
+    let settings = use_settings();
+    let mut folder_id = use_signal(|| None::<u32>);
+    let mut mode = use_signal(PrintMode::default);
+    let mut paper_size = use_signal(PaperSize::default);
+    let mut font_family = use_signal(FontFamily::default);
+    let mut font_size = use_signal(|| DEFAULT_FONT_SIZE);
+
+    let folders = use_resource(move || async move {
+        get_storage().get_all_folders().await
+    });
+    let folders = match &*folders.read_unchecked() {
+        Some(Ok(folders)) => folders.clone(),
+        _ => vec![],
+    };
+
+    // the folder of the words view is chosen until another one is selected
+    let selected_id = folder_id()
+        .or_else(|| {
+            folders
+                .iter()
+                .find(|folder| folder.folder == settings().selected_folder)
+                .and_then(|folder| folder.id)
+        })
+        .or_else(|| folders.first().and_then(|folder| folder.id));
+
+    let open_print_layout = move |_| {
+        let Some(id) = selected_id else {
+            return;
+        };
+        let options = PrintOptions {
+            mode: mode(),
+            paper_size: paper_size(),
+            font_family: font_family(),
+            font_size: font_size(),
+            locale: current_locale(),
+        };
+        spawn(async move {
+            match print_folder(id, &options).await {
+                Ok(html) => if let Err(err) = open_print_window(&html) {
+                    console::warn_1(&err);
+                    error_str.set(js_value_to_string(&err));
+                },
+                Err(err) => error_str.set(storage_error_message(&err).to_string()),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: class!(text_base),
+            margin_top: "10px",
+            {t(Msg::PrintLayout)}
+            table { class: class!(text_sm),
+                margin_top: "5px",
+                tr {
+                    td { class: class!(pr_4), {t(Msg::Folders)} }
+                    td {
+                        select { class: class!(outline),
+                            aria_label: t(Msg::Folders),
+                            onchange: move |event| folder_id.set(event.value().parse().ok()),
+                            for folder in folders {
+                                option {
+                                    value: "{folder.id.unwrap_or_default()}",
+                                    selected: folder.id == selected_id,
+                                    "{folder.folder}"
+                                }
+                            }
+                        }
+                    }
+                }
+                tr {
+                    td { class: class!(pr_4), {t(Msg::PrintMode)} }
+                    td {
+                        select { class: class!(outline),
+                            aria_label: t(Msg::PrintMode),
+                            onchange: move |event| mode.set(match event.value().as_str() {
+                                "Flashcards" => PrintMode::Flashcards,
+                                _ => PrintMode::WordList,
+                            }),
+                            option {
+                                value: "WordList",
+                                selected: mode() == PrintMode::WordList,
+                                {t(Msg::PrintWordList)}
+                            }
+                            option {
+                                value: "Flashcards",
+                                selected: mode() == PrintMode::Flashcards,
+                                {t(Msg::PrintFlashcards)}
+                            }
+                        }
+                    }
+                }
+                tr {
+                    td { class: class!(pr_4), {t(Msg::PaperSize)} }
+                    td {
+                        select { class: class!(outline),
+                            aria_label: t(Msg::PaperSize),
+                            onchange: move |event| {
+                                if let Some(size) = PaperSize::ALL
+                                    .into_iter()
+                                    .find(|size| format!("{:?}", size) == event.value()) {
+                                    paper_size.set(size);
+                                }
+                            },
+                            for size in PaperSize::ALL {
+                                option {
+                                    value: "{size:?}",
+                                    selected: paper_size() == size,
+                                    "{size:?}"
+                                }
+                            }
+                        }
+                    }
+                }
+                tr {
+                    td { class: class!(pr_4), {t(Msg::FontFamily)} }
+                    td {
+                        select { class: class!(outline),
+                            aria_label: t(Msg::FontFamily),
+                            onchange: move |event| font_family.set(match event.value().as_str() {
+                                "SansSerif" => FontFamily::SansSerif,
+                                _ => FontFamily::Serif,
+                            }),
+                            option {
+                                value: "Serif",
+                                selected: font_family() == FontFamily::Serif,
+                                {t(Msg::FontSerif)}
+                            }
+                            option {
+                                value: "SansSerif",
+                                selected: font_family() == FontFamily::SansSerif,
+                                {t(Msg::FontSansSerif)}
+                            }
+                        }
+                    }
+                }
+                tr {
+                    td { class: class!(pr_4), {t(Msg::FontSize)} }
+                    td {
+                        select { class: class!(outline),
+                            aria_label: t(Msg::FontSize),
+                            onchange: move |event| font_size.set(event.value().parse().unwrap_or(DEFAULT_FONT_SIZE)),
+                            for size in FONT_SIZES {
+                                option {
+                                    value: "{size}",
+                                    selected: font_size() == size,
+                                    "{size} pt"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            button { class: class!(btn btn_sm),
+                margin_top: "5px",
+                disabled: selected_id.is_none(),
+                onclick: open_print_layout,
+                {t(Msg::OpenPrintLayout)}
+            }
+
+            p { class: class!(text_xs text_red_500),
+                "{error_str}"
+            }
+        }
+    }
+}
+
+async fn print_folder(id: u32, options: &PrintOptions) -> Result<String, StorageError> {
+    let data = get_storage().get_subtree_data(id).await?;
+    Ok(print_html(&data, options))
+}
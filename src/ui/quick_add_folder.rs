@@ -0,0 +1,91 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_daisyui::prelude::*;
+
+use crate::i18n::Msg;
+use crate::storage_global::get_storage;
+use crate::ui::{t, t_args};
+use crate::ui::app_settings::use_settings;
+use crate::ui::error_message::storage_error_message;
+
+#[component]
+pub(crate) fn QuickAddFolder() -> Element {
+    let mut settings = use_settings();
+    let mut folder_val = use_signal(String::new);
+    let mut message_str = use_signal(String::new);
+
+    use_effect(move || folder_val.set(settings().quick_add_folder));
+
+    rsx! {
+        div {
+            form { action: "",
+                onsubmit: move |event| async move {
+                    event.stop_propagation();
+                    let folder = folder_val().trim().to_string();
+                    match get_storage().get_folder_by_name(&folder).await {
+                        Ok(Some(_)) => {
+                            settings.write().quick_add_folder = folder.clone();
+                            message_str.set(t_args(Msg::QuickAddFolderUpdated, &[&folder]));
+                        }
+                        Ok(None) => message_str.set(t_args(Msg::QuickAddFolderNotFound, &[&folder])),
+                        Err(err) => message_str.set(storage_error_message(&err).to_string()),
+                    }
+                },
+                input { class: class!(outline),
+                    margin_top: "5px",
+                    oninput: move |event| folder_val.set(event.value()),
+                    placeholder: t(Msg::QuickAddFolderIsNotSet),
+                    r#type: "text",
+                    value: "{folder_val}"
+                }
+                button { class: class!(btn btn_sm btn_outline),
+                    margin_left: "5px",
+                    {t(Msg::Change)}
+                }
+                p { class: class!(text_xs),
+                    "{message_str}"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub(crate) fn LookupAfterSave() -> Element {
+    let mut settings = use_settings();
+
+    let lookup_after_save_memo = use_memo(move || match settings().lookup_after_save {
+        true => t(Msg::LookUpSavedSelections),
+        false => t(Msg::SavedSelectionsAreNotLookedUp),
+    });
+
+    rsx! {
+        form {
+            action: "",
+            onsubmit: move |event| event.stop_propagation(),
+            input {
+                r#type: "checkbox",
+                checked: settings().lookup_after_save,
+                onchange: move |event| settings.write().lookup_after_save = event.checked(),
+            }
+            " {lookup_after_save_memo}"
+        }
+    }
+}
@@ -20,7 +20,8 @@ use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
 use futures_util::StreamExt;
 
-use crate::ui::dictionaryLookup;
+use crate::i18n::Msg;
+use crate::ui::{dictionaryLookup, t};
 
 #[component]
 pub(crate) fn SearchForm(search_str: Signal<String>) -> Element {
@@ -43,16 +44,16 @@ pub(crate) fn SearchForm(search_str: Signal<String>) -> Element {
                     r#for: "search-text",
                     input { class: class!(outline min_w_52 flex_none),
                         oninput: move |event| search_str.set(event.value()),
-                        placeholder: "search",
+                        placeholder: t(Msg::SearchPlaceholder),
                         r#type: "text",
                         id: "search-text",
                         value: "{search_str}"
                     }
                 }
                 label {
-                    title: "Search",
+                    title: t(Msg::Search),
                     button { class: class!(btn btn_sm btn_outline self_center mx_2 flex_none),
-                        "Search"
+                        {t(Msg::Search)}
                     }
                 }
             }
@@ -18,10 +18,17 @@
 
 use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
-use dioxus_sdk::storage::{LocalStorage, use_synced_storage};
-use crate::ui::AUTOPLAY;
-use crate::ui::navigation::{DataProtection, NavigationState};
+use crate::i18n::{Locale, Msg};
+use crate::model::{DataProtection, ProtectedAction, Theme};
+use crate::ui::app_settings::use_settings;
+use crate::ui::check_database::CheckDatabase;
+use crate::ui::check_integrity::CheckIntegrity;
+use crate::ui::goals::DailyGoalForm;
+use crate::ui::keymap::KeyBindings;
+use crate::ui::navigation::NavigationState;
 use crate::ui::page_length::PageLength;
+use crate::ui::quick_add_folder::{LookupAfterSave, QuickAddFolder};
+use crate::ui::t;
 
 #[component]
 pub(crate) fn Settings(
@@ -29,20 +36,13 @@ pub(crate) fn Settings(
     folders_offset: Signal<Option<u32>>,
     words_page_length: Signal<Option<u32>>,
     words_offset: Signal<Option<u32>>,
-    data_protection: Signal<DataProtection>,
 ) -> Element {
     let navigation = use_coroutine_handle::<NavigationState>();
 
-    let data_protection_memo = use_memo(move || match data_protection() {
-        DataProtection::Unprotected => "You can delete folders and words",
-        DataProtection::Protected => "Uncheck to be able to delete folders and words",
-    });
-
-    let mut autoplay = use_synced_storage::<LocalStorage, bool>(
-        "autoplay".to_string(), || true);
+    let mut settings = use_settings();
 
     let autoplay_memo = use_memo(
-        move || if autoplay() { "Autoplay is enabled" } else { "Autoplay is disabled" });
+        move || t(if settings().autoplay { Msg::AutoplayIsEnabled } else { Msg::AutoplayIsDisabled }));
 
     rsx! {
         div { class: class!(text_base),
@@ -50,33 +50,98 @@ pub(crate) fn Settings(
             div {
                 button { class: class!(btn btn_sm),
                     onclick: move |_| navigation.send(NavigationState::ExportData),
-                    "Export"
+                    {t(Msg::Export)}
                 }
                 button { class: class!(btn btn_sm),
                     margin_left: "5px",
                     onclick: move |_| navigation.send(NavigationState::ImportData),
-                    "Import"
+                    {t(Msg::Import)}
+                }
+                button { class: class!(btn btn_sm),
+                    margin_left: "5px",
+                    onclick: move |_| navigation.send(NavigationState::PrintLayout),
+                    {t(Msg::PrintLayout)}
+                }
+            }
+
+            hr { margin_top: "10px" }
+            div {
+                margin_top: "10px",
+                {t(Msg::Language)}
+                div {
+                    margin_top: "5px",
+                    select { class: class!(outline),
+                        onchange: move |event| {
+                            settings.write().locale = Locale::ALL
+                                .into_iter()
+                                .find(|locale| locale.code() == event.value());
+                        },
+                        option {
+                            value: "",
+                            selected: settings().locale.is_none(),
+                            {t(Msg::BrowserLanguage)}
+                        }
+                        for locale in Locale::ALL {
+                            option {
+                                value: locale.code(),
+                                selected: settings().locale == Some(locale),
+                                {locale.name()}
+                            }
+                        }
+                    }
+                }
+            }
+
+            hr { margin_top: "10px" }
+            div {
+                margin_top: "10px",
+                {t(Msg::Theme)}
+                div {
+                    margin_top: "5px",
+                    select { class: class!(outline),
+                        onchange: move |event| {
+                            if let Some(theme) = Theme::ALL
+                                .into_iter()
+                                .find(|theme| format!("{:?}", theme) == event.value()) {
+                                settings.write().theme = theme;
+                            }
+                        },
+                        for theme in Theme::ALL {
+                            option {
+                                value: "{theme:?}",
+                                selected: settings().theme == theme,
+                                {t(theme_name(theme))}
+                            }
+                        }
+                    }
                 }
             }
 
             hr { margin_top: "10px" }
             div {
                 margin_top: "10px",
-                "Page length"
+                {t(Msg::DailyGoal)}
+                DailyGoalForm {}
+            }
+
+            hr { margin_top: "10px" }
+            div {
+                margin_top: "10px",
+                {t(Msg::PageLength)}
                 div {
                     margin_top: "5px",
-                    "Folders"
+                    {t(Msg::Folders)}
                     PageLength {
-                        placeholder_str: "page length for folders",
+                        placeholder_str: Msg::PageLengthForFolders,
                         page_length: folders_page_length,
                         offset: folders_offset,
                     }
                 }
                 div {
                     margin_top: "5px",
-                    "Words"
+                    {t(Msg::Words)}
                     PageLength {
-                        placeholder_str: "page length for words",
+                        placeholder_str: Msg::PageLengthForWords,
                         page_length: words_page_length,
                         offset: words_offset,
                     }
@@ -86,23 +151,32 @@ pub(crate) fn Settings(
             hr { margin_top: "10px" }
             div {
                 margin_top: "10px",
-                "Data protection"
-                div {
+                {t(Msg::DataProtection)}
+                table { class: class!(text_sm),
                     margin_top: "5px",
-                    form {
-                        action: "",
-                        onsubmit: move |event| event.stop_propagation(),
-                        input {
-                            r#type: "checkbox",
-                            checked: data_protection() == DataProtection::Protected,
-                            onchange: move |event| {
-                                match event.checked() {
-                                    true => data_protection.set(DataProtection::Protected),
-                                    false => data_protection.set(DataProtection::Unprotected),
+                    for action in ProtectedAction::ALL {
+                        tr {
+                            td { class: class!(pr_4), {t(protected_action_name(action))} }
+                            td {
+                                select { class: class!(outline),
+                                    aria_label: t(protected_action_name(action)),
+                                    onchange: move |event| {
+                                        if let Some(protection) = DataProtection::ALL
+                                            .into_iter()
+                                            .find(|protection| format!("{:?}", protection) == event.value()) {
+                                            settings.write().set_protection(action, protection);
+                                        }
+                                    },
+                                    for protection in DataProtection::ALL {
+                                        option {
+                                            value: "{protection:?}",
+                                            selected: settings().protection(action) == protection,
+                                            {t(protection_name(protection))}
+                                        }
+                                    }
                                 }
-                            },
+                            }
                         }
-                        " {data_protection_memo}"
                     }
                 }
             }
@@ -110,7 +184,7 @@ pub(crate) fn Settings(
             hr { margin_top: "10px" }
             div {
                 margin_top: "10px",
-                "Autoplay"
+                {t(Msg::Autoplay)}
                 div {
                     margin_top: "5px",
                     form {
@@ -118,17 +192,79 @@ pub(crate) fn Settings(
                         onsubmit: move |event| event.stop_propagation(),
                         input {
                             r#type: "checkbox",
-                            checked: autoplay(),
-                            onchange: move |event| {
-                                AUTOPLAY.with_mut(move |v| *v = event.checked());
-                                autoplay.set(AUTOPLAY());
-                            },
+                            checked: settings().autoplay,
+                            onchange: move |event| settings.write().autoplay = event.checked(),
                         }
                         " {autoplay_memo}"
                     }
                 }
             }
 
+            hr { margin_top: "10px" }
+            div {
+                margin_top: "10px",
+                {t(Msg::QuickAdd)}
+                div {
+                    margin_top: "5px",
+                    {t(Msg::QuickAddFolderHint)}
+                    QuickAddFolder {}
+                }
+                div {
+                    margin_top: "5px",
+                    LookupAfterSave {}
+                }
+            }
+
+            hr { margin_top: "10px" }
+            div {
+                margin_top: "10px",
+                {t(Msg::KeyboardShortcuts)}
+                div {
+                    margin_top: "5px",
+                    KeyBindings {}
+                }
+            }
+
+            hr { margin_top: "10px" }
+            div {
+                margin_top: "10px",
+                {t(Msg::Database)}
+                div {
+                    margin_top: "5px",
+                    CheckDatabase {}
+                }
+                div {
+                    margin_top: "5px",
+                    CheckIntegrity {}
+                }
+            }
+
         }
     }
 }
+
+fn protected_action_name(action: ProtectedAction) -> Msg {
+    match action {
+        ProtectedAction::DeleteWord => Msg::ProtectDeleteWord,
+        ProtectedAction::DeleteFolder => Msg::ProtectDeleteFolder,
+        ProtectedAction::DeleteSmartFolder => Msg::ProtectDeleteSmartFolder,
+    }
+}
+
+fn protection_name(protection: DataProtection) -> Msg {
+    match protection {
+        DataProtection::Confirm => Msg::ProtectionConfirm,
+        DataProtection::TypeName => Msg::ProtectionTypeName,
+        DataProtection::Protected => Msg::ProtectionProtected,
+        DataProtection::Unprotected => Msg::ProtectionUnprotected,
+    }
+}
+
+fn theme_name(theme: Theme) -> Msg {
+    match theme {
+        Theme::System => Msg::ThemeSystem,
+        Theme::Light => Msg::ThemeLight,
+        Theme::Dark => Msg::ThemeDark,
+        Theme::HighContrast => Msg::ThemeHighContrast,
+    }
+}
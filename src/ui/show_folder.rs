@@ -15,47 +15,145 @@
  */
 
 #![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
 
 use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
 use dioxus_free_icons::Icon;
-use dioxus_free_icons::icons::fi_icons::FiTrash;
+use dioxus_free_icons::icons::fi_icons::{
+    FiArchive, FiAward, FiBook, FiBookmark, FiChevronDown, FiChevronRight, FiDownload, FiEdit2,
+    FiFlag, FiHeart, FiStar, FiTrash,
+};
+use web_sys::console;
 
-use crate::model::{Folder, FolderKey};
-use crate::ui::navigation::NavigationState;
-use crate::ui::SELECTED_WORD_INDEX;
+use crate::model::{Folder, FolderColor, FolderIcon, FolderKey, FolderStats};
+use crate::storage::StorageBackend;
+use crate::storage_global::get_storage;
+use crate::ui::export_data::open_download_window;
+use crate::ui::folders::{FolderTree, SubFolders};
+use crate::ui::navigation::{NavigationMessage, NavigationState};
+use crate::ui::SELECTED_SMART_FOLDER;
+use crate::ui::list_selection::{FOLDERS_SELECTION, WORDS_SELECTION};
+use crate::ui::{t, Msg};
+
+const folder_colors: [(FolderColor, &str, &str, Msg); 6] = [
+    (FolderColor::Red, "red", "#ef4444", Msg::ColourRed),
+    (FolderColor::Orange, "orange", "#f97316", Msg::ColourOrange),
+    (FolderColor::Yellow, "yellow", "#eab308", Msg::ColourYellow),
+    (FolderColor::Green, "green", "#22c55e", Msg::ColourGreen),
+    (FolderColor::Blue, "blue", "#3b82f6", Msg::ColourBlue),
+    (FolderColor::Purple, "purple", "#a855f7", Msg::ColourPurple),
+];
+
+const folder_icons: [(FolderIcon, &str, Msg); 6] = [
+    (FolderIcon::Book, "book", Msg::IconBook),
+    (FolderIcon::Bookmark, "bookmark", Msg::IconBookmark),
+    (FolderIcon::Star, "star", Msg::IconStar),
+    (FolderIcon::Flag, "flag", Msg::IconFlag),
+    (FolderIcon::Heart, "heart", Msg::IconHeart),
+    (FolderIcon::Award, "award", Msg::IconAward),
+];
 
 #[component]
 pub(crate) fn ShowFolder(
     index: i32,
-    folder: ReadOnlySignal<Folder>,
+    folder_stats: ReadOnlySignal<FolderStats>,
+    depth: u32,
+    tree: ReadOnlySignal<FolderTree>,
     selected_folder_str: Signal<String>,
     words_page_offset: Signal<Option<u32>>,
 ) -> Element {
-    let navigation = use_coroutine_handle::<NavigationState>();
+    let navigation_message = use_coroutine_handle::<NavigationMessage>();
     let folder_key = use_coroutine_handle::<FolderKey>();
 
-    let id = folder().id.unwrap();
-    let folder_str = folder().folder;
-    let folder_note_str = folder().folder_note;
+    let FolderStats { folder, word_count, subtree_word_count, subfolder_count, .. } = folder_stats();
+    let id = folder.id.unwrap();
+    let folder_str = folder.folder.clone();
+    let folder_name = folder.name().to_string();
+    let folder_note_str = folder.folder_note.clone();
+
+    let mut show_label_form = use_signal(|| false);
+    let refresh_folders = tree().refresh_folders;
+
+    let border_color = folder
+        .color
+        .and_then(|color| folder_colors.iter().find(|x| x.0 == color))
+        .map_or("transparent", |x| x.2);
+    let pinned_color = if folder.pinned { "text-yellow-500" } else { "" };
+    let archived_opacity = if folder.archived { "opacity-50" } else { "" };
+
+    let mut expanded_folders = tree().expanded_folders;
+    let has_subfolders = tree().enabled && subfolder_count > 0;
+    let expanded = has_subfolders && expanded_folders.read().contains(&id);
+
+    let word_count_str = match subfolder_count {
+        0 => word_count.to_string(),
+        _ => format!("{} / {}", word_count, subtree_word_count),
+    };
 
     let background_color = if let 0 = index % 2 { "lists-second-style" } else { "" };
+    // only the folders of the page are selected with the keyboard, not the expanded subfolders
+    let selected_folder_index = use_memo(move || *FOLDERS_SELECTION.index.read());
+    let selectable = depth == 0;
+    let selected = selectable && selected_folder_index() == Some(index);
+    let selected_folder = if selected { "lists-selected-style" } else { "" };
+
+    let expand_title = t(if expanded { Msg::Collapse } else { Msg::Expand });
+    let pin_title = t(if folder.pinned { Msg::Unpin } else { Msg::PinToTop });
+    let archive_title = t(if folder.archived { Msg::RestoreFromArchive } else { Msg::ArchiveWithSubfolders });
 
     rsx! {
-        div { class: class!(flex flex_wrap items_baseline min_h_12 background_color),
+        div { class: class!(flex flex_wrap items_baseline min_h_12 background_color archived_opacity selected_folder),
+            id: selectable.then(|| FOLDERS_SELECTION.item_id(index)),
+            role: selectable.then_some("option"),
+            aria_selected: selectable.then_some(selected),
             margin: "1px",
+            padding_left: "{depth * 20}px",
+            border_left: "4px solid {border_color}",
             onclick: move |_| {
-                if selected_folder_str() != folder_str {
-                    words_page_offset.set(None);
-                    *SELECTED_WORD_INDEX.write() = None;
+                if selectable {
+                    FOLDERS_SELECTION.select(index);
                 }
-                selected_folder_str.set(folder_str.to_owned());
-                navigation.send(NavigationState::Words);
+                open_folder(&folder_str, selected_folder_str, words_page_offset);
             },
+            div { class: class!(flex_none),
+                min_width: "20px",
+                if has_subfolders {
+                    button { class: class!(inline_block),
+                        title: expand_title,
+                        aria_label: expand_title,
+                        aria_expanded: expanded,
+                        onclick: move |event| {
+                            event.stop_propagation();
+                            let mut expanded_folders = expanded_folders.write();
+                            if !expanded_folders.remove(&id) {
+                                expanded_folders.insert(id);
+                            }
+                        },
+                        if expanded {
+                            Icon { height: 16, width: 16, icon: FiChevronDown }
+                        } else {
+                            Icon { height: 16, width: 16, icon: FiChevronRight }
+                        }
+                    }
+                }
+            }
             div { class: class!(flex_auto),
                 margin: "2px",
+                if let Some(icon) = folder.icon {
+                    span { class: class!(inline_block),
+                        margin_right: "5px",
+                        aria_hidden: "true",
+                        FolderIconView { icon }
+                    }
+                }
                 button { class: class!(underline),
-                    "{folder_str}"
+                    "{folder_name}"
+                }
+                span { class: class!(badge badge_ghost badge_sm),
+                    margin_left: "5px",
+                    title: t(Msg::WordCountTitle),
+                    "{word_count_str}"
                 }
             }
             div { class: class!(flex_auto text_xs),
@@ -63,7 +161,80 @@ pub(crate) fn ShowFolder(
             }
             div { class: class!(flex_none),
                 margin: "2px",
+                button { class: class!(inline_block pinned_color),
+                    title: pin_title,
+                    aria_label: pin_title,
+                    aria_pressed: folder.pinned,
+                    onclick: {
+                        let folder = folder.clone();
+                        move |event: Event<MouseData>| {
+                            event.stop_propagation();
+                            save_folder(Folder { pinned: !folder.pinned, ..folder.clone() }, refresh_folders);
+                        }
+                    },
+                    Icon {
+                        height: 16,
+                        width: 16,
+                        icon: FiStar,
+                    }
+                }
+                button { class: class!(inline_block),
+                    margin_left: "5px",
+                    title: t(Msg::ColourAndIcon),
+                    aria_label: t(Msg::ColourAndIcon),
+                    aria_expanded: show_label_form(),
+                    onclick: move |event| {
+                        event.stop_propagation();
+                        show_label_form.toggle();
+                    },
+                    Icon {
+                        height: 16,
+                        width: 16,
+                        icon: FiEdit2,
+                    }
+                }
+                button { class: class!(inline_block),
+                    margin_left: "5px",
+                    title: archive_title,
+                    aria_label: archive_title,
+                    onclick: {
+                        let folder = folder.clone();
+                        move |event: Event<MouseData>| {
+                            event.stop_propagation();
+                            save_folder(Folder { archived: !folder.archived, ..folder.clone() }, refresh_folders);
+                        }
+                    },
+                    Icon {
+                        height: 16,
+                        width: 16,
+                        icon: FiArchive,
+                    }
+                }
+                button { class: class!(inline_block),
+                    margin_left: "5px",
+                    title: t(Msg::ExportWithSubfolders),
+                    aria_label: t(Msg::ExportWithSubfolders),
+                    onclick: move |event| {
+                        event.stop_propagation();
+                        spawn(async move {
+                            match get_storage().export_subtree(id).await {
+                                Ok(data) => if let Err(err) = open_download_window(&data) {
+                                    console::warn_1(&err);
+                                },
+                                Err(err) => navigation_message.send(NavigationMessage::storage_error(&err)),
+                            }
+                        });
+                    },
+                    Icon {
+                        height: 16,
+                        width: 16,
+                        icon: FiDownload,
+                    }
+                }
                 button { class: class!(inline_block),
+                    margin_left: "5px",
+                    title: t(Msg::DeleteWithSubfolders),
+                    aria_label: t(Msg::DeleteWithSubfolders),
                     onclick: move |event| {
                         event.stop_propagation();
                         folder_key.send(FolderKey{ id });
@@ -76,5 +247,106 @@ pub(crate) fn ShowFolder(
                 }
             }
         }
+        if show_label_form() {
+            FolderLabelForm {
+                folder: folder.clone(),
+                depth,
+                refresh_folders,
+            }
+        }
+        if expanded {
+            SubFolders {
+                parent_id: id,
+                depth: depth + 1,
+                tree,
+                selected_folder_str,
+                words_page_offset,
+            }
+        }
     }
 }
+
+#[component]
+fn FolderLabelForm(folder: ReadOnlySignal<Folder>, depth: u32, refresh_folders: Signal<u8>) -> Element {
+    rsx! {
+        div { class: class!(flex flex_wrap items_center gap_2 text_sm),
+            margin: "1px",
+            padding_left: "{depth * 20 + 24}px",
+            select { class: class!(outline),
+                aria_label: t(Msg::FolderColour),
+                onchange: move |event| {
+                    let color = folder_colors.iter().find(|x| x.1 == event.value()).map(|x| x.0);
+                    save_folder(Folder { color, ..folder() }, refresh_folders);
+                },
+                option {
+                    value: "",
+                    selected: folder().color.is_none(),
+                    {t(Msg::NoColour)}
+                }
+                for (color, color_str, _, color_name) in folder_colors {
+                    option {
+                        value: "{color_str}",
+                        selected: folder().color == Some(color),
+                        {t(color_name)}
+                    }
+                }
+            }
+            select { class: class!(outline),
+                aria_label: t(Msg::FolderIcon),
+                onchange: move |event| {
+                    let icon = folder_icons.iter().find(|x| x.1 == event.value()).map(|x| x.0);
+                    save_folder(Folder { icon, ..folder() }, refresh_folders);
+                },
+                option {
+                    value: "",
+                    selected: folder().icon.is_none(),
+                    {t(Msg::NoIcon)}
+                }
+                for (icon, icon_str, icon_name) in folder_icons {
+                    option {
+                        value: "{icon_str}",
+                        selected: folder().icon == Some(icon),
+                        {t(icon_name)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn FolderIconView(icon: FolderIcon) -> Element {
+    match icon {
+        FolderIcon::Book => rsx! { Icon { height: 16, width: 16, icon: FiBook } },
+        FolderIcon::Bookmark => rsx! { Icon { height: 16, width: 16, icon: FiBookmark } },
+        FolderIcon::Star => rsx! { Icon { height: 16, width: 16, icon: FiStar } },
+        FolderIcon::Flag => rsx! { Icon { height: 16, width: 16, icon: FiFlag } },
+        FolderIcon::Heart => rsx! { Icon { height: 16, width: 16, icon: FiHeart } },
+        FolderIcon::Award => rsx! { Icon { height: 16, width: 16, icon: FiAward } },
+    }
+}
+
+/// Shows the words of the folder
+pub(crate) fn open_folder(
+    folder_str: &str,
+    mut selected_folder_str: Signal<String>,
+    mut words_page_offset: Signal<Option<u32>>,
+) {
+    if *selected_folder_str.peek() != folder_str {
+        words_page_offset.set(None);
+        WORDS_SELECTION.clear();
+    }
+    selected_folder_str.set(folder_str.to_owned());
+    *SELECTED_SMART_FOLDER.write() = None;
+    consume_context::<Coroutine<NavigationState>>().send(NavigationState::Words);
+}
+
+fn save_folder(folder: Folder, mut refresh_folders: Signal<u8>) {
+    let navigation_message = consume_context::<Coroutine<NavigationMessage>>();
+    spawn(async move {
+        if let Err(err) = get_storage().update(&folder).await {
+            navigation_message.send(NavigationMessage::storage_error(&err));
+        }
+        refresh_folders.toggle();
+    });
+}
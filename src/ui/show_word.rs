@@ -19,11 +19,14 @@
 use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
 use dioxus_free_icons::Icon;
-use dioxus_free_icons::icons::fi_icons::{FiSearch, FiTrash};
-use dioxus_sdk::storage::{LocalStorage, use_synced_storage};
+use dioxus_free_icons::icons::fi_icons::{FiInfo, FiSearch, FiTrash};
 use crate::model::{Word, WordKey};
-use crate::ui::{SELECTED_WORD_INDEX, dictionaryLookup, openUrl, msg_use_arrow_keys_to_navigate};
-use crate::ui::navigation::{NAVIGATION_MESSAGE_NOTIFICATION, NavigationMessage};
+use crate::i18n::Msg;
+use crate::ui::{dictionaryLookup, openUrl, t};
+use crate::ui::list_selection::WORDS_SELECTION;
+use crate::ui::app_settings::use_settings;
+use crate::ui::markdown_text::MarkdownText;
+use crate::ui::navigation::{NAVIGATION_MESSAGE_NOTIFICATION, NavigationMessage, NavigationState};
 
 #[component]
 pub(crate) fn ShowWord(
@@ -31,16 +34,13 @@ pub(crate) fn ShowWord(
     word: ReadOnlySignal<Word>,
 ) -> Element {
     let navigation_message = use_coroutine_handle::<NavigationMessage>();
-    let mut show_use_keyboard_message = use_synced_storage::<LocalStorage, bool>(
-        "show_use_keyboard_message".to_string(), || true);
+    let mut settings = use_settings();
 
     let word_key = use_coroutine_handle::<WordKey>();
+    let navigation = use_coroutine_handle::<NavigationState>();
 
-    let selected_word_index = use_memo(move || SELECTED_WORD_INDEX());
-    let is_selected = match selected_word_index() {
-        None => false,
-        Some(v) => index == v
-    };
+    let selected_word_index = use_memo(move || *WORDS_SELECTION.index.read());
+    let is_selected = selected_word_index() == Some(index);
 
     if is_selected {
         spawn(openUrl(word().url.clone()));
@@ -50,6 +50,8 @@ pub(crate) fn ShowWord(
     let word_str = word().word;
     let word_class = word().word_class;
     let note = word().note;
+    let source_context = word().source_context;
+    let examples = word().examples;
     let url = word().url;
 
     let background_color = if let 0 = index % 2 { "lists-second-style" } else { "" };
@@ -57,24 +59,25 @@ pub(crate) fn ShowWord(
 
     rsx! {
         div { class: class!(flex flex_wrap items_baseline min_h_12 background_color selected_word),
-            id: "word-{index}",
-            tabindex: "-1",
+            id: WORDS_SELECTION.item_id(index),
+            role: "option",
+            aria_selected: "{is_selected}",
             margin: "1px",
             onclick: move |_| {
-                *SELECTED_WORD_INDEX.write() = Some(index);
-                if show_use_keyboard_message() {
-                    show_use_keyboard_message.set(false);
+                WORDS_SELECTION.select(index);
+                if settings.peek().show_use_keyboard_message {
+                    settings.write().show_use_keyboard_message = false;
                     navigation_message.send(NavigationMessage {
-                        message: msg_use_arrow_keys_to_navigate,
+                        message: t(Msg::UseArrowKeysToNavigate),
                         color: NAVIGATION_MESSAGE_NOTIFICATION
                     });
                 }
             },
             div { class: class!(flex_none),
-                tabindex: "-1",
                 margin_top: "1px",
                 button {
-                    tabindex: "0",
+                    title: t(Msg::LookUpInDictionary),
+                    aria_label: t(Msg::LookUpInDictionary),
                     onclick: move |_| { spawn(dictionaryLookup(word_str.clone())); },
                     Icon {
                         height: 16,
@@ -84,28 +87,54 @@ pub(crate) fn ShowWord(
                 }
             }
             div { class: class!(flex_1),
-                tabindex: "-1",
                 margin: "2px",
                 button { class: class!(inline_block underline),
-                    tabindex: "0",
                     onclick: move |_| { spawn(openUrl(url.clone())); },
                     "{word_str}"
                 }
                 p { class: class!(text_xs),
-                    tabindex: "-1",
                     "{word_class}"
                 }
             }
             div { class: class!(flex_1),
-                tabindex: "-1",
                 margin: "2px",
-                "{note}"
+                MarkdownText { text: note }
+                if !examples.is_empty() {
+                    ul { class: class!(text_sm list_disc list_inside),
+                        for example in examples {
+                            li { class: class!(italic),
+                                "{example}"
+                            }
+                        }
+                    }
+                }
+                if !source_context.is_empty() {
+                    p { class: class!(text_xs italic),
+                        "{source_context}"
+                    }
+                }
+            }
+            div { class: class!(flex_none),
+                margin: "2px",
+                button { class: class!(inline_block),
+                    title: t(Msg::WordDetails),
+                    aria_label: t(Msg::WordDetails),
+                    onclick: move |event| {
+                        event.stop_propagation();
+                        navigation.send(NavigationState::WordDetail(id));
+                    },
+                    Icon {
+                        height: 16,
+                        width: 16,
+                        icon: FiInfo,
+                    }
+                }
             }
             div { class: class!(flex_none),
-                tabindex: "-1",
                 margin: "2px",
                 button { class: class!(inline_block),
-                    tabindex: "0",
+                    title: t(Msg::DeleteWord),
+                    aria_label: t(Msg::DeleteWord),
                     onclick: move |event| {
                         event.stop_propagation();
                         word_key.send(WordKey{ id });
@@ -0,0 +1,290 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+use chrono::Utc;
+use dioxus::prelude::*;
+use dioxus_daisyui::prelude::*;
+use dioxus_free_icons::Icon;
+use dioxus_free_icons::icons::fi_icons::FiTrash;
+
+use crate::model::{QueryCondition, SmartFolder, SmartFolderKey, SmartFolderStats};
+use crate::storage::StorageBackend;
+use crate::storage_global::get_storage;
+use crate::ui::error_message::StorageErrorMessage;
+use crate::ui::navigation::{NavigationMessage, NavigationState};
+use crate::i18n::Msg;
+use crate::ui::{t, t_args, SELECTED_SMART_FOLDER};
+use crate::ui::list_selection::WORDS_SELECTION;
+
+
+/// The smart folders with their word counts and the form to add a new one
+#[component]
+pub(crate) fn SmartFolders(
+    refresh_folders: Signal<u8>,
+    words_page_offset: Signal<Option<u32>>,
+) -> Element {
+    let smart_folders = use_resource(move || async move {
+        let _ = refresh_folders();
+        get_storage().get_smart_folders(Utc::now().timestamp_millis()).await
+    });
+
+    let smart_folders_to_show = match &*smart_folders.read_unchecked() {
+        None => rsx! {},
+        Some(Err(err)) => rsx! {
+            StorageErrorMessage {
+                error: err.clone()
+            }
+        },
+        Some(Ok(result)) => rsx! {
+            for (index, smart_folder_stats) in result.iter().enumerate() {
+                ShowSmartFolder {
+                    key: "{smart_folder_stats.smart_folder.id:?}",
+                    index: index as i32,
+                    smart_folder_stats: smart_folder_stats.to_owned(),
+                    words_page_offset,
+                }
+            }
+        },
+    };
+
+    rsx! {
+        div { class: class!(text_base),
+            margin_top: "5px",
+            details {
+                summary { class: class!(text_sm),
+                    {t(Msg::SmartFolders)}
+                }
+                AddSmartFolderForm {
+                    refresh_folders,
+                }
+                {smart_folders_to_show}
+            }
+        }
+    }
+}
+
+#[component]
+fn ShowSmartFolder(
+    index: i32,
+    smart_folder_stats: ReadOnlySignal<SmartFolderStats>,
+    words_page_offset: Signal<Option<u32>>,
+) -> Element {
+    let navigation = use_coroutine_handle::<NavigationState>();
+    let smart_folder_key = use_coroutine_handle::<SmartFolderKey>();
+
+    let SmartFolderStats { smart_folder, word_count } = smart_folder_stats();
+    let Some(id) = smart_folder.id else {
+        return VNode::empty();
+    };
+    let name_str = smart_folder.name;
+    let conditions_str = conditions_to_string(&smart_folder.conditions);
+
+    let background_color = if let 0 = index % 2 { "lists-second-style" } else { "" };
+
+    rsx! {
+        div { class: class!(flex flex_wrap items_baseline min_h_12 background_color),
+            margin: "1px",
+            onclick: move |_| {
+                words_page_offset.set(None);
+                WORDS_SELECTION.clear();
+                *SELECTED_SMART_FOLDER.write() = Some(id);
+                navigation.send(NavigationState::Words);
+            },
+            div { class: class!(flex_auto),
+                margin: "2px",
+                button { class: class!(underline italic),
+                    "{name_str}"
+                }
+                span { class: class!(badge badge_ghost badge_sm),
+                    margin_left: "5px",
+                    "{word_count}"
+                }
+            }
+            div { class: class!(flex_auto text_xs),
+                "{conditions_str}"
+            }
+            div { class: class!(flex_none),
+                margin: "2px",
+                button { class: class!(inline_block),
+                    title: t(Msg::DeleteSmartFolderKeepWords),
+                    onclick: move |event| {
+                        event.stop_propagation();
+                        smart_folder_key.send(SmartFolderKey { id });
+                    },
+                    Icon {
+                        height: 16,
+                        width: 16,
+                        icon: FiTrash,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn AddSmartFolderForm(refresh_folders: Signal<u8>) -> Element {
+    let navigation_message = use_coroutine_handle::<NavigationMessage>();
+
+    let mut name_str = use_signal(String::new);
+    let mut added_within_days_str = use_signal(String::new);
+    let mut word_class_str = use_signal(String::new);
+    let mut tag_str = use_signal(String::new);
+    let mut due_for_review = use_signal(|| false);
+    let mut no_note = use_signal(|| false);
+    let mut error_str = use_signal(String::new);
+
+    let conditions = move || {
+        let mut conditions = vec![];
+        if let Ok(days) = added_within_days_str().trim().parse::<u32>() {
+            conditions.push(QueryCondition::AddedWithinDays(days));
+        }
+        if !word_class_str().trim().is_empty() {
+            conditions.push(QueryCondition::WordClass(word_class_str().trim().to_string()));
+        }
+        if !tag_str().trim().is_empty() {
+            conditions.push(QueryCondition::Tag(tag_str().trim().to_string()));
+        }
+        if due_for_review() {
+            conditions.push(QueryCondition::DueForReview);
+        }
+        if no_note() {
+            conditions.push(QueryCondition::NoNote);
+        }
+        conditions
+    };
+
+    rsx! {
+        form { class: class!(flex flex_wrap items_center gap_2 text_sm mx_1 my_2),
+            action: "",
+            onsubmit: move |event| {
+                event.stop_propagation();
+
+                let conditions = conditions();
+                if name_str().trim().is_empty() {
+                    error_str.set(t(Msg::FolderNameIsEmpty).to_string());
+                } else if conditions.is_empty() {
+                    error_str.set(t(Msg::NoConditions).to_string());
+                } else {
+                    let smart_folder = SmartFolder::new(name_str().trim(), conditions);
+                    spawn(async move {
+                        match get_storage().add::<SmartFolder>(&smart_folder).await {
+                            Ok(_) => {
+                                name_str.set(String::new());
+                                added_within_days_str.set(String::new());
+                                word_class_str.set(String::new());
+                                tag_str.set(String::new());
+                                due_for_review.set(false);
+                                no_note.set(false);
+                                error_str.set(String::new());
+                            }
+                            Err(err) => navigation_message.send(NavigationMessage::storage_error(&err)),
+                        }
+                        refresh_folders.toggle();
+                    });
+                }
+            },
+            input { class: class!(outline min_w_52),
+                oninput: move |event| name_str.set(event.value()),
+                placeholder: t(Msg::NewSmartFolderName),
+                r#type: "text",
+                value: "{name_str}"
+            }
+            input { class: class!(outline w_24),
+                oninput: move |event| added_within_days_str.set(event.value()),
+                placeholder: t(Msg::AddedInDays),
+                r#type: "number",
+                min: "0",
+                value: "{added_within_days_str}"
+            }
+            input { class: class!(outline w_32),
+                oninput: move |event| word_class_str.set(event.value()),
+                placeholder: t(Msg::WordClass),
+                r#type: "text",
+                value: "{word_class_str}"
+            }
+            input { class: class!(outline w_32),
+                oninput: move |event| tag_str.set(event.value()),
+                placeholder: t(Msg::TagInNotes),
+                r#type: "text",
+                value: "{tag_str}"
+            }
+            label {
+                input {
+                    r#type: "checkbox",
+                    checked: due_for_review(),
+                    onchange: move |event| due_for_review.set(event.checked()),
+                }
+                " {t(Msg::DueForReview)}"
+            }
+            label {
+                input {
+                    r#type: "checkbox",
+                    checked: no_note(),
+                    onchange: move |event| no_note.set(event.checked()),
+                }
+                " {t(Msg::NoNoteYet)}"
+            }
+            button { class: class!(btn btn_sm btn_outline),
+                {t(Msg::AddSmartFolder)}
+            }
+            div { class: class!(text_xs text_red_500 w_full),
+                "{error_str}"
+            }
+        }
+    }
+}
+
+/// The name of the selected smart folder in the Words view
+#[component]
+pub(crate) fn SmartFolderName(id: ReadOnlySignal<u32>) -> Element {
+    let smart_folder = use_resource(move || async move {
+        get_storage().get_smart_folder_by_id(id()).await
+    });
+
+    let name = match &*smart_folder.read_unchecked() {
+        Some(Ok(Some(smart_folder))) => smart_folder.name.clone(),
+        _ => String::new(),
+    };
+
+    rsx! {
+        div { class: class!(text_base),
+            margin_top: "5px",
+            {t(Msg::SmartFolderPath)}
+            span { class: class!(italic),
+                "{name}"
+            }
+        }
+    }
+}
+
+fn conditions_to_string(conditions: &[QueryCondition]) -> String {
+    conditions
+        .iter()
+        .map(|condition| match condition {
+            QueryCondition::AddedWithinDays(days) => t_args(Msg::ConditionAddedWithinDays, &[&days.to_string()]),
+            QueryCondition::WordClass(word_class) => t_args(Msg::ConditionWordClass, &[word_class]),
+            QueryCondition::Tag(tag) => format!("#{}", tag.trim_start_matches('#')),
+            QueryCondition::DueForReview => t(Msg::DueForReview).to_string(),
+            QueryCondition::NoNote => t(Msg::NoNoteYet).to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,217 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![allow(non_snake_case)]
+
+use chrono::{Local, Utc};
+use dioxus::prelude::*;
+use dioxus_daisyui::prelude::*;
+
+use crate::i18n::Msg;
+use crate::stats::{day_to_string, largest};
+use crate::storage::StorageBackend;
+use crate::storage_global::get_storage;
+use crate::ui::error_message::StorageErrorMessage;
+use crate::ui::{t, t_args};
+
+const DAY_COUNT: i64 = 30;
+const WEEK_COUNT: i64 = 12;
+/// The folders and word classes with the most words
+const BAR_LIST_LENGTH: usize = 10;
+
+const CHART_HEIGHT: u32 = 100;
+const COLUMN_WIDTH: u32 = 10;
+const BAR_WIDTH: u32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Period {
+    Days,
+    Weeks,
+}
+
+#[component]
+pub(crate) fn Stats(refresh_words: Signal<u8>) -> Element {
+    let mut period = use_signal(|| Period::Days);
+
+    let word_stats = use_resource(move || async move {
+        let _ = refresh_words();
+        get_storage()
+            .get_word_stats(Local::now().offset().local_minus_utc())
+            .await
+    });
+
+    let word_stats = match &*word_stats.read_unchecked() {
+        None => return VNode::empty(),
+        Some(Err(err)) => return rsx! {
+            StorageErrorMessage {
+                error: err.clone()
+            }
+        },
+        Some(Ok(word_stats)) => word_stats.clone(),
+    };
+
+    let today = word_stats.day(Utc::now().timestamp_millis());
+    let (chart_title, columns) = match period() {
+        Period::Days => (Msg::StatsAddedPerDay, word_stats.last_days(today, DAY_COUNT)),
+        Period::Weeks => (Msg::StatsAddedPerWeek, word_stats.last_weeks(today, WEEK_COUNT)),
+    };
+    let columns: Vec<(String, u32)> = columns
+        .into_iter()
+        .map(|(day, added)| (day_to_string(day), added))
+        .collect();
+
+    let word_classes = largest(&word_stats.per_word_class, BAR_LIST_LENGTH)
+        .into_iter()
+        .map(|(word_class, count)| match word_class.is_empty() {
+            true => (t(Msg::StatsNoWordClass).to_string(), count),
+            false => (word_class, count),
+        })
+        .collect();
+
+    let most_reviewed = word_stats.most_reviewed();
+
+    let reviews = match word_stats.accuracy() {
+        Some(accuracy) => t_args(Msg::StatsReviews, &[&word_stats.review_count.to_string(), &accuracy.to_string()]),
+        None => t(Msg::StatsNoReviews).to_string(),
+    };
+
+    rsx! {
+        div { class: class!(text_sm),
+            margin_top: "10px",
+            h2 { class: class!(text_base font_bold),
+                {t(Msg::Statistics)}
+            }
+            p { {t_args(Msg::StatsWordCount, &[&word_stats.word_count.to_string()])} }
+            p { "{reviews}" }
+            p { {t_args(Msg::StatsCurrentStreak, &[&word_stats.current_streak(today).to_string()])} }
+            p { {t_args(Msg::StatsLongestStreak, &[&word_stats.longest_streak().to_string()])} }
+
+            div { class: class!(flex flex_row items_baseline gap_2),
+                margin_top: "10px",
+                h3 { class: class!(flex_auto font_bold),
+                    {t(chart_title)}
+                }
+                select { class: class!(outline),
+                    aria_label: t(chart_title),
+                    onchange: move |event| period.set(match event.value().as_str() {
+                        "weeks" => Period::Weeks,
+                        _ => Period::Days,
+                    }),
+                    option { value: "days", selected: period() == Period::Days, {t(Msg::StatsByDay)} }
+                    option { value: "weeks", selected: period() == Period::Weeks, {t(Msg::StatsByWeek)} }
+                }
+            }
+            ColumnChart { title: chart_title, columns }
+
+            h3 { class: class!(font_bold),
+                margin_top: "10px",
+                {t(Msg::StatsPerFolder)}
+            }
+            BarList { items: largest(&word_stats.per_folder, BAR_LIST_LENGTH) }
+
+            h3 { class: class!(font_bold),
+                margin_top: "10px",
+                {t(Msg::StatsPerWordClass)}
+            }
+            BarList { items: word_classes }
+
+            if !most_reviewed.is_empty() {
+                h3 { class: class!(font_bold),
+                    margin_top: "10px",
+                    {t(Msg::StatsMostReviewed)}
+                }
+                table {
+                    for reviewed in most_reviewed.iter() {
+                        tr {
+                            td { class: class!(pr_4), "{reviewed.word}" }
+                            td { class: class!(pr_4 text_xs), "{reviewed.folder}" }
+                            td { class: class!(text_xs),
+                                {t_args(Msg::StatsReviewCount, &[&reviewed.count.to_string(), &reviewed.correct.to_string()])}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Vertical columns with the first and the last labels under them.
+/// Screen readers get all values from the label of the chart
+#[component]
+fn ColumnChart(title: Msg, columns: Vec<(String, u32)>) -> Element {
+    let max = columns.iter().map(|(_, count)| *count).max().unwrap_or(0).max(1);
+    let width = columns.len() as u32 * COLUMN_WIDTH;
+    let summary = columns
+        .iter()
+        .map(|(name, count)| format!("{name}: {count}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let first_label = columns.first().map(|(name, _)| name.clone()).unwrap_or_default();
+    let last_label = columns.last().map(|(name, _)| name.clone()).unwrap_or_default();
+
+    rsx! {
+        svg { class: class!(w_full text_primary),
+            height: "{CHART_HEIGHT}",
+            view_box: "0 0 {width} {CHART_HEIGHT}",
+            preserve_aspect_ratio: "none",
+            role: "img",
+            "aria-label": "{t(title)}: {summary}",
+            for (index, (_, count)) in columns.iter().enumerate() {
+                rect {
+                    x: "{index as u32 * COLUMN_WIDTH + 1}",
+                    y: "{CHART_HEIGHT - count * CHART_HEIGHT / max}",
+                    width: "{COLUMN_WIDTH - 2}",
+                    height: "{count * CHART_HEIGHT / max}",
+                    fill: "currentColor",
+                }
+            }
+        }
+        div { class: class!(flex flex_row justify_between text_xs),
+            span { "{first_label}" }
+            span { "{last_label}" }
+        }
+    }
+}
+
+/// Horizontal bars with the names and the counts
+#[component]
+fn BarList(items: Vec<(String, u32)>) -> Element {
+    let max = items.iter().map(|(_, count)| *count).max().unwrap_or(0).max(1);
+
+    rsx! {
+        table {
+            for (name, count) in items {
+                tr {
+                    td { class: class!(pr_4), "{name}" }
+                    td { class: class!(text_primary),
+                        svg {
+                            width: "{BAR_WIDTH}",
+                            height: "10",
+                            "aria-hidden": "true",
+                            rect {
+                                width: "{count * BAR_WIDTH / max}",
+                                height: "10",
+                                fill: "currentColor",
+                            }
+                        }
+                    }
+                    td { class: class!(pl_2 text_xs), "{count}" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,420 @@
+/*
+ * Copyright (c) 2024-2025 Oleg Okhotnikov
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![allow(non_snake_case)]
+
+use chrono::{Local, TimeZone};
+use dioxus::prelude::*;
+use dioxus_daisyui::prelude::*;
+use dioxus_free_icons::Icon;
+use dioxus_free_icons::icons::fi_icons::{FiArrowLeft, FiEdit, FiExternalLink, FiFolder, FiTrash, FiVolume2};
+
+use crate::dictionary_source::canonical_url;
+use crate::model::{DailyTotal, Word, WordKey};
+use crate::stats::{local_offset_seconds, new_review};
+use crate::storage::StorageBackend;
+use crate::storage_global::get_storage;
+use crate::ui::error_message::{storage_error_message, StorageErrorMessage};
+use crate::ui::goals::add_to_today;
+use crate::ui::markdown_text::MarkdownText;
+use crate::ui::navigation::NavigationState;
+use crate::i18n::Msg;
+use crate::ui::{CURRENT_TAB_DATA, PLAY_ON_TAB_LOADED, openUrl, playPhonetics, t, t_args};
+
+#[derive(Debug, Clone, PartialEq)]
+enum WordDetailMode {
+    View,
+    Edit,
+    Move,
+}
+
+#[component]
+pub(crate) fn WordDetail(
+    id: ReadOnlySignal<u32>,
+    /// Opens the word in the edit mode
+    edit: bool,
+    refresh_words: Signal<u8>,
+) -> Element {
+    let refresh_word = use_signal(|| 0u8);
+
+    let word = use_resource(move || async move {
+        let _ = refresh_word();
+        get_storage().get_word_by_id(id()).await
+    });
+
+    match &*word.read_unchecked() {
+        None => VNode::empty(),
+        Some(Err(err)) => {
+            rsx! {
+                StorageErrorMessage {
+                    error: err.clone()
+                }
+            }
+        }
+        Some(Ok(word)) => rsx! {
+            WordDetailView {
+                word: word.to_owned(),
+                edit,
+                refresh_word,
+                refresh_words,
+            }
+        },
+    }
+}
+
+#[component]
+fn WordDetailView(
+    word: ReadOnlySignal<Word>,
+    edit: bool,
+    refresh_word: Signal<u8>,
+    refresh_words: Signal<u8>,
+) -> Element {
+    let navigation = use_coroutine_handle::<NavigationState>();
+    let word_key = use_coroutine_handle::<WordKey>();
+    let mut mode = use_signal(|| if edit { WordDetailMode::Edit } else { WordDetailMode::View });
+    let mut error_str = use_signal(String::new);
+
+    let other_words = use_resource(move || async move {
+        get_storage()
+            .get_words_by_headword(&word().word)
+            .await
+            .map(|words| {
+                words
+                    .into_iter()
+                    .filter(|other| other.id != word().id)
+                    .collect::<Vec<Word>>()
+            })
+    });
+
+    let save_word = move |new_word: Word| {
+        let reviews = new_review(&word(), &new_word, local_offset_seconds());
+        spawn(async move {
+            match get_storage().update::<Word>(&new_word).await {
+                Ok(_) => {
+                    if reviews > 0 {
+                        add_to_today(DailyTotal { words: 0, reviews }).await;
+                    }
+                    error_str.set(String::new());
+                    mode.set(WordDetailMode::View);
+                    refresh_word.toggle();
+                    refresh_words.toggle();
+                }
+                Err(err) => error_str.set(storage_error_message(&err).to_string()),
+            }
+        });
+    };
+
+    let id = word().id.unwrap();
+    let url = word().url;
+    let datetime = format_datetime(word().datetime);
+    let review = word().review;
+    let last_review = match review.count {
+        0 => t(Msg::NeverReviewed).to_string(),
+        _ => format_datetime(review.datetime),
+    };
+    let accuracy = match review.count {
+        0 => 0,
+        count => review.correct * 100 / count,
+    };
+    let review_summary = t_args(
+        Msg::ReviewSummary,
+        &[&review.count.to_string(), &review.correct.to_string(), &accuracy.to_string(), &last_review],
+    );
+
+    rsx! {
+        div { class: class!(text_base),
+            margin_top: "10px",
+            div { class: class!(flex flex_row gap_2 items_baseline),
+                button { class: class!(btn btn_sm),
+                    title: t(Msg::BackToWords),
+                    aria_label: t(Msg::BackToWords),
+                    onclick: move |_| navigation.send(NavigationState::Words),
+                    Icon { height: 16, width: 16, icon: FiArrowLeft }
+                }
+                button { class: class!(btn btn_sm),
+                    title: t(Msg::Edit),
+                    aria_label: t(Msg::Edit),
+                    onclick: move |_| mode.set(WordDetailMode::Edit),
+                    Icon { height: 16, width: 16, icon: FiEdit }
+                }
+                button { class: class!(btn btn_sm),
+                    title: t(Msg::MoveToFolder),
+                    aria_label: t(Msg::MoveToFolder),
+                    onclick: move |_| mode.set(WordDetailMode::Move),
+                    Icon { height: 16, width: 16, icon: FiFolder }
+                }
+                button { class: class!(btn btn_sm),
+                    title: t(Msg::OpenInDictionary),
+                    aria_label: t(Msg::OpenInDictionary),
+                    onclick: move |_| { spawn(openUrl(word().url)); },
+                    Icon { height: 16, width: 16, icon: FiExternalLink }
+                }
+                button { class: class!(btn btn_sm),
+                    title: t(Msg::PlayAudio),
+                    aria_label: t(Msg::PlayAudio),
+                    onclick: move |_| play_audio(&word().url),
+                    Icon { height: 16, width: 16, icon: FiVolume2 }
+                }
+                button { class: class!(btn btn_sm),
+                    title: t(Msg::Delete),
+                    aria_label: t(Msg::Delete),
+                    onclick: move |_| word_key.send(WordKey { id }),
+                    Icon { height: 16, width: 16, icon: FiTrash }
+                }
+            }
+
+            p { class: class!(text_xs text_red_500),
+                "{error_str}"
+            }
+
+            match mode() {
+                WordDetailMode::View => rsx! {},
+                WordDetailMode::Edit => rsx! {
+                    EditWordForm {
+                        word,
+                        on_save: save_word,
+                        on_cancel: move |_| mode.set(WordDetailMode::View),
+                    }
+                },
+                WordDetailMode::Move => rsx! {
+                    MoveWordForm {
+                        word,
+                        on_save: save_word,
+                        on_cancel: move |_| mode.set(WordDetailMode::View),
+                    }
+                },
+            }
+
+            div { class: class!(grid grid_cols_3 gap_1 my_2),
+                div { class: class!(text_sm), {t(Msg::DetailWord)} }
+                div { class: class!(col_span_2 font_bold), "{word().word}" }
+                div { class: class!(text_sm), {t(Msg::DetailWordClass)} }
+                div { class: class!(col_span_2), "{word().word_class}" }
+                div { class: class!(text_sm), {t(Msg::DetailFolder)} }
+                div { class: class!(col_span_2 italic), "{word().folder}" }
+                div { class: class!(text_sm), {t(Msg::DetailUrl)} }
+                div { class: class!(col_span_2 text_xs font_mono break_all), "{url}" }
+                div { class: class!(text_sm), {t(Msg::DetailAdded)} }
+                div { class: class!(col_span_2), "{datetime}" }
+                div { class: class!(text_sm), {t(Msg::DetailNote)} }
+                div { class: class!(col_span_2),
+                    MarkdownText { text: word().note }
+                }
+                div { class: class!(text_sm), {t(Msg::DetailExamples)} }
+                div { class: class!(col_span_2),
+                    ul { class: class!(list_disc list_inside),
+                        for example in word().examples {
+                            li { class: class!(italic), "{example}" }
+                        }
+                    }
+                }
+                div { class: class!(text_sm), {t(Msg::DetailContext)} }
+                div { class: class!(col_span_2 text_sm italic), "{word().source_context}" }
+                div { class: class!(text_sm), {t(Msg::DetailReviews)} }
+                div { class: class!(col_span_2),
+                    {review_summary}
+                    div {
+                        button { class: class!(btn btn_xs btn_outline),
+                            onclick: move |_| save_word(word().with_review(true)),
+                            {t(Msg::Remembered)}
+                        }
+                        button { class: class!(btn btn_xs btn_outline),
+                            margin_left: "5px",
+                            onclick: move |_| save_word(word().with_review(false)),
+                            {t(Msg::Forgot)}
+                        }
+                    }
+                }
+                div { class: class!(text_sm), {t(Msg::DetailAlsoIn)} }
+                div { class: class!(col_span_2),
+                    match &*other_words.read_unchecked() {
+                        Some(Ok(words)) => rsx! {
+                            for other in words.iter().cloned() {
+                                button { class: class!(underline italic mr_2),
+                                    onclick: move |_| {
+                                        navigation.send(NavigationState::WordDetail(other.id.unwrap()))
+                                    },
+                                    "{other.folder}"
+                                }
+                            }
+                        },
+                        Some(Err(err)) => rsx! {
+                            StorageErrorMessage { error: err.clone() }
+                        },
+                        None => rsx! {},
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn EditWordForm(
+    word: ReadOnlySignal<Word>,
+    on_save: EventHandler<Word>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let mut word_str = use_signal(|| word().word);
+    let mut word_class_str = use_signal(|| word().word_class);
+    let mut note_str = use_signal(|| word().note);
+    let mut examples_str = use_signal(|| word().examples.join("\n"));
+    let mut word_error_str = use_signal(String::new);
+
+    rsx! {
+        form {
+            action: "",
+            onsubmit: move |event| event.stop_propagation(),
+            div { class: class!(grid grid_cols_3 gap_1 mx_1 my_2 text_base),
+                div { class: class!(col_span_3),
+                    input { class: class!(outline min_w_52),
+                        oninput: move |event| word_str.set(event.value()),
+                        placeholder: t(Msg::WordOrLinkName),
+                        r#type: "text",
+                        value: "{word_str}"
+                    }
+                    p { class: class!(text_xs text_red_500),
+                        "{word_error_str}"
+                    }
+                }
+                div { class: class!(col_span_3),
+                    input { class: class!(outline min_w_52),
+                        oninput: move |event| word_class_str.set(event.value()),
+                        placeholder: t(Msg::WordClass),
+                        r#type: "text",
+                        value: "{word_class_str}"
+                    }
+                }
+                div { class: class!(col_span_3),
+                    textarea { class: class!(outline min_w_52),
+                        oninput: move |event| note_str.set(event.value()),
+                        placeholder: t(Msg::WordNote),
+                        rows: "3",
+                        value: "{note_str}"
+                    }
+                }
+                div { class: class!(col_span_3),
+                    textarea { class: class!(outline min_w_52),
+                        oninput: move |event| examples_str.set(event.value()),
+                        placeholder: t(Msg::WordExamples),
+                        rows: "2",
+                        value: "{examples_str}"
+                    }
+                }
+                div {
+                    button { class: class!(btn btn_sm btn_outline),
+                        onclick: move |event| {
+                            event.stop_propagation();
+                            if word_str().trim().is_empty() {
+                                word_error_str.set(t(Msg::WordIsEmpty).to_string());
+                                return;
+                            }
+                            let mut new_word = word().with_examples(&examples_str());
+                            new_word.word = word_str().trim().to_string();
+                            new_word.word_class = word_class_str();
+                            new_word.note = note_str();
+                            on_save.call(new_word);
+                        },
+                        {t(Msg::Save)}
+                    }
+                }
+                div {
+                    button { class: class!(btn btn_sm btn_outline),
+                        onclick: move |event| {
+                            event.stop_propagation();
+                            on_cancel.call(());
+                        },
+                        {t(Msg::Cancel)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn MoveWordForm(
+    word: ReadOnlySignal<Word>,
+    on_save: EventHandler<Word>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let mut folder_str = use_signal(|| word().folder);
+
+    let folders = use_resource(move || async move {
+        get_storage().get_folders(None, None, String::new(), false).await
+    });
+
+    let folder_options = match &*folders.read_unchecked() {
+        Some(Ok(result)) => result
+            .folders
+            .iter()
+            .map(|folder| folder.folder.clone())
+            .collect::<Vec<String>>(),
+        _ => vec![],
+    };
+
+    rsx! {
+        form {
+            action: "",
+            onsubmit: move |event| event.stop_propagation(),
+            div { class: class!(flex flex_row gap_2 items_baseline mx_1 my_2 text_base),
+                select { class: class!(outline),
+                    onchange: move |event| folder_str.set(event.value()),
+                    for folder in folder_options {
+                        option {
+                            value: "{folder}",
+                            selected: folder == folder_str(),
+                            "{folder}"
+                        }
+                    }
+                }
+                button { class: class!(btn btn_sm btn_outline),
+                    onclick: move |event| {
+                        event.stop_propagation();
+                        let mut new_word = word();
+                        new_word.folder = folder_str();
+                        on_save.call(new_word);
+                    },
+                    {t(Msg::Move)}
+                }
+                button { class: class!(btn btn_sm btn_outline),
+                    onclick: move |event| {
+                        event.stop_propagation();
+                        on_cancel.call(());
+                    },
+                    {t(Msg::Cancel)}
+                }
+            }
+        }
+    }
+}
+
+fn format_datetime(timestamp_millis: i64) -> String {
+    match Local.timestamp_millis_opt(timestamp_millis).single() {
+        Some(datetime) => datetime.format("%Y-%m-%d %H:%M").to_string(),
+        None => String::new(),
+    }
+}
+
+/// The pronunciation is played from the dictionary page, so it's opened first if needed
+fn play_audio(url: &str) {
+    if canonical_url(&CURRENT_TAB_DATA().url) == canonical_url(url) {
+        spawn(playPhonetics());
+    } else {
+        *PLAY_ON_TAB_LOADED.write() = true;
+        spawn(openUrl(url.to_string()));
+    }
+}
@@ -16,16 +16,25 @@
 
 #![allow(non_snake_case)]
 
+use chrono::Utc;
 use dioxus::prelude::*;
 use dioxus_daisyui::prelude::*;
-use log::debug;
+use crate::i18n::Msg;
+use crate::model::{KeyAction, WordKey};
+use crate::storage::StorageBackend;
 use crate::storage_global::get_storage;
 use crate::ui::add_word_form::AddWordForm;
-use crate::ui::{MAX_WORD_INDEX, next_word, previous_word};
-use crate::ui::error_message::ErrorMessage;
-use crate::ui::pager::{Pager, PagerMode};
+use crate::ui::app_settings::use_settings;
+use crate::ui::{t, SELECTED_SMART_FOLDER};
+use crate::ui::error_message::StorageErrorMessage;
+use crate::ui::folder_breadcrumbs::FolderBreadcrumbs;
+use crate::ui::keymap::key_action;
+use crate::ui::list_selection::WORDS_SELECTION;
+use crate::ui::navigation::NavigationState;
+use crate::ui::pager::{page_left, page_right, Pager, PagerMode};
 use crate::ui::search_form::SearchForm;
 use crate::ui::show_word::ShowWord;
+use crate::ui::smart_folders::SmartFolderName;
 
 #[component]
 pub(crate) fn Words(
@@ -35,19 +44,36 @@ pub(crate) fn Words(
     word_error_str: Signal<String>,
     word_class_str: Signal<String>,
     note_str: Signal<String>,
+    examples_str: Signal<String>,
     page_length: Signal<Option<u32>>,
     offset: Signal<Option<u32>>,
     direction: Signal<String>,
     refresh_words: Signal<u8>,
-    show_add_word_form: Signal<u8>,
+    show_add_word_form: Signal<bool>,
 ) -> Element {
-    let search_str = use_signal(|| String::new());
+    let search_str = use_signal(String::new);
+    let mut filter_str = use_signal(String::new);
+    let settings = use_settings();
+    let navigation = use_coroutine_handle::<NavigationState>();
+    let word_key = use_coroutine_handle::<WordKey>();
 
     let words = use_resource(move || async move {
         let _ = refresh_words();
-        get_storage()
-            .get_words(selected_folder_str(), page_length(), offset(), direction())
-            .await
+        match SELECTED_SMART_FOLDER() {
+            Some(id) => get_storage()
+                .get_smart_folder_words(
+                    id,
+                    Utc::now().timestamp_millis(),
+                    filter_str(),
+                    page_length(),
+                    offset(),
+                    direction(),
+                )
+                .await,
+            None => get_storage()
+                .get_words(selected_folder_str(), filter_str(), page_length(), offset(), direction())
+                .await,
+        }
     });
 
     let (words_to_show, count) = match &*words.read_unchecked() {
@@ -56,18 +82,17 @@ pub(crate) fn Words(
             (rsx! {}, 0u32)
         }
         Some(Err(err)) => {
-            debug!("Err: {}", err);
             (
                 rsx! {
-                    ErrorMessage {
-                        message: err.to_string()
+                    StorageErrorMessage {
+                        error: err.clone()
                     }
                 },
                 0u32,
             )
         }
         Some(Ok(result)) => {
-            *MAX_WORD_INDEX.write() = result.words.len() as i32;
+            WORDS_SELECTION.set_len(result.words.len());
             (
                 rsx! {
                     for (index, word) in result.words.iter().enumerate() {
@@ -83,7 +108,11 @@ pub(crate) fn Words(
     };
 
     rsx! {
-        if show_add_word_form() != 0u8 {
+        if let Some(id) = SELECTED_SMART_FOLDER() {
+            SmartFolderName {
+                id,
+            }
+        } else if show_add_word_form() {
             SearchForm {
                 search_str: search_str,
             }
@@ -96,15 +125,13 @@ pub(crate) fn Words(
                 word_error_str: word_error_str,
                 word_class_str: word_class_str,
                 note_str: note_str,
+                examples_str: examples_str,
                 refresh_words: refresh_words,
             }
         } else {
-            div { class: class!(text_base),
-                margin_top: "5px",
-                "Folder: "
-                span { class: class!(italic),
-                    "{selected_folder_str}"
-                }
+            FolderBreadcrumbs {
+                selected_folder_str,
+                offset,
             }
         }
 
@@ -112,12 +139,48 @@ pub(crate) fn Words(
             margin_top: "5px",
             tabindex: "-1",
             onkeydown: move |event| {
+                let Some(action) = key_action(&event, &settings.peek()) else {
+                    return;
+                };
+                let selected_id = match (&*words.peek(), *WORDS_SELECTION.index.peek()) {
+                    (Some(Ok(result)), Some(index)) => result.words
+                        .get(index as usize)
+                        .and_then(|word| word.id),
+                    _ => None,
+                };
+                match (action, selected_id) {
+                    (KeyAction::NextItem, _) => WORDS_SELECTION.next(),
+                    (KeyAction::PreviousItem, _) => WORDS_SELECTION.previous(),
+                    (KeyAction::PageLeft, _) => page_left(PagerMode::Words, page_length, offset),
+                    (KeyAction::PageRight, _) =>
+                        page_right(PagerMode::Words, count, page_length, offset, true),
+                    (KeyAction::Open, Some(id)) => navigation.send(NavigationState::WordDetail(id)),
+                    (KeyAction::Edit, Some(id)) => navigation.send(NavigationState::EditWord(id)),
+                    (KeyAction::Delete, Some(id)) => word_key.send(WordKey { id }),
+                    _ => return,
+                }
+                event.prevent_default();
                 event.stop_propagation();
-                key_event(event);
             },
             div { class: class!(hidden),
                 "{refresh_words}"
             }
+            div { class: class!(text_sm mx_1 my_2),
+                label {
+                    r#for: "filter-text",
+                    input { class: class!(outline min_w_52),
+                        oninput: move |event| {
+                            filter_str.set(event.value());
+                            offset.set(None);
+                            WORDS_SELECTION.clear();
+                        },
+                        placeholder: t(Msg::FilterWords),
+                        r#type: "text",
+                        id: "filter-text",
+                        value: "{filter_str}"
+                    }
+                }
+            }
             Pager {
                 mode: PagerMode::Words,
                 page_length: page_length,
@@ -128,18 +191,11 @@ pub(crate) fn Words(
             div { class: "main-content",
                 id: "words-list",
                 tabindex: "0",
+                role: "listbox",
+                aria_label: t(Msg::Words),
+                aria_activedescendant: WORDS_SELECTION.active_descendant(),
                 {words_to_show}
             }
         }
     }
 }
-
-fn key_event(event: Event<KeyboardData>) {
-    let code = event.code();
-    // debug!("code: {}", code);
-    match code {
-        Code::ArrowDown => next_word(),
-        Code::ArrowUp => previous_word(),
-        _ => {},
-    }
-}
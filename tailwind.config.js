@@ -11,4 +11,5 @@ module.exports = {
     plugins: [
         require('daisyui')
     ],
-};
\ No newline at end of file
+    daisyui: require('./daisyui.config.js'),
+};
@@ -20,4 +20,5 @@ module.exports = {
         // require('@tailwindcss/container-queries'),
         require('daisyui')
     ],
+    daisyui: require('./daisyui.config.js'),
 };
\ No newline at end of file